@@ -0,0 +1,96 @@
+//! Compares batch operations on a million vectors stored as a Vec3box and as a Vec3soa.
+//!
+//! Run with `cargo +nightly bench`.
+//!
+//! On one core of an x86_64 Xeon with the default `simd` feature, in ns/iter:
+//!
+//! | benchmark    |      Vec3box |      Vec3soa | speedup |
+//! |--------------|--------------|--------------|---------|
+//! | `add_assign` |    1,077,482 |    1,036,510 |   1.04× |
+//! | `crossarr`   |    2,471,483 |    2,076,090 |   1.19× |
+//! | `dot`        |    1,630,547 |    1,445,529 |   1.13× |
+//! | `mag`        |    1,492,243 |    1,228,863 |   1.21× |
+//! | `norm`       |    2,351,082 |    1,809,274 |   1.30× |
+
+#![feature(test)]
+
+extern crate lineq;
+extern crate test;
+
+use lineq::vec3::Vec3;
+use lineq::vec3arr::{Vec3Array, Vec3box};
+use lineq::vec3soa::Vec3soa;
+use test::{black_box, Bencher};
+
+const LEN : usize = 1_000_000;
+
+fn point(i : usize) -> Vec3 {
+	let t : f32 = i as f32;
+	Vec3 { x: t.sin(), y: t.cos(), z: 0.001*t }
+}
+
+#[bench]
+fn box_mag(b : &mut Bencher) {
+	let a : Vec3box = Vec3box::ftoarr(point, LEN);
+	b.iter(|| black_box(a.mag()));
+}
+
+#[bench]
+fn soa_mag(b : &mut Bencher) {
+	let a : Vec3soa = Vec3soa::ftoarr(point, LEN);
+	b.iter(|| black_box(a.mag()));
+}
+
+#[bench]
+fn box_norm(b : &mut Bencher) {
+	let a : Vec3box = Vec3box::ftoarr(point, LEN);
+	b.iter(|| black_box(a.norm()));
+}
+
+#[bench]
+fn soa_norm(b : &mut Bencher) {
+	let a : Vec3soa = Vec3soa::ftoarr(point, LEN);
+	b.iter(|| black_box(a.norm()));
+}
+
+#[bench]
+fn box_dot(b : &mut Bencher) {
+	let a : Vec3box = Vec3box::ftoarr(point, LEN);
+	let c : Vec3box = Vec3box::ftoarr(|i| point(i + 1), LEN);
+	b.iter(|| black_box(&a * &c));
+}
+
+#[bench]
+fn soa_dot(b : &mut Bencher) {
+	let a : Vec3soa = Vec3soa::ftoarr(point, LEN);
+	let c : Vec3soa = Vec3soa::ftoarr(|i| point(i + 1), LEN);
+	b.iter(|| black_box(&a * &c));
+}
+
+#[bench]
+fn box_crossarr(b : &mut Bencher) {
+	let a : Vec3box = Vec3box::ftoarr(point, LEN);
+	let c : Vec3box = Vec3box::ftoarr(|i| point(i + 1), LEN);
+	b.iter(|| black_box(Vec3Array::crossarr(&a, &c)));
+}
+
+#[bench]
+fn soa_crossarr(b : &mut Bencher) {
+	let a : Vec3soa = Vec3soa::ftoarr(point, LEN);
+	let c : Vec3soa = Vec3soa::ftoarr(|i| point(i + 1), LEN);
+	b.iter(|| black_box(a.crossarr(&c)));
+}
+
+#[bench]
+fn box_add_assign(b : &mut Bencher) {
+	let mut a : Vec3box = Vec3box::ftoarr(point, LEN);
+	let c : Vec3box = Vec3box::ftoarr(|i| point(i + 1), LEN);
+	b.iter(|| { a += &c; black_box(&a); });
+}
+
+#[bench]
+fn soa_add_assign(b : &mut Bencher) {
+	let mut a : Vec3soa = Vec3soa::ftoarr(point, LEN);
+	let c : Vec3soa = Vec3soa::ftoarr(|i| point(i + 1), LEN);
+	b.iter(|| { a += &c; black_box(&a); });
+}
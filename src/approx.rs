@@ -0,0 +1,299 @@
+//! Module containing approximate equality, for comparing the results of floating point math.
+//!
+//! The derived [PartialEq] on vectors and matricies is exact, so two results that only differ by
+//! rounding are not equal. [ApproxEq] compares them component by component with a
+//! [Tolerance] instead, which is either absolute, relative to the larger magnitude, or a number of
+//! ULPs, that is representable values, apart. Relative and ULP tolerances scale with the values
+//! compared, but fail for results that should be zero and are instead tiny, so use an absolute
+//! tolerance for those.
+//!
+//! [assert_approx_eq](crate::assert_approx_eq) asserts that two values are approximately equal,
+//! and lists the components that are not when it fails.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[macro_use] extern crate lineq;
+//! use lineq::vec3::Vector3;
+//! use lineq::mat::Matrix33;
+//! use lineq::approx::{ApproxEq, Tolerance};
+//! # fn main() {
+//! let a : Vector3<f64> = Vector3 { x: 0.1, y: 0.2, z: 0.3 };
+//! let b : Vector3<f64> = Vector3 { x: 0.1 + 0.2 - 0.2, y: 0.2, z: 0.1 + 0.2 };
+//! assert_ne!(a, b);
+//! assert!(a.ulps_eq(&b, 2));
+//! assert!(a.approx_eq(&b, Tolerance::Abs(1e-15)));
+//! assert!(!a.rel_eq(&(b*1.001), 1e-6));
+//! assert_approx_eq!(a, b, rel = 1e-15);
+//! let m : Matrix33<f64> = Matrix33::augment(a, Vector3::UP, Vector3::LEFT);
+//! assert_approx_eq!(m.inverse().inverse(), m, abs = 1e-12);
+//! # }
+//! ```
+
+use std::fmt;
+use std::fmt::Write;
+use crate::scalar::Scalar;
+use crate::error::{LineqError, check_len};
+use crate::vec2::Vector2;
+use crate::vec3::Vector3;
+use crate::vec4::Vector4;
+use crate::quat::Quaternion;
+use crate::mat::{Matrix22, Matrix33, Matrix44};
+use crate::matn::{MatrixN, VectorN};
+use crate::vec2arr::{Vector2arr, Vector2box, Vector2vec, Vector2win, Vector2raw};
+use crate::vec3arr::{Vector3arr, Vector3box, Vector3vec, Vector3win, Vector3raw};
+use crate::vec3soa::Vector3soa;
+
+/// How far apart two scalars can be and still count as equal.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Tolerance<T = f32> {
+	/// At most this far apart.
+	Abs(T),
+	/// At most this fraction of the larger of their magnitudes apart.
+	Rel(T),
+	/// At most this many representable values apart, see [ulps](crate::scalar::Scalar::ulps).
+	Ulps(u64),
+}
+
+impl<T: Scalar> Tolerance<T> {
+
+	/// Whether `a` and `b` are within the tolerance of each other, which they always are if
+	/// they are equal, and never are if either is NaN.
+	pub fn accepts(self, a : T, b : T) -> bool {
+		if a == b { return true; }
+		match self {
+			Tolerance::Abs(eps) => (a - b).abs() <= eps,
+			Tolerance::Rel(rel) => (a - b).abs() <= rel * if a.abs() > b.abs() { a.abs() } else { b.abs() },
+			Tolerance::Ulps(n) => a.ulps(b) <= n,
+		}
+	}
+}
+
+/// Approximate equality, checked component by component.
+pub trait ApproxEq<Rhs: ?Sized = Self> {
+	type Scalar : Scalar;
+
+	/// Calls `f` with the name and the values of every pair of matching components, or fails
+	/// without calling it if the two have different lengths.
+	fn zip_components<F: FnMut(fmt::Arguments<'_>, Self::Scalar, Self::Scalar)>(&self, other : &Rhs, f : F) -> Result<(), LineqError>;
+
+	fn approx_eq(&self, other : &Rhs, tol : Tolerance<Self::Scalar>) -> bool {
+		let mut eq : bool = true;
+		self.zip_components(other, |_, a, b| eq &= tol.accepts(a, b)).is_ok() && eq
+	}
+
+	fn abs_eq(&self, other : &Rhs, eps : Self::Scalar) -> bool {
+		self.approx_eq(other, Tolerance::Abs(eps))
+	}
+
+	fn rel_eq(&self, other : &Rhs, rel : Self::Scalar) -> bool {
+		self.approx_eq(other, Tolerance::Rel(rel))
+	}
+
+	fn ulps_eq(&self, other : &Rhs, ulps : u64) -> bool {
+		self.approx_eq(other, Tolerance::Ulps(ulps))
+	}
+}
+
+// At most this many mismatching components are listed by diff.
+const MAX_LISTED : usize = 16;
+
+/// Lists the components of `left` and `right` that are not within `tol` of each other, or
+/// returns [None] if they all are.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec3::Vec3;
+/// use lineq::vec3arr::Vec3arr;
+/// use lineq::approx::{diff, Tolerance};
+/// let a : Vec3arr<2> = Vec3arr([Vec3::ZERO, Vec3::ONE]);
+/// let b : Vec3arr<2> = Vec3arr([Vec3::ZERO, Vec3 { x: 1.0, y: 1.5, z: 1.0 }]);
+/// assert_eq!(diff(&a, &a, Tolerance::Ulps(0)), None);
+/// assert_eq!(diff(&a, &b, Tolerance::Abs(0.1)).unwrap(),
+///            "1 of 6 components differ by more than Abs(0.1):\n    [1].y: 1 != 1.5, difference 0.5, 4194304 ulps\n");
+/// ```
+pub fn diff<A: ApproxEq<B> + ?Sized, B: ?Sized>(left : &A, right : &B, tol : Tolerance<A::Scalar>) -> Option<String> {
+	let (mut lines, mut count, mut total) = (String::new(), 0, 0);
+	let zipped = left.zip_components(right, |name, a, b| {
+		total += 1;
+		if tol.accepts(a, b) { return; }
+		count += 1;
+		if count <= MAX_LISTED {
+			let _ = writeln!(lines, "    {}: {} != {}, difference {}, {} ulps", name, a, b, (a - b).abs(), a.ulps(b));
+		}
+	});
+	if let Err(e) = zipped { return Some(format!("{}", e)); }
+	if count == 0 { return None; }
+	if count > MAX_LISTED { let _ = writeln!(lines, "    and {} more", count - MAX_LISTED); }
+	Some(format!("{} of {} components differ by more than {:?}:\n{}", count, total, tol, lines))
+}
+
+/// Asserts that two values are approximately equal with [ApproxEq](crate::approx::ApproxEq),
+/// and panics with the [diff](crate::approx::diff) of them if not.
+///
+/// The tolerance is given as `abs = eps`, `rel = rel` or `ulps = n`, and is 4 ULPs if left out.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate lineq;
+/// use lineq::vec3::Vec3;
+/// use lineq::approx::{diff, Tolerance};
+/// # fn main() {
+/// let a : Vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// let b : Vec3 = a + Vec3 { x: 0.0, y: 1e-6, z: 0.0 };
+/// assert_approx_eq!(a, a * (1.0 + f32::EPSILON));
+/// assert_approx_eq!(a, b, abs = 1e-5);
+/// // the message that assert_approx_eq!(a, b, abs = 1e-7) panics with, after
+/// // "assertion `left ≈ right` failed: "
+/// assert_eq!(diff(&a, &b, Tolerance::Abs(1e-7)).unwrap(),
+///            "1 of 3 components differ by more than Abs(1e-7):\n    y: 2 != 2.000001, difference 0.0000009536743, 4 ulps\n");
+/// # }
+/// ```
+///
+/// ```rust,should_panic
+/// # #[macro_use] extern crate lineq;
+/// use lineq::vec3::Vec3;
+/// # fn main() {
+/// let a : Vec3 = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+/// assert_approx_eq!(a, a + Vec3 { x: 0.0, y: 1e-6, z: 0.0 }, abs = 1e-7);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+        (@tol $left:expr, $right:expr, $tol:expr) => {
+                match (&$left, &$right) {
+                        (left, right) => {
+                                if let Some(diff) = $crate::approx::diff(left, right, $tol) {
+                                        panic!("assertion `left ≈ right` failed: {}", diff);
+                                }
+                        }
+                }
+        };
+        ($left:expr, $right:expr) => {
+                $crate::assert_approx_eq!(@tol $left, $right, $crate::approx::Tolerance::Ulps(4))
+        };
+        ($left:expr, $right:expr, abs = $eps:expr) => {
+                $crate::assert_approx_eq!(@tol $left, $right, $crate::approx::Tolerance::Abs($eps))
+        };
+        ($left:expr, $right:expr, rel = $rel:expr) => {
+                $crate::assert_approx_eq!(@tol $left, $right, $crate::approx::Tolerance::Rel($rel))
+        };
+        ($left:expr, $right:expr, ulps = $n:expr) => {
+                $crate::assert_approx_eq!(@tol $left, $right, $crate::approx::Tolerance::Ulps($n))
+        };
+}
+
+macro_rules! approx_impl {
+        (Approx $t:ident; $($e:ident),+) => {
+                impl<T: Scalar> ApproxEq for $t<T> {
+                        type Scalar = T;
+
+                        fn zip_components<F: FnMut(fmt::Arguments<'_>, T, T)>(&self, other : &$t<T>, mut f : F) -> Result<(), LineqError> {
+                                $(f(format_args!(stringify!($e)), self.$e, other.$e);)+
+                                Ok(())
+                        }
+                }
+        };
+        (Slice $v:ident) => {
+                impl<T: Scalar> ApproxEq for [$v<T>] {
+                        type Scalar = T;
+
+                        fn zip_components<F: FnMut(fmt::Arguments<'_>, T, T)>(&self, other : &[$v<T>], mut f : F) -> Result<(), LineqError> {
+                                check_len(self.len(), other.len())?;
+                                for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+                                        a.zip_components(b, |name, a, b| f(format_args!("[{}].{}", i, name), a, b))?;
+                                }
+                                Ok(())
+                        }
+                }
+        };
+        (Deref $t:ty; $v:ident$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> ApproxEq for $t {
+                        type Scalar = T;
+
+                        fn zip_components<F: FnMut(fmt::Arguments<'_>, T, T)>(&self, other : &$t, f : F) -> Result<(), LineqError> {
+                                self[..].zip_components(&other[..], f)
+                        }
+                }
+        };
+}
+
+approx_impl! {Approx Vector2; x, y}
+approx_impl! {Approx Vector3; x, y, z}
+approx_impl! {Approx Vector4; x, y, z, w}
+approx_impl! {Approx Quaternion; w, x, y, z}
+approx_impl! {Approx Matrix22; x1, x2, y1, y2}
+approx_impl! {Approx Matrix33; x1, x2, x3, y1, y2, y3, z1, z2, z3}
+approx_impl! {Approx Matrix44; x1, x2, x3, x4, y1, y2, y3, y4, z1, z2, z3, z4, w1, w2, w3, w4}
+approx_impl! {Slice Vector2}
+approx_impl! {Slice Vector3}
+approx_impl! {Deref Vector2arr<N, T>; Vector2; const N: usize}
+approx_impl! {Deref Vector2box<T>; Vector2}
+approx_impl! {Deref Vector2vec<T>; Vector2}
+approx_impl! {Deref Vector2win<'a, T>; Vector2; <'a>}
+approx_impl! {Deref Vector2raw<T>; Vector2}
+approx_impl! {Deref Vector3arr<N, T>; Vector3; const N: usize}
+approx_impl! {Deref Vector3box<T>; Vector3}
+approx_impl! {Deref Vector3vec<T>; Vector3}
+approx_impl! {Deref Vector3win<'a, T>; Vector3; <'a>}
+approx_impl! {Deref Vector3raw<T>; Vector3}
+
+impl<T: Scalar> ApproxEq for VectorN<T> {
+	type Scalar = T;
+
+	fn zip_components<F: FnMut(fmt::Arguments<'_>, T, T)>(&self, other : &VectorN<T>, mut f : F) -> Result<(), LineqError> {
+		check_len(self.len(), other.len())?;
+		for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+			f(format_args!("[{}]", i), *a, *b);
+		}
+		Ok(())
+	}
+}
+
+impl<T: Scalar> ApproxEq for Vector3soa<T> {
+	type Scalar = T;
+
+	/// Names the components the same way as for a [Vec3box](crate::vec3arr::Vec3box), so that `[i].x` is the x
+	/// component of the vector at index `i`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[macro_use] extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::vec3soa::Vector3soa;
+	/// use lineq::approx::{ApproxEq, diff, Tolerance};
+	/// # fn main() {
+	/// let a : Vector3soa<f64> = Vector3soa::ftoarr(|i| Vector3::ONE * (0.1 * i as f64), 3);
+	/// let b : Vector3soa<f64> = &a * 3.0 / 3.0;
+	/// assert!(a.ulps_eq(&b, 1));
+	/// assert_approx_eq!(a, b, abs = 1e-15);
+	/// let c : Vector3soa<f64> = Vector3soa::ftoarr(|i| a.get(i) + Vector3::UP, 3);
+	/// let d : String = diff(&a, &c, Tolerance::Abs(0.5)).unwrap();
+	/// assert!(d.starts_with("3 of 9 components differ by more than Abs(0.5):\n    [0].y: 0 != 1,"));
+	/// # }
+	/// ```
+	fn zip_components<F: FnMut(fmt::Arguments<'_>, T, T)>(&self, other : &Vector3soa<T>, mut f : F) -> Result<(), LineqError> {
+		check_len(self.len(), other.len())?;
+		for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+			a.zip_components(&b, |name, a, b| f(format_args!("[{}].{}", i, name), a, b))?;
+		}
+		Ok(())
+	}
+}
+
+impl<T: Scalar> ApproxEq for MatrixN<T> {
+	type Scalar = T;
+
+	fn zip_components<F: FnMut(fmt::Arguments<'_>, T, T)>(&self, other : &MatrixN<T>, mut f : F) -> Result<(), LineqError> {
+		check_len(self.rows(), other.rows())?;
+		check_len(self.cols(), other.cols())?;
+		for (k, (a, b)) in self.as_slice().iter().zip(other.as_slice().iter()).enumerate() {
+			f(format_args!("({}, {})", k/self.cols(), k % self.cols()), *a, *b);
+		}
+		Ok(())
+	}
+}
@@ -13,6 +13,10 @@
 ///   be the name of it.
 /// - gent: If your struct includes one, and only one, const generic, then this will
 ///   be the type of that const generic.
+/// - tp: If your struct is generic over one, and only one, scalar type, then this will
+///   be the name of it.
+/// - tb: If your struct is generic over one, and only one, scalar type, then this will
+///   be the bound on it, usually [Scalar](crate::scalar::Scalar).
 ///
 /// # Examples
 ///
@@ -40,16 +44,24 @@
 /// ```
 #[macro_export]
 macro_rules! deref_impl {
-    (Deref val $tin:ty; to $tout:ty$(; const $gen:ident: $gent:ty)?) => {
-        impl$(<const $gen: $gent>)? Deref for $tin {
+    (Deref val $tin:ty; to $tout:ty; const $gen:ident: $gent:ty$(; $tp:ident: $tb:path)?) => {
+        impl<$($tp: $tb,)?const $gen: $gent> Deref for $tin {
+            type Target = $tout;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+    };
+    (Deref val $tin:ty; to $tout:ty$(; $tp:ident: $tb:path)?) => {
+        impl$(<$tp: $tb>)? Deref for $tin {
             type Target = $tout;
             fn deref(&self) -> &Self::Target {
                 &self.0
             }
         }
     };
-    (Deref ptr $tin:ty; to $tout:ty) => {
-        impl Deref for $tin {
+    (Deref ptr $tin:ty; to $tout:ty$(; $tp:ident: $tb:path)?) => {
+        impl$(<$tp: $tb>)? Deref for $tin {
             type Target = $tout;
             fn deref(&self) -> &Self::Target {
                 &*self.0
@@ -73,6 +85,10 @@ macro_rules! deref_impl {
 ///   be the name of it.
 /// - gent: If your struct includes one, and only one, const generic, then this will
 ///   be the type of that const generic.
+/// - tp: If your struct is generic over one, and only one, scalar type, then this will
+///   be the name of it.
+/// - tb: If your struct is generic over one, and only one, scalar type, then this will
+///   be the bound on it, usually [Scalar](crate::scalar::Scalar).
 ///
 /// # Examples
 ///
@@ -105,15 +121,22 @@ macro_rules! deref_impl {
 /// ```
 #[macro_export]
 macro_rules! deref_mut_impl {
-    (DerefMut val $tin:ty; to $tout:ty$(; const $gen:ident: $gent:ty)?) => {
-        impl$(<const $gen: $gent>)? DerefMut for $tin {
+    (DerefMut val $tin:ty; to $tout:ty; const $gen:ident: $gent:ty$(; $tp:ident: $tb:path)?) => {
+        impl<$($tp: $tb,)?const $gen: $gent> DerefMut for $tin {
+            fn deref_mut(&mut self) -> &mut $tout {
+                &mut self.0
+            }
+        }
+    };
+    (DerefMut val $tin:ty; to $tout:ty$(; $tp:ident: $tb:path)?) => {
+        impl$(<$tp: $tb>)? DerefMut for $tin {
             fn deref_mut(&mut self) -> &mut $tout {
                 &mut self.0
             }
         }
     };
-    (DerefMut ptr $tin:ty; to $tout:ty) => {
-        impl DerefMut for $tin {
+    (DerefMut ptr $tin:ty; to $tout:ty$(; $tp:ident: $tb:path)?) => {
+        impl$(<$tp: $tb>)? DerefMut for $tin {
             fn deref_mut(&mut self) -> &mut $tout {
                 &mut*self.0
             }
@@ -143,13 +166,18 @@ macro_rules! deref_mut_impl {
 /// - rhs: This is the type on the right hand side of the operation.
 /// - lhs: This is the type on the left hand side of the operation.
 /// - out: This is the type that results after the operation, so if you add a f32 to a f32, your
-///   output type is f32.
+///   output type is f32. The forms with a const generic build it with an `ftoarr` function, like
+///   [Vec3arr::ftoarr](crate::vec3arr::Vec3arr::ftoarr).
 /// - gen: If your your types include one, and only one, const generic in total, then this
-///    will be the name of it.
+///   will be the name of it.
 /// - gent: If your your types include one, and only one, const generic in total, then this
-///    will be the type of that const generic.
+///   will be the type of that const generic.
 /// - lt: If your your types include one, and only one, lifetime in total, then this will
 ///   be the name for it.
+/// - tp: If your types are generic over one, and only one, scalar type, then this will
+///   be the name of it.
+/// - tb: If your types are generic over one, and only one, scalar type, then this will
+///   be the bound on it, usually [Scalar](crate::scalar::Scalar).
 ///
 /// # Examples
 ///
@@ -158,7 +186,6 @@ macro_rules! deref_mut_impl {
 /// use std::ops::Add;
 /// use std::ops::Deref;
 /// use std::ops::DerefMut;
-/// use std::mem::MaybeUninit;
 /// use lineq::deref_impl;
 /// use lineq::deref_mut_impl;
 /// use lineq::pv_value_impl;
@@ -167,6 +194,12 @@ macro_rules! deref_mut_impl {
 /// struct F32arr<const N: usize>([f32; N]);
 /// struct F32win<'a>(&'a mut [f32]);
 ///
+/// impl<const N: usize> F32arr<N> {
+///     fn ftoarr<F: Fn(usize) -> f32>(f: F) -> F32arr<N> {
+///         F32arr(std::array::from_fn(f))
+///     }
+/// }
+///
 /// deref_impl! {Deref val F32arr<N>; to [f32; N]; const N: usize}
 /// deref_mut_impl! {DerefMut val F32arr<N>; to [f32; N]; const N: usize}
 ///
@@ -181,13 +214,13 @@ macro_rules! deref_mut_impl {
 /// # use std::ops::Add;
 /// # use std::ops::Deref;
 /// # use std::ops::DerefMut;
-/// # use std::mem::MaybeUninit;
 /// # use lineq::deref_impl;
 /// # use lineq::deref_mut_impl;
 /// # use lineq::pv_value_impl;
 /// # use lineq::value_impl;
 /// # struct F32arr<const N: usize>([f32; N]);
 /// # struct F32win<'a>(&'a mut [f32]);
+/// # impl<const N: usize> F32arr<N> { fn ftoarr<F: Fn(usize) -> f32>(f: F) -> F32arr<N> { F32arr(std::array::from_fn(f)) } }
 /// # deref_impl! {Deref val F32arr<N>; to [f32; N]; const N: usize}
 /// # deref_mut_impl! {DerefMut val F32arr<N>; to [f32; N]; const N: usize}
 /// # deref_impl! {Deref val F32win<'_>; to [f32]}
@@ -199,41 +232,41 @@ macro_rules! deref_mut_impl {
 /// ```
 #[macro_export]
 macro_rules! pv_value_impl {
-    ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty; out: $out:tt$(; <$lt:lifetime>)?) => {
-        value_impl!{$imp;$func;$op; 3 $rhs; for $lhs; out: $out$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 3 &$rhs; for $lhs; out: $out$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 3 $rhs; for &$lhs; out: $out$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 3 &$rhs; for &$lhs; out: $out$(; <$lt>)?}
-    };
-    ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:tt) => {
-        value_impl!{$imp;$func;$op; 2 $rhs; for $lhs; out: $out}
-        value_impl!{$imp;$func;$op; 2 &$rhs; for $lhs; out: $out}
-        value_impl!{$imp;$func;$op; 2 $rhs; for &$lhs; out: $out}
-        value_impl!{$imp;$func;$op; 2 &$rhs; for &$lhs; out: $out}
-    };
-    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; out: $out:tt) => {
-        value_impl!{$imp;$func;$op; 1 $rhs; for $lhs; out: $out}
-        value_impl!{$imp;$func;$op; 1 &$rhs; for $lhs; out: $out}
-        value_impl!{$imp;$func;$op; 1 $rhs; for &$lhs; out: $out}
-        value_impl!{$imp;$func;$op; 1 &$rhs; for &$lhs; out: $out}
-    };
-    ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        value_impl!{$imp;$func;$op; 3 $rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 3 &$rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 3 $rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 3 &$rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-    };
-    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        value_impl!{$imp;$func;$op; 1 $rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 1 &$rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 1 $rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 1 &$rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-    };
-    ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        value_impl!{$imp;$func;$op; 2 $rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 2 &$rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 2 $rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?}
-        value_impl!{$imp;$func;$op; 2 &$rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?}
+    ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty; out: $out:ident$(<$og:ty>)?$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        value_impl!{$imp;$func;$op; 3 $rhs; for $lhs; out: $out$(<$og>)?$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 3 &$rhs; for $lhs; out: $out$(<$og>)?$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 3 $rhs; for &$lhs; out: $out$(<$og>)?$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 3 &$rhs; for &$lhs; out: $out$(<$og>)?$(; <$lt>)?$(; $tp: $tb)?}
+    };
+    ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:ident$(<$og:ty>)?) => {
+        value_impl!{$imp;$func;$op; 2 $rhs; for $lhs; out: $out$(<$og>)?}
+        value_impl!{$imp;$func;$op; 2 &$rhs; for $lhs; out: $out$(<$og>)?}
+        value_impl!{$imp;$func;$op; 2 $rhs; for &$lhs; out: $out$(<$og>)?}
+        value_impl!{$imp;$func;$op; 2 &$rhs; for &$lhs; out: $out$(<$og>)?}
+    };
+    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; out: $out:ident$(<$og:ty>)?$(; $tp:ident: $tb:path)?) => {
+        value_impl!{$imp;$func;$op; 1 $rhs; for $lhs; out: $out$(<$og>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 1 &$rhs; for $lhs; out: $out$(<$og>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 1 $rhs; for &$lhs; out: $out$(<$og>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 1 &$rhs; for &$lhs; out: $out$(<$og>)?$(; $tp: $tb)?}
+    };
+    ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        value_impl!{$imp;$func;$op; 3 $rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 3 &$rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 3 $rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 3 &$rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+    };
+    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        value_impl!{$imp;$func;$op; 1 $rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 1 &$rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 1 $rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 1 &$rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+    };
+    ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        value_impl!{$imp;$func;$op; 2 $rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 2 &$rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 2 $rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        value_impl!{$imp;$func;$op; 2 &$rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
     };
 }
 
@@ -257,11 +290,15 @@ macro_rules! pv_value_impl {
 /// - rhs: This is the type on the right hand side of the operation.
 /// - lhs: This is the type on the left hand side of the operation.
 /// - gen: If your your types include one, and only one, const generic in total, then this
-///    will be the name of it.
+///   will be the name of it.
 /// - gent: If your your types include one, and only one, const generic in total, then this
-///    will be the type of that const generic.
+///   will be the type of that const generic.
 /// - lt: If your your types include one, and only one, lifetime in total, then this will
 ///   be the name for it.
+/// - tp: If your types are generic over one, and only one, scalar type, then this will
+///   be the name of it.
+/// - tb: If your types are generic over one, and only one, scalar type, then this will
+///   be the bound on it, usually [Scalar](crate::scalar::Scalar).
 ///
 /// # Examples
 ///
@@ -307,21 +344,21 @@ macro_rules! pv_value_impl {
 /// ```
 #[macro_export]
 macro_rules! pv_inplace_impl {
-    ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        inplace_impl!{$imp;$func;$op; 0 $rhs; for $lhs; const $gen: $gent$(; <$lt>)?}
-        inplace_impl!{$imp;$func;$op; 0 &$rhs; for $lhs; const $gen: $gent$(; <$lt>)?}
+    ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        inplace_impl!{$imp;$func;$op; 0 $rhs; for $lhs; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        inplace_impl!{$imp;$func;$op; 0 &$rhs; for $lhs; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
     };
-    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        inplace_impl!{$imp;$func;$op; 1 $rhs; for $lhs; const $gen: $gent$(; <$lt>)?}
-        inplace_impl!{$imp;$func;$op; 1 &$rhs; for $lhs; const $gen: $gent$(; <$lt>)?}
+    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        inplace_impl!{$imp;$func;$op; 1 $rhs; for $lhs; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        inplace_impl!{$imp;$func;$op; 1 &$rhs; for $lhs; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
     };
-    ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
-        inplace_impl!{$imp;$func;$op; 0 $rhs; for $lhs$(; <$lt>)?}
-        inplace_impl!{$imp;$func;$op; 0 &$rhs; for $lhs$(; <$lt>)?}
+    ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        inplace_impl!{$imp;$func;$op; 0 $rhs; for $lhs$(; <$lt>)?$(; $tp: $tb)?}
+        inplace_impl!{$imp;$func;$op; 0 &$rhs; for $lhs$(; <$lt>)?$(; $tp: $tb)?}
     };
-    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
-        inplace_impl!{$imp;$func;$op; 1 $rhs; for $lhs$(; <$lt>)?}
-        inplace_impl!{$imp;$func;$op; 1 &$rhs; for $lhs$(; <$lt>)?}
+    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        inplace_impl!{$imp;$func;$op; 1 $rhs; for $lhs$(; <$lt>)?$(; $tp: $tb)?}
+        inplace_impl!{$imp;$func;$op; 1 &$rhs; for $lhs$(; <$lt>)?$(; $tp: $tb)?}
     };
 }
 
@@ -337,12 +374,18 @@ macro_rules! pv_inplace_impl {
 ///
 /// - rhs: This is the type on the right hand side of the operation.
 /// - lhs: This is the type on the left hand side of the operation.
+/// - out: This is the scalar type that each dot product results in, so for two arrays of
+///   Vec3 this is f32. It can be left out along with its `;`, in which case it is f32.
 /// - gen: If your your types include one, and only one, const generic in total, then this
-///    will be the name of it.
+///   will be the name of it.
 /// - gent: If your your types include one, and only one, const generic in total, then this
-///    will be the type of that const generic.
+///   will be the type of that const generic.
 /// - lt: If your your types include one, and only one, lifetime in total, then this will
 ///   be the name for it.
+/// - tp: If your types are generic over one, and only one, scalar type, then this will
+///   be the name of it.
+/// - tb: If your types are generic over one, and only one, scalar type, then this will
+///   be the bound on it, usually [Scalar](crate::scalar::Scalar).
 ///
 /// # Examples
 ///
@@ -351,7 +394,6 @@ macro_rules! pv_inplace_impl {
 /// use std::ops::Mul;
 /// use std::ops::Deref;
 /// use std::ops::DerefMut;
-/// use std::mem::MaybeUninit;
 /// use lineq::vec3::Vec3;
 /// use lineq::deref_impl;
 /// use lineq::deref_mut_impl;
@@ -375,7 +417,6 @@ macro_rules! pv_inplace_impl {
 /// # use std::ops::Mul;
 /// # use std::ops::Deref;
 /// # use std::ops::DerefMut;
-/// # use std::mem::MaybeUninit;
 /// # use lineq::vec3::Vec3;
 /// # use lineq::deref_impl;
 /// # use lineq::deref_mut_impl;
@@ -387,24 +428,30 @@ macro_rules! pv_inplace_impl {
 /// # deref_mut_impl! {DerefMut val V3arr<N>; to [Vec3; N]; const N: usize}
 /// # deref_impl! {Deref val V3win<'_>; to [Vec3]}
 /// # deref_mut_impl! {DerefMut val V3win<'_>; to [Vec3]}
-/// dot_impl! {Dot V3win<'a>; for V3arr<N>; const N: usize; <'a>}
-/// dot_impl! {Dot &V3win<'a>; for V3arr<N>; const N: usize; <'a>}
-/// dot_impl! {Dot V3win<'a>; for &V3arr<N>; const N: usize; <'a>}
-/// dot_impl! {Dot &V3win<'a>; for &V3arr<N>; const N: usize; <'a>}
+/// dot_impl! {Dot V3win<'a>; for V3arr<N>; out: f32; const N: usize; <'a>}
+/// dot_impl! {Dot &V3win<'a>; for V3arr<N>; out: f32; const N: usize; <'a>}
+/// dot_impl! {Dot V3win<'a>; for &V3arr<N>; out: f32; const N: usize; <'a>}
+/// dot_impl! {Dot &V3win<'a>; for &V3arr<N>; out: f32; const N: usize; <'a>}
 /// ```
 #[macro_export]
 macro_rules! pv_dot_impl {
+    (Dot $rhs:ty; for $lhs:ty; out: $out:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        dot_impl!{Dot $rhs; for $lhs; out: $out$(; <$lt>)?$(; $tp: $tb)?}
+        dot_impl!{Dot &$rhs; for $lhs; out: $out$(; <$lt>)?$(; $tp: $tb)?}
+        dot_impl!{Dot $rhs; for &$lhs; out: $out$(; <$lt>)?$(; $tp: $tb)?}
+        dot_impl!{Dot &$rhs; for &$lhs; out: $out$(; <$lt>)?$(; $tp: $tb)?}
+    };
+    (Dot $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        dot_impl!{Dot $rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        dot_impl!{Dot &$rhs; for $lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        dot_impl!{Dot $rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+        dot_impl!{Dot &$rhs; for &$lhs; out: $out; const $gen: $gent$(; <$lt>)?$(; $tp: $tb)?}
+    };
     (Dot $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
-        dot_impl!{Dot $rhs; for $lhs$(; <$lt>)?}
-        dot_impl!{Dot &$rhs; for $lhs$(; <$lt>)?}
-        dot_impl!{Dot $rhs; for &$lhs$(; <$lt>)?}
-        dot_impl!{Dot &$rhs; for &$lhs$(; <$lt>)?}
+        $crate::pv_dot_impl!{Dot $rhs; for $lhs; out: f32$(; <$lt>)?}
     };
     (Dot $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        dot_impl!{Dot $rhs; for $lhs; const $gen: $gent$(; <$lt>)?}
-        dot_impl!{Dot &$rhs; for $lhs; const $gen: $gent$(; <$lt>)?}
-        dot_impl!{Dot $rhs; for &$lhs; const $gen: $gent$(; <$lt>)?}
-        dot_impl!{Dot &$rhs; for &$lhs; const $gen: $gent$(; <$lt>)?}
+        $crate::pv_dot_impl!{Dot $rhs; for $lhs; out: f32; const $gen: $gent$(; <$lt>)?}
     };
 }
 
@@ -417,6 +464,11 @@ macro_rules! pv_dot_impl {
 /// For example for form 2, the first type mentioned, ie rhs, will be an array while the second type,
 /// ie lhs, will be a value as the binary representation is 0b10.
 ///
+/// The forms without a const generic compute with the crate's SIMD kernels, so the elements of
+/// their arrays have to be [Vec2](crate::vec2::Vec2) or [Vec3](crate::vec3::Vec3), and the
+/// output has to have a `new_uninit_box` function, like
+/// [Vec3box::new_uninit_box](crate::vec3arr::Vec3box::new_uninit_box).
+///
 /// # Arguments
 ///
 /// - imp: The name of the implementation for the operation that you are calling.
@@ -427,13 +479,18 @@ macro_rules! pv_dot_impl {
 /// - rhs: This is the type on the right hand side of the operation.
 /// - lhs: This is the type on the left hand side of the operation.
 /// - out: This is the type that results after the operation, so if you add a f32 to a f32, your
-///   output type is f32.
+///   output type is f32. The forms with a const generic build it with an `ftoarr` function, like
+///   [Vec3arr::ftoarr](crate::vec3arr::Vec3arr::ftoarr).
 /// - gen: If your your types include one, and only one, const generic in total, then this
-///    will be the name of it.
+///   will be the name of it.
 /// - gent: If your your types include one, and only one, const generic in total, then this
-///    will be the type of that const generic.
+///   will be the type of that const generic.
 /// - lt: If your your types include one, and only one, lifetime in total, then this will
 ///   be the name for it.
+/// - tp: If your types are generic over one, and only one, scalar type, then this will
+///   be the name of it.
+/// - tb: If your types are generic over one, and only one, scalar type, then this will
+///   be the bound on it, usually [Scalar](crate::scalar::Scalar).
 ///
 /// # Examples
 ///
@@ -442,7 +499,6 @@ macro_rules! pv_dot_impl {
 /// use std::ops::Add;
 /// use std::ops::Deref;
 /// use std::ops::DerefMut;
-/// use std::mem::MaybeUninit;
 /// use lineq::deref_impl;
 /// use lineq::deref_mut_impl;
 /// use lineq::value_impl;
@@ -450,6 +506,12 @@ macro_rules! pv_dot_impl {
 /// struct F32arr<const N: usize>([f32; N]);
 /// struct F32win<'a>(&'a mut [f32]);
 ///
+/// impl<const N: usize> F32arr<N> {
+///     fn ftoarr<F: Fn(usize) -> f32>(f: F) -> F32arr<N> {
+///         F32arr(std::array::from_fn(f))
+///     }
+/// }
+///
 /// deref_impl! {Deref val F32arr<N>; to [f32; N]; const N: usize}
 /// deref_mut_impl! {DerefMut val F32arr<N>; to [f32; N]; const N: usize}
 ///
@@ -464,12 +526,12 @@ macro_rules! pv_dot_impl {
 /// # use std::ops::Add;
 /// # use std::ops::Deref;
 /// # use std::ops::DerefMut;
-/// # use std::mem::MaybeUninit;
 /// # use lineq::deref_impl;
 /// # use lineq::deref_mut_impl;
 /// # use lineq::value_impl;
 /// # struct F32arr<const N: usize>([f32; N]);
 /// # struct F32win<'a>(&'a mut [f32]);
+/// # impl<const N: usize> F32arr<N> { fn ftoarr<F: Fn(usize) -> f32>(f: F) -> F32arr<N> { F32arr(std::array::from_fn(f)) } }
 /// # deref_impl! {Deref val F32arr<N>; to [f32; N]; const N: usize}
 /// # deref_mut_impl! {DerefMut val F32arr<N>; to [f32; N]; const N: usize}
 /// # deref_impl! {Deref val F32win<'_>; to [f32]}
@@ -480,103 +542,101 @@ macro_rules! pv_dot_impl {
 ///     fn add(self, rhs: F32win<'a>) -> F32arr<N> {
 ///         if self.len() != rhs.len() { panic!("slice and array inequal length"); }
 ///         // The panic message and the inclusing of the panic! alltogether depend on the mode and the inputs.
-///         let mut tmp: F32arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-///         // The way that tmp is initialized and filled depends on the inputs to the macro.
-///         for i in 0..N {
-///             tmp[i] = self[i] + rhs[i];
-///         }
-///         unsafe { std::mem::transmute::<_, F32arr<N>>(tmp) }
+///         // The way that the output is filled depends on the inputs to the macro.
+///         F32arr::ftoarr(|i| self[i] + rhs[i])
 ///     }
 /// }
 /// ```
+///
+/// The forms without a const generic work the same way on boxed arrays defined outside this crate:
+/// ```rust
+/// # extern crate lineq;
+/// use std::mem::MaybeUninit;
+/// use std::ops::{Add, Deref, DerefMut, Mul};
+/// use lineq::vec3::Vec3;
+/// use lineq::{deref_impl, deref_mut_impl, value_impl};
+///
+/// struct MyBox(Box<[Vec3]>);
+///
+/// impl MyBox {
+///     fn new_uninit_box(len: usize) -> Box<[MaybeUninit<Vec3>]> {
+///         Box::new_uninit_slice(len)
+///     }
+/// }
+///
+/// deref_impl! {Deref val MyBox; to [Vec3]}
+/// deref_mut_impl! {DerefMut val MyBox; to [Vec3]}
+///
+/// value_impl! {Add;add;+; 3 &MyBox; for &MyBox; out: MyBox}
+/// value_impl! {Mul;mul;*; 1 f32; for &MyBox; out: MyBox}
+/// value_impl! {Mul;mul;*; 2 &MyBox; for f32; out: MyBox}
+///
+/// let a : MyBox = MyBox(Box::new([Vec3::UP, Vec3::RIGHT, Vec3 { x: 1.0, y: 2.0, z: 3.0 }]));
+/// let b : MyBox = &a + &a;
+/// assert_eq!(&b[..], &(&a * 2.0)[..]);
+/// assert_eq!(&b[..], &(2.0 * &a)[..]);
+/// ```
 #[macro_export]
 macro_rules! value_impl {
-    ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty; out: $out:tt$(; <$lt:lifetime>)?) => {
-        impl$(<$lt>)? $imp<$rhs> for $lhs {
-            type Output = $out;
+    ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty; out: $out:ident$(<$og:ty>)?$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb)?> $imp<$rhs> for $lhs {
+            type Output = $out$(<$og>)?;
             #[inline]
-            fn $func(self, rhs: $rhs) -> $out {
+            fn $func(self, rhs: $rhs) -> $out$(<$og>)? {
                 if self.len() != rhs.len() { panic!("slices inequal length"); }
                 let mut tmp = $out::new_uninit_box(self.len());
-                let tmp = unsafe {
-                    for i in 0..self.len() {
-                        tmp[i].as_mut_ptr().write( self[i] $op rhs[i] );
-                    }
-                    tmp.assume_init()
-                };
-                $out(tmp)
+                $crate::__private::zip(&mut tmp, &self[..], &rhs[..], |a, b| a $op b);
+                $out(unsafe { tmp.assume_init() })
             }
         }
     };
-    ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:tt) => {
+    ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:ident$(<$og:ty>)?) => {
         impl $imp<$rhs> for $lhs {
-            type Output = $out;
+            type Output = $out$(<$og>)?;
             #[inline]
-            fn $func(self, rhs: $rhs) -> $out {
+            fn $func(self, rhs: $rhs) -> $out$(<$og>)? {
                 let mut tmp = $out::new_uninit_box(rhs.len());
-                let tmp = unsafe {
-                    for i in 0..rhs.len() {
-                        tmp[i].as_mut_ptr().write( self $op rhs[i] );
-                    }
-                    tmp.assume_init()
-                };
-                $out(tmp)
+                $crate::__private::scalar_zip(&mut tmp, self, &rhs[..], |a, b| a $op b);
+                $out(unsafe { tmp.assume_init() })
             }
         }
     };
-    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; out: $out:tt) => {
-        impl $imp<$rhs> for $lhs {
-            type Output = $out;
+    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; out: $out:ident$(<$og:ty>)?$(; $tp:ident: $tb:path)?) => {
+        impl$(<$tp: $tb>)? $imp<$rhs> for $lhs {
+            type Output = $out$(<$og>)?;
             #[inline]
-            fn $func(self, rhs: $rhs) -> $out {
+            fn $func(self, rhs: $rhs) -> $out$(<$og>)? {
                 let mut tmp = $out::new_uninit_box(self.len());
-                let tmp = unsafe {
-                    for i in 0..self.len() {
-                        tmp[i].as_mut_ptr().write( self[i] $op rhs );
-                    }
-                    tmp.assume_init()
-                };
-                $out(tmp)
+                $crate::__private::zip_scalar(&mut tmp, &self[..], rhs, |a, b| a $op b);
+                $out(unsafe { tmp.assume_init() })
             }
         }
     };
-    ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        impl<$($lt,)?const $gen: $gent> $imp<$rhs> for $lhs {
+    ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb,)?const $gen: $gent> $imp<$rhs> for $lhs {
             type Output = $out;
             #[inline]
             fn $func(self, rhs: $rhs) -> $out {
                 if self.len() != rhs.len() { panic!("slice and array inequal length"); }
-                let mut tmp: $out = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..$gen {
-                    tmp[i] = self[i] $op rhs[i];
-                }
-                unsafe { std::mem::transmute::<_, $out>(tmp) }
+                <$out>::ftoarr(|i| self[i] $op rhs[i])
             }
         }
     };
-    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        impl<$($lt,)?const $gen: $gent> $imp<$rhs> for $lhs {
+    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb,)?const $gen: $gent> $imp<$rhs> for $lhs {
             type Output = $out;
             #[inline]
             fn $func(self, rhs: $rhs) -> $out {
-                let mut tmp: $out = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..$gen {
-                    tmp[i] = self[i] $op rhs;
-                }
-                unsafe { std::mem::transmute::<_, $out>(tmp) }
+                <$out>::ftoarr(|i| self[i] $op rhs)
             }
         }
     };
-    ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        impl<$($lt,)?const $gen: $gent> $imp<$rhs> for $lhs {
+    ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb,)?const $gen: $gent> $imp<$rhs> for $lhs {
             type Output = $out;
             #[inline]
             fn $func(self, rhs: $rhs) -> $out {
-                let mut tmp: $out = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..$gen {
-                    tmp[i] = self $op rhs[i];
-                }
-                unsafe { std::mem::transmute::<_, $out>(tmp) }
+                <$out>::ftoarr(|i| self $op rhs[i])
             }
         }
     };
@@ -589,6 +649,9 @@ macro_rules! value_impl {
 /// This macro has two forms, marked with a 0 or 1. These numbers mark in binary
 /// about whether rhs is an array type or a single value (1 or 0 respectively).
 ///
+/// The forms without a const generic compute with the crate's SIMD kernels, so the elements of
+/// their arrays have to be [Vec2](crate::vec2::Vec2) or [Vec3](crate::vec3::Vec3).
+///
 /// # Arguments
 ///
 /// - imp: The name of the implementation for the operation that you are calling.
@@ -599,11 +662,15 @@ macro_rules! value_impl {
 /// - rhs: This is the type on the right hand side of the operation.
 /// - lhs: This is the type on the left hand side of the operation.
 /// - gen: If your your types include one, and only one, const generic in total, then this
-///    will be the name of it.
+///   will be the name of it.
 /// - gent: If your your types include one, and only one, const generic in total, then this
-///    will be the type of that const generic.
+///   will be the type of that const generic.
 /// - lt: If your your types include one, and only one, lifetime in total, then this will
 ///   be the name for it.
+/// - tp: If your types are generic over one, and only one, scalar type, then this will
+///   be the name of it.
+/// - tb: If your types are generic over one, and only one, scalar type, then this will
+///   be the bound on it, usually [Scalar](crate::scalar::Scalar).
 ///
 /// # Examples
 ///
@@ -654,10 +721,32 @@ macro_rules! value_impl {
 ///     }
 /// }
 /// ```
+///
+/// The forms without a const generic work the same way on boxed arrays defined outside this crate:
+/// ```rust
+/// # extern crate lineq;
+/// use std::ops::{AddAssign, Deref, DerefMut, MulAssign};
+/// use lineq::vec3::Vec3;
+/// use lineq::{deref_impl, deref_mut_impl, inplace_impl};
+///
+/// struct MyBox(Box<[Vec3]>);
+///
+/// deref_impl! {Deref val MyBox; to [Vec3]}
+/// deref_mut_impl! {DerefMut val MyBox; to [Vec3]}
+///
+/// inplace_impl! {AddAssign;add_assign;+=; 1 &MyBox; for MyBox}
+/// inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for MyBox}
+///
+/// let mut a : MyBox = MyBox(Box::new([Vec3::UP, Vec3 { x: 1.0, y: 2.0, z: 3.0 }]));
+/// let b : MyBox = MyBox(Box::new([Vec3::UP, Vec3 { x: 1.0, y: 2.0, z: 3.0 }]));
+/// a += &b;
+/// a *= 0.5;
+/// assert_eq!(&a[..], &b[..]);
+/// ```
 #[macro_export]
 macro_rules! inplace_impl {
-    ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        impl<$($lt,)?const $gen: $gent> $imp<$rhs> for $lhs {
+    ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb,)?const $gen: $gent> $imp<$rhs> for $lhs {
             #[inline]
             fn $func(&mut self, rhs: $rhs) {
                 for i in 0..self.len() {
@@ -666,8 +755,8 @@ macro_rules! inplace_impl {
             }
         }
     };
-    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        impl<$($lt,)?const $gen: $gent> $imp<$rhs> for $lhs {
+    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb,)?const $gen: $gent> $imp<$rhs> for $lhs {
             #[inline]
             fn $func(&mut self, rhs: $rhs) {
                 if self.len() != rhs.len() { panic!("slice and array inequal length"); }
@@ -677,24 +766,20 @@ macro_rules! inplace_impl {
             }
         }
     };
-    ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
-        impl$(<$lt>)? $imp<$rhs> for $lhs {
+    ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb)?> $imp<$rhs> for $lhs {
             #[inline]
             fn $func(&mut self, rhs: $rhs) {
-                for i in 0..self.len() {
-                    self[i] $op rhs;
-                }
+                $crate::__private::zip_scalar_assign(&mut self[..], rhs, |mut a, b| { a $op b; a });
             }
         }
     };
-    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
-        impl$(<$lt>)? $imp<$rhs> for $lhs {
+    ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb)?> $imp<$rhs> for $lhs {
             #[inline]
             fn $func(&mut self, rhs: $rhs) {
                 if self.len() != rhs.len() { panic!("slices inequal length"); }
-                for i in 0..self.len() {
-                    self[i] $op rhs[i];
-                }
+                $crate::__private::zip_assign(&mut self[..], &rhs[..], |mut a, b| { a $op b; a });
             }
         }
     };
@@ -708,16 +793,25 @@ macro_rules! inplace_impl {
 /// This macro has two forms, marked with a 0 or 1. These numbers mark in binary
 /// about whether rhs is an array type or a single value (1 or 0 respectively).
 ///
+/// The form without a const generic computes with the crate's SIMD kernels, so the elements of
+/// its arrays have to be [Vec2](crate::vec2::Vec2) or [Vec3](crate::vec3::Vec3).
+///
 /// # Arguments
 ///
 /// - rhs: This is the type on the right hand side of the operation.
 /// - lhs: This is the type on the left hand side of the operation.
+/// - out: This is the scalar type that each dot product results in, so for two arrays of
+///   Vec3 this is f32. It can be left out along with its `;`, in which case it is f32.
 /// - gen: If your your types include one, and only one, const generic in total, then this
-///    will be the name of it.
+///   will be the name of it.
 /// - gent: If your your types include one, and only one, const generic in total, then this
-///    will be the type of that const generic.
+///   will be the type of that const generic.
 /// - lt: If your your types include one, and only one, lifetime in total, then this will
 ///   be the name for it.
+/// - tp: If your types are generic over one, and only one, scalar type, then this will
+///   be the name of it.
+/// - tb: If your types are generic over one, and only one, scalar type, then this will
+///   be the bound on it, usually [Scalar](crate::scalar::Scalar).
 ///
 /// # Examples
 ///
@@ -726,7 +820,6 @@ macro_rules! inplace_impl {
 /// use std::ops::Mul;
 /// use std::ops::Deref;
 /// use std::ops::DerefMut;
-/// use std::mem::MaybeUninit;
 /// use lineq::vec3::Vec3;
 /// use lineq::deref_impl;
 /// use lineq::deref_mut_impl;
@@ -749,7 +842,6 @@ macro_rules! inplace_impl {
 /// # use std::ops::Mul;
 /// # use std::ops::Deref;
 /// # use std::ops::DerefMut;
-/// # use std::mem::MaybeUninit;
 /// # use lineq::vec3::Vec3;
 /// # use lineq::deref_impl;
 /// # use lineq::deref_mut_impl;
@@ -766,46 +858,57 @@ macro_rules! inplace_impl {
 ///     fn mul(self, rhs: V3win<'a>) -> [f32; N] {
 ///         if self.len() != rhs.len() { panic!("slice and array inequal length"); }
 ///         // The panic message and the inclusing of the panic! alltogether depend on the mode and the inputs.
-///         let mut tmp: [f32; N] = unsafe { MaybeUninit::uninit().assume_init() };
-///         // The way that tmp is initialized and filled depends on the inputs to the macro.
-///         for i in 0..N {
-///             tmp[i] = self[i]*rhs[i];
-///         }
-///         unsafe { std::mem::transmute::<_, [f32; N]>(tmp) }
+///         // The way that the output is filled depends on the inputs to the macro.
+///         std::array::from_fn(|i| self[i]*rhs[i])
 ///     }
 /// }
 /// ```
+///
+/// The form without a const generic works the same way on boxed arrays defined outside this crate:
+/// ```rust
+/// # extern crate lineq;
+/// use std::ops::{Deref, DerefMut, Mul};
+/// use lineq::vec3::Vec3;
+/// use lineq::{deref_impl, deref_mut_impl, dot_impl};
+///
+/// struct MyBox(Box<[Vec3]>);
+///
+/// deref_impl! {Deref val MyBox; to [Vec3]}
+/// deref_mut_impl! {DerefMut val MyBox; to [Vec3]}
+///
+/// dot_impl! {Dot &MyBox; for &MyBox; out: f32}
+///
+/// let a : MyBox = MyBox(Box::new([Vec3::UP, Vec3 { x: 1.0, y: 2.0, z: 3.0 }]));
+/// assert_eq!(&*(&a * &a), &[1.0, 14.0]);
+/// ```
 #[macro_export]
 macro_rules! dot_impl {
-    (Dot $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
-        impl$(<$lt>)? Mul<$rhs> for $lhs {
-            type Output = Box<[f32]>;
+    (Dot $rhs:ty; for $lhs:ty; out: $out:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb)?> Mul<$rhs> for $lhs {
+            type Output = Box<[$out]>;
             #[inline]
-            fn mul(self, rhs: $rhs) -> Box<[f32]> {
+            fn mul(self, rhs: $rhs) -> Box<[$out]> {
                 if self.len() != rhs.len() { panic!("slices inequal length"); }
-                let mut tmp = Box::<[f32]>::new_uninit_slice(self.len());
-                let tmp = unsafe {
-                    for i in 0..self.len() {
-                        tmp[i].as_mut_ptr().write(self[i]*rhs[i]);
-                    }
-                    tmp.assume_init()
-                };
-                tmp
+                let mut tmp = Box::<[$out]>::new_uninit_slice(self.len());
+                $crate::__private::dot(&mut tmp, &self[..], &rhs[..]);
+                unsafe { tmp.assume_init() }
             }
         }
     };
-    (Dot $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
-        impl<$($lt,)?const $gen: $gent> Mul<$rhs> for $lhs {
-            type Output = [f32; N];
+    (Dot $rhs:ty; for $lhs:ty; out: $out:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?$(; $tp:ident: $tb:path)?) => {
+        impl<$($lt,)?$($tp: $tb,)?const $gen: $gent> Mul<$rhs> for $lhs {
+            type Output = [$out; $gen];
             #[inline]
-            fn mul(self, rhs: $rhs) -> [f32; N] {
+            fn mul(self, rhs: $rhs) -> [$out; $gen] {
                 if self.len() != rhs.len() { panic!("slice and array inequal length"); }
-                let mut tmp: [f32; N] = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..$gen {
-                    tmp[i] = self[i]*rhs[i];
-                }
-                unsafe { std::mem::transmute::<_, [f32; N]>(tmp) }
+                std::array::from_fn(|i| self[i]*rhs[i])
             }
         }
     };
+    (Dot $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
+        $crate::dot_impl!{Dot $rhs; for $lhs; out: f32$(; <$lt>)?}
+    };
+    (Dot $rhs:ty; for $lhs:ty; const $gen:ident: $gent:ty$(; <$lt:lifetime>)?) => {
+        $crate::dot_impl!{Dot $rhs; for $lhs; out: f32; const $gen: $gent$(; <$lt>)?}
+    };
 }
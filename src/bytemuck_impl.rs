@@ -0,0 +1,54 @@
+//! Pod and Zeroable implementations for the vector and matrix structs.
+//!
+//! All of these are `#[repr(C)]` structs with fields of a single scalar type, or
+//! `#[repr(transparent)]` arrays of them, so they have no padding and any bit pattern of their
+//! scalars is valid.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! # extern crate bytemuck;
+//! use lineq::vec3::{Vec3, Vector3};
+//! use lineq::mat::Mat22;
+//! use lineq::vec3arr::Vec3box;
+//! let a : Vec3box = Vec3box::ftoarr(|i| Vec3 { x: i as f32, y: 1.0, z: 2.0 }, 2);
+//! let floats : &[f32] = bytemuck::cast_slice(&a);
+//! assert_eq!(floats, &[0.0, 1.0, 2.0, 1.0, 1.0, 2.0]);
+//! let bytes : &[u8] = bytemuck::cast_slice(&a);
+//! assert_eq!(bytes.len(), 24);
+//! assert_eq!(bytemuck::cast_slice::<u8, Vec3>(bytes), &a[..]);
+//! let m : Mat22 = Mat22 { x1: 1.0, x2: 2.0, y1: 3.0, y2: 4.0 };
+//! assert_eq!(bytemuck::cast::<Mat22, [f32; 4]>(m), [1.0, 3.0, 2.0, 4.0]);
+//! assert_eq!(<Vector3<f64> as bytemuck::Zeroable>::zeroed(), Vector3::ZERO);
+//! ```
+
+use crate::mat::{Matrix22, Matrix33, Matrix44};
+use crate::quat::Quaternion;
+use crate::vec2::Vector2;
+use crate::vec2arr::Vector2arr;
+use crate::vec3::Vector3;
+use crate::vec3arr::Vector3arr;
+use crate::vec4::Vector4;
+use bytemuck::{Pod, Zeroable};
+
+macro_rules! pod_impl {
+        (Pod $t:ident) => {
+                unsafe impl<T: Zeroable> Zeroable for $t<T> {}
+                unsafe impl<T: Pod> Pod for $t<T> {}
+        };
+        (Pod $t:ident; const N) => {
+                unsafe impl<const N: usize, T: Zeroable> Zeroable for $t<N, T> {}
+                unsafe impl<const N: usize, T: Pod> Pod for $t<N, T> {}
+        };
+}
+
+pod_impl! {Pod Vector2}
+pod_impl! {Pod Vector3}
+pod_impl! {Pod Vector4}
+pod_impl! {Pod Quaternion}
+pod_impl! {Pod Matrix22}
+pod_impl! {Pod Matrix33}
+pod_impl! {Pod Matrix44}
+pod_impl! {Pod Vector2arr; const N}
+pod_impl! {Pod Vector3arr; const N}
@@ -0,0 +1,288 @@
+//! Module containing Cholesky factorizations of symmetric positive definite matricies.
+//!
+//! [Cholesky](crate::chol::Cholesky) factors `A = L Lᵀ` and [LDL](crate::chol::LDL) factors
+//! `A = L D Lᵀ` without taking square roots. Both only read the lower triangle of A and fail with
+//! [NotPositiveDefinite](crate::error::LineqError::NotPositiveDefinite) when A is not positive
+//! definite, so factoring is also a cheap test for it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::Vector3;
+//! use lineq::mat::Matrix33;
+//! use lineq::error::LineqError;
+//! use lineq::chol::Cholesky;
+//! let cov : Matrix33<f64> = Matrix33 { x1: 4.0, x2: 2.0, x3: 0.4,
+//!                                y1: 2.0, y2: 2.0, y3: 0.6,
+//!                                z1: 0.4, z2: 0.6, z3: 1.0 };
+//! let chol : Cholesky<f64> = Cholesky::new_from_mat33(cov).unwrap();
+//! let x : Vector3<f64> = chol.solve_vec3(Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+//! assert!((cov*x - Vector3 { x: 1.0, y: 2.0, z: 3.0 }).mag() < 1e-12);
+//! assert!((chol.log_det() - cov.det().ln()).abs() < 1e-12);
+//! let indefinite : Matrix33<f64> = Matrix33 { x1: 1.0, x2: 2.0, x3: 0.0,
+//!                                       y1: 2.0, y2: 1.0, y3: 0.0,
+//!                                       z1: 0.0, z2: 0.0, z3: 1.0 };
+//! assert_eq!(Cholesky::new_from_mat33(indefinite), Err(LineqError::NotPositiveDefinite));
+//! ```
+//!
+//! A matrix with a NaN in it is not positive definite either:
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::matn::MatrixN;
+//! use lineq::error::LineqError;
+//! use lineq::chol::{Cholesky, LDL};
+//! let a : MatrixN<f64> = MatrixN::from_rows(2, 2, Box::new([f64::NAN, 0.0,
+//!                                                     0.0, 1.0]));
+//! assert_eq!(Cholesky::new_from_matn(&a), Err(LineqError::NotPositiveDefinite));
+//! assert_eq!(LDL::new_from_matn(&a), Err(LineqError::NotPositiveDefinite));
+//! ```
+
+use crate::scalar::Scalar;
+use crate::vec3::Vector3;
+use crate::mat::Matrix33;
+use crate::error::LineqError;
+use crate::matn::{MatrixN, VectorN};
+
+/// The factorization `A = L Lᵀ` of a symmetric positive definite matrix, where L is lower
+/// triangular with a positive diagonal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cholesky<T = f32> {
+        n : usize,
+        l : Box<[T]>,
+}
+
+/// The factorization `A = L D Lᵀ` of a symmetric positive definite matrix, where L is lower
+/// triangular with ones on the diagonal and D is diagonal and positive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LDL<T = f32> {
+        n : usize,
+        l : Box<[T]>,
+        d : Box<[T]>,
+}
+
+// The largest diagonal entry of the n by n row-major matrix in `a`, which pivots are measured against.
+fn scale<T: Scalar>(a : &[T], n : usize) -> T {
+	let mut scale : T = T::ZERO;
+	for i in 0..n {
+		if a[i*n + i].abs() > scale { scale = a[i*n + i].abs(); }
+	}
+	scale
+}
+
+// Solves L y = x in place, where L is lower triangular, with an implied unit diagonal if `unit`.
+fn forward<T: Scalar>(l : &[T], unit : bool, x : &mut [T]) {
+	let n : usize = x.len();
+	for i in 0..n {
+		let mut sum : T = x[i];
+		for j in 0..i {
+			sum -= l[i*n + j]*x[j];
+		}
+		x[i] = if unit { sum } else { sum/l[i*n + i] };
+	}
+}
+
+// Solves Lᵀ y = x in place, where L is lower triangular, with an implied unit diagonal if `unit`.
+fn backward<T: Scalar>(l : &[T], unit : bool, x : &mut [T]) {
+	let n : usize = x.len();
+	for i in (0..n).rev() {
+		let mut sum : T = x[i];
+		for j in i+1..n {
+			sum -= l[j*n + i]*x[j];
+		}
+		x[i] = if unit { sum } else { sum/l[i*n + i] };
+	}
+}
+
+//Cholesky Methods
+
+impl<T: Scalar> Cholesky<T> {
+
+	/// Factors a square [MatN](crate::matn::MatN), panicking if it is not square.
+	pub fn new_from_matn(a : &MatrixN<T>) -> Result<Cholesky<T>, LineqError> {
+		if a.rows() != a.cols() { panic!("matrix is not square"); }
+		Cholesky::new_from_rows(a.rows(), a.as_slice())
+	}
+
+	pub fn new_from_mat33(a : Matrix33<T>) -> Result<Cholesky<T>, LineqError> {
+		Cholesky::new_from_rows(3, &[a.x1, a.x2, a.x3, a.y1, a.y2, a.y3, a.z1, a.z2, a.z3])
+	}
+
+	// the pivot test is negated so that NaN fails it, which partial_cmp would only obscure
+	#[allow(clippy::neg_cmp_op_on_partial_ord)]
+	fn new_from_rows(n : usize, a : &[T]) -> Result<Cholesky<T>, LineqError> {
+		let tol : T = scale(a, n) * T::EPSILON;
+		let mut l : Box<[T]> = vec![T::ZERO; n*n].into_boxed_slice();
+		for j in 0..n {
+			let mut d : T = a[j*n + j];
+			for k in 0..j {
+				d -= l[j*n + k]*l[j*n + k];
+			}
+			if !(d > tol) { return Err(LineqError::NotPositiveDefinite); }
+			l[j*n + j] = d.sqrt();
+			for i in j+1..n {
+				let mut sum : T = a[i*n + j];
+				for k in 0..j {
+					sum -= l[i*n + k]*l[j*n + k];
+				}
+				l[i*n + j] = sum/l[j*n + j];
+			}
+		}
+		Ok(Cholesky { n, l })
+	}
+
+	pub fn size(&self) -> usize {
+		self.n
+	}
+
+	/// The lower triangular factor L.
+	pub fn l(&self) -> MatrixN<T> {
+		MatrixN::from_rows(self.n, self.n, self.l.clone())
+	}
+
+	/// Solves `A x = b`.
+	pub fn solve(&self, b : &VectorN<T>) -> VectorN<T> {
+		if b.len() != self.n { panic!("matrix and vector dimensions do not match"); }
+		let mut x : VectorN<T> = b.clone();
+		forward(&self.l, false, &mut x);
+		backward(&self.l, false, &mut x);
+		x
+	}
+
+	pub fn solve_vec3(&self, b : Vector3<T>) -> Vector3<T> {
+		if self.n != 3 { panic!("matrix and vector dimensions do not match"); }
+		let mut x : [T; 3] = [b.x, b.y, b.z];
+		forward(&self.l, false, &mut x);
+		backward(&self.l, false, &mut x);
+		Vector3 { x: x[0], y: x[1], z: x[2] }
+	}
+
+	pub fn det(&self) -> T {
+		let mut det : T = T::ONE;
+		for i in 0..self.n {
+			det *= self.l[i*self.n + i]*self.l[i*self.n + i];
+		}
+		det
+	}
+
+	/// The natural log of the determinant, which unlike [det](crate::chol::Cholesky::det) does
+	/// not overflow or underflow for large matricies.
+	pub fn log_det(&self) -> T {
+		let mut sum : T = T::ZERO;
+		for i in 0..self.n {
+			sum += self.l[i*self.n + i].ln();
+		}
+		sum + sum
+	}
+}
+
+//LDL Methods
+
+impl<T: Scalar> LDL<T> {
+
+	/// Factors a square [MatN](crate::matn::MatN), panicking if it is not square.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::matn::{MatrixN, VectorN};
+	/// use lineq::chol::LDL;
+	/// // the normal equations of fitting a line through (0, 1), (1, 3) and (2, 4)
+	/// let a : MatrixN<f64> = MatrixN::from_rows(3, 2, Box::new([1.0, 0.0, 1.0, 1.0, 1.0, 2.0]));
+	/// let b : VectorN<f64> = VectorN(Box::new([1.0, 3.0, 4.0]));
+	/// let ldl : LDL<f64> = LDL::new_from_matn(&(&a.t() * &a)).unwrap();
+	/// let x : VectorN<f64> = ldl.solve(&(&a.t() * &b));
+	/// assert!((x[0] - 7.0/6.0).abs() < 1e-12 && (x[1] - 1.5).abs() < 1e-12);
+	/// assert_eq!(ldl.d()[0], 3.0);
+	/// ```
+	pub fn new_from_matn(a : &MatrixN<T>) -> Result<LDL<T>, LineqError> {
+		if a.rows() != a.cols() { panic!("matrix is not square"); }
+		LDL::new_from_rows(a.rows(), a.as_slice())
+	}
+
+	pub fn new_from_mat33(a : Matrix33<T>) -> Result<LDL<T>, LineqError> {
+		LDL::new_from_rows(3, &[a.x1, a.x2, a.x3, a.y1, a.y2, a.y3, a.z1, a.z2, a.z3])
+	}
+
+	// the pivot test is negated so that NaN fails it, which partial_cmp would only obscure
+	#[allow(clippy::neg_cmp_op_on_partial_ord)]
+	fn new_from_rows(n : usize, a : &[T]) -> Result<LDL<T>, LineqError> {
+		let tol : T = scale(a, n) * T::EPSILON;
+		let mut l : Box<[T]> = vec![T::ZERO; n*n].into_boxed_slice();
+		let mut d : Box<[T]> = vec![T::ZERO; n].into_boxed_slice();
+		for j in 0..n {
+			let mut dj : T = a[j*n + j];
+			for k in 0..j {
+				dj -= l[j*n + k]*l[j*n + k]*d[k];
+			}
+			if !(dj > tol) { return Err(LineqError::NotPositiveDefinite); }
+			d[j] = dj;
+			l[j*n + j] = T::ONE;
+			for i in j+1..n {
+				let mut sum : T = a[i*n + j];
+				for k in 0..j {
+					sum -= l[i*n + k]*l[j*n + k]*d[k];
+				}
+				l[i*n + j] = sum/dj;
+			}
+		}
+		Ok(LDL { n, l, d })
+	}
+
+	pub fn size(&self) -> usize {
+		self.n
+	}
+
+	/// The lower triangular factor L, with ones on the diagonal.
+	pub fn l(&self) -> MatrixN<T> {
+		MatrixN::from_rows(self.n, self.n, self.l.clone())
+	}
+
+	/// The diagonal of D.
+	pub fn d(&self) -> VectorN<T> {
+		VectorN(self.d.clone())
+	}
+
+	/// Solves `A x = b`.
+	pub fn solve(&self, b : &VectorN<T>) -> VectorN<T> {
+		if b.len() != self.n { panic!("matrix and vector dimensions do not match"); }
+		let mut x : VectorN<T> = b.clone();
+		forward(&self.l, true, &mut x);
+		for (e, d) in x.iter_mut().zip(self.d.iter()) {
+			*e /= *d;
+		}
+		backward(&self.l, true, &mut x);
+		x
+	}
+
+	pub fn solve_vec3(&self, b : Vector3<T>) -> Vector3<T> {
+		if self.n != 3 { panic!("matrix and vector dimensions do not match"); }
+		let mut x : [T; 3] = [b.x, b.y, b.z];
+		forward(&self.l, true, &mut x);
+		for (e, d) in x.iter_mut().zip(self.d.iter()) {
+			*e /= *d;
+		}
+		backward(&self.l, true, &mut x);
+		Vector3 { x: x[0], y: x[1], z: x[2] }
+	}
+
+	pub fn det(&self) -> T {
+		let mut det : T = T::ONE;
+		for d in self.d.iter() {
+			det *= *d;
+		}
+		det
+	}
+
+	/// The natural log of the determinant, which unlike [det](crate::chol::LDL::det) does
+	/// not overflow or underflow for large matricies.
+	pub fn log_det(&self) -> T {
+		let mut sum : T = T::ZERO;
+		for d in self.d.iter() {
+			sum += d.ln();
+		}
+		sum
+	}
+}
@@ -0,0 +1,54 @@
+//! Module containing the error type shared by the fallible methods of the crate.
+//!
+//! Most operations panic when given input they cannot handle, the same as slices do when
+//! indexed out of bounds. Each of those has a `try_` variant returning a
+//! [LineqError](crate::error::LineqError) instead, for code that has to recover from bad input.
+//! The solvers and factorizations, which can only fail on singular or indefinite matricies,
+//! return the same error type.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::Vector3;
+//! use lineq::mat::Mat22;
+//! use lineq::error::LineqError;
+//! let a : Mat22 = Mat22 { x1: 1.0, x2: 2.0, y1: 2.0, y2: 4.0 };
+//! assert_eq!(a.try_inverse(), Err(LineqError::Singular));
+//! assert_eq!(Vector3::<f32>::ZERO.try_norm(), Err(LineqError::ZeroVector));
+//! ```
+
+use std::fmt;
+
+/// The ways that an operation in this crate can fail.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineqError {
+	/// The matrix has no inverse, or a pivot is zero to within rounding error.
+	Singular,
+	/// The matrix was expected to be symmetric positive definite, but a pivot of its
+	/// Cholesky factorization is not positive.
+	NotPositiveDefinite,
+	/// A vector with zero magnitude was normalized or inverted.
+	ZeroVector,
+	/// Two arrays that need to be the same length are not.
+	LengthMismatch { expected : usize, found : usize },
+}
+
+impl fmt::Display for LineqError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                        LineqError::Singular => write!(f, "singular matrix"),
+                        LineqError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+                        LineqError::ZeroVector => write!(f, "zero vector"),
+                        LineqError::LengthMismatch { expected, found } => write!(f, "expected length {}, found length {}", expected, found),
+                }
+        }
+}
+
+impl std::error::Error for LineqError {}
+
+// Checks that two lengths match, with `expected` the length of the array being written to.
+pub(crate) fn check_len(expected : usize, found : usize) -> Result<(), LineqError> {
+	if expected != found { return Err(LineqError::LengthMismatch { expected, found }); }
+	Ok(())
+}
@@ -0,0 +1,358 @@
+//! Module containing geometric primitives and the intersection tests between them.
+//!
+//! A [Ray3](crate::geom::Ray3) can be cast at a [Plane](crate::geom::Plane),
+//! [Sphere](crate::geom::Sphere), [Aabb3](crate::geom::Aabb3) or
+//! [Triangle3](crate::geom::Triangle3), and returns the nearest [Hit](crate::geom::Hit) in front
+//! of its origin, if there is one. Many rays at once are cast as a
+//! [Ray3box](crate::geom::Ray3box), which stores their origins and directions in two
+//! [Vec3box](crate::vec3arr::Vec3box)es.
+//!
+//! Ray directions do not need to be normalized, and hit distances are measured in multiples of
+//! them, so that the hit is at `origin + dir*t`. Hit normals are normalized, and face back
+//! against the ray, even when it hits the inside of a sphere or box.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::Vector3;
+//! use lineq::geom::{Ray3, Plane, Sphere, Aabb3, Triangle3, Hit};
+//! let ray : Ray3<f64> = Ray3 { origin: Vector3 { x: 0.0, y: 0.0, z: -5.0 }, dir: Vector3::IN };
+//! let floor : Plane<f64> = Plane::new_from_point(Vector3::IN, Vector3::ZERO);
+//! assert_eq!(ray.hit_plane(&floor), Some(Hit { t: 5.0, normal: Vector3::OUT }));
+//! let ball : Sphere<f64> = Sphere { center: Vector3::ZERO, radius: 1.0 };
+//! assert_eq!(ray.hit_sphere(&ball), Some(Hit { t: 4.0, normal: Vector3::OUT }));
+//! let cube : Aabb3<f64> = Aabb3 { min: -Vector3::ONE, max: Vector3::ONE };
+//! assert_eq!(ray.hit_aabb(&cube), Some(Hit { t: 4.0, normal: Vector3::OUT }));
+//! let tri : Triangle3<f64> = Triangle3 { a: Vector3::LEFT, b: Vector3::RIGHT, c: Vector3::UP };
+//! assert_eq!(ray.hit_triangle(&tri), Some(Hit { t: 5.0, normal: Vector3::OUT }));
+//! // nothing behind the origin is hit
+//! let away : Ray3<f64> = Ray3 { origin: ray.origin, dir: Vector3::OUT };
+//! assert_eq!(away.hit_sphere(&ball), None);
+//! assert!(cube.intersects(&Aabb3 { min: Vector3::ONE, max: Vector3::ONE*2.0 }));
+//! ```
+
+use crate::scalar::Scalar;
+use crate::vec3::Vector3;
+use crate::vec3arr::Vector3box;
+
+/// A half-line starting at `origin` and going in the direction `dir`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray3<T = f32> {
+        pub origin : Vector3<T>,
+        pub dir : Vector3<T>,
+}
+
+/// Many rays, with the origin and direction of each at the same index of two boxed arrays.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray3box<T = f32> {
+        pub origin : Vector3box<T>,
+        pub dir : Vector3box<T>,
+}
+
+/// The points p where `normal*p == d`, with `normal` not necessarily normalized.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane<T = f32> {
+        pub normal : Vector3<T>,
+        pub d : T,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere<T = f32> {
+        pub center : Vector3<T>,
+        pub radius : T,
+}
+
+/// An axis aligned bounding box, the points between `min` and `max` in every component.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb3<T = f32> {
+        pub min : Vector3<T>,
+        pub max : Vector3<T>,
+}
+
+/// A triangle with corners `a`, `b` and `c`, whose front faces the side they wind
+/// counterclockwise around.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangle3<T = f32> {
+        pub a : Vector3<T>,
+        pub b : Vector3<T>,
+        pub c : Vector3<T>,
+}
+
+/// Where a ray hits a shape, at `origin + dir*t`, and the unit normal of the surface there.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hit<T = f32> {
+        pub t : T,
+        pub normal : Vector3<T>,
+}
+
+// The unit normal facing back against `dir`.
+fn facing<T: Scalar>(normal : Vector3<T>, dir : Vector3<T>) -> Vector3<T> {
+	let normal : Vector3<T> = normal.norm();
+	if normal*dir > T::ZERO { -normal } else { normal }
+}
+
+fn min<T: Scalar>(a : T, b : T) -> T {
+	if b < a { b } else { a }
+}
+
+fn max<T: Scalar>(a : T, b : T) -> T {
+	if b > a { b } else { a }
+}
+
+//Ray3 Methods
+
+impl<T: Scalar> Ray3<T> {
+
+	/// The point `origin + dir*t`.
+	pub fn at(&self, t : T) -> Vector3<T> {
+		self.origin + self.dir*t
+	}
+
+	/// The ray hits a plane once, unless it is parallel to it.
+	pub fn hit_plane(&self, plane : &Plane<T>) -> Option<Hit<T>> {
+		let denom : T = plane.normal*self.dir;
+		if denom == T::ZERO { return None; }
+		let t : T = (plane.d - plane.normal*self.origin)/denom;
+		if t < T::ZERO { return None; }
+		Some(Hit { t, normal: facing(plane.normal, self.dir) })
+	}
+
+	/// The nearest of the points where the ray enters or leaves a sphere.
+	pub fn hit_sphere(&self, sphere : &Sphere<T>) -> Option<Hit<T>> {
+		let oc : Vector3<T> = self.origin - sphere.center;
+		let a : T = self.dir.mag2();
+		let half_b : T = oc*self.dir;
+		let disc : T = half_b*half_b - a*(oc.mag2() - sphere.radius*sphere.radius);
+		if disc < T::ZERO || a == T::ZERO { return None; }
+		let sq : T = disc.sqrt();
+		let mut t : T = (-half_b - sq)/a;
+		if t < T::ZERO { t = (-half_b + sq)/a; }
+		if t < T::ZERO { return None; }
+		Some(Hit { t, normal: facing(self.at(t) - sphere.center, self.dir) })
+	}
+
+	/// The nearest of the points where the ray enters or leaves a box, found with the slab
+	/// method. A ray from the inside hits the face it leaves through.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::geom::{Ray3, Aabb3, Hit};
+	/// let cube : Aabb3 = Aabb3 { min: Vec3::ZERO, max: Vec3::ONE };
+	/// let inside : Ray3 = Ray3 { origin: Vec3::ONE*0.5, dir: Vec3 { x: 1.0, y: 0.5, z: 0.0 } };
+	/// assert_eq!(inside.hit_aabb(&cube), Some(Hit { t: 0.5, normal: Vec3::LEFT }));
+	/// let past : Ray3 = Ray3 { origin: Vec3 { x: -1.0, y: 2.0, z: 0.5 }, dir: Vec3::RIGHT };
+	/// assert_eq!(past.hit_aabb(&cube), None);
+	/// ```
+	pub fn hit_aabb(&self, aabb : &Aabb3<T>) -> Option<Hit<T>> {
+		let o : [T; 3] = [self.origin.x, self.origin.y, self.origin.z];
+		let d : [T; 3] = [self.dir.x, self.dir.y, self.dir.z];
+		let lo : [T; 3] = [aabb.min.x, aabb.min.y, aabb.min.z];
+		let hi : [T; 3] = [aabb.max.x, aabb.max.y, aabb.max.z];
+		let (mut near, mut far) = (T::ZERO, T::ZERO);
+		let (mut near_axis, mut far_axis) = (None, None);
+		for k in 0..3 {
+			if d[k] == T::ZERO {
+				// parallel to this slab, so either always inside it or never
+				if o[k] < lo[k] || o[k] > hi[k] { return None; }
+				continue;
+			}
+			let (t1, t2) = ((lo[k] - o[k])/d[k], (hi[k] - o[k])/d[k]);
+			let (t1, t2) = (min(t1, t2), max(t1, t2));
+			if near_axis.is_none() || t1 > near { near = t1; near_axis = Some(k); }
+			if far_axis.is_none() || t2 < far { far = t2; far_axis = Some(k); }
+		}
+		let (axis, t) = match (near_axis, far_axis) {
+			(Some(n), Some(f)) if near <= far && far >= T::ZERO => if near >= T::ZERO { (n, near) } else { (f, far) },
+			_ => return None,
+		};
+		let mut normal : [T; 3] = [T::ZERO; 3];
+		normal[axis] = if d[axis] > T::ZERO { T::NEG_ONE } else { T::ONE };
+		Some(Hit { t, normal: Vector3 { x: normal[0], y: normal[1], z: normal[2] } })
+	}
+
+	/// Where the ray hits a triangle from either side, found with the Möller–Trumbore method.
+	/// Rays in the plane of the triangle never hit it.
+	pub fn hit_triangle(&self, tri : &Triangle3<T>) -> Option<Hit<T>> {
+		let (e1, e2) = (tri.b - tri.a, tri.c - tri.a);
+		let p : Vector3<T> = self.dir.cross(e2);
+		let det : T = e1*p;
+		let n : Vector3<T> = e1.cross(e2);
+		if det.abs() <= T::EPSILON*n.mag()*self.dir.mag() { return None; }
+		let s : Vector3<T> = self.origin - tri.a;
+		let u : T = s*p/det;
+		if u < T::ZERO || u > T::ONE { return None; }
+		let q : Vector3<T> = s.cross(e1);
+		let v : T = self.dir*q/det;
+		if v < T::ZERO || u + v > T::ONE { return None; }
+		let t : T = e2*q/det;
+		if t < T::ZERO { return None; }
+		Some(Hit { t, normal: facing(n, self.dir) })
+	}
+}
+
+//Ray3box Methods
+
+impl<T: Scalar> Ray3box<T> {
+
+	/// Pairs up origins and directions, panicking if there are not as many of each.
+	pub fn new_from_boxes(origin : Vector3box<T>, dir : Vector3box<T>) -> Ray3box<T> {
+		if origin.len() != dir.len() { panic!("slices inequal length"); }
+		Ray3box { origin, dir }
+	}
+
+	pub fn len(&self) -> usize {
+		self.origin.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.origin.is_empty()
+	}
+
+	/// The ray at index `i`.
+	pub fn ray(&self, i : usize) -> Ray3<T> {
+		Ray3 { origin: self.origin[i], dir: self.dir[i] }
+	}
+
+	// Casts every ray with `f`, panicking if the origins and directions are different lengths.
+	fn hit_each<F: Fn(Ray3<T>) -> Option<Hit<T>>>(&self, f : F) -> Box<[Option<Hit<T>>]> {
+		if self.origin.len() != self.dir.len() { panic!("slices inequal length"); }
+		self.origin.iter().zip(self.dir.iter()).map(|(o, d)| f(Ray3 { origin: *o, dir: *d })).collect()
+	}
+
+	/// Casts every ray at a plane, see [Ray3::hit_plane].
+	pub fn hit_plane(&self, plane : &Plane<T>) -> Box<[Option<Hit<T>>]> {
+		self.hit_each(|r| r.hit_plane(plane))
+	}
+
+	/// Casts every ray at a sphere, see [Ray3::hit_sphere].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::Vec3box;
+	/// use lineq::geom::{Ray3box, Sphere, Hit};
+	/// let rays : Ray3box = Ray3box::new_from_boxes(Vec3box::ftoarr(|i| Vec3 { x: i as f32, y: 0.0, z: -2.0 }, 3),
+	///                                              Vec3box::ftoarr(|_| Vec3::IN, 3));
+	/// let ball : Sphere = Sphere { center: Vec3::ZERO, radius: 1.0 };
+	/// assert_eq!(&*rays.hit_sphere(&ball), &[Some(Hit { t: 1.0, normal: Vec3::OUT }),
+	///                                        Some(Hit { t: 2.0, normal: Vec3::RIGHT }),
+	///                                        None]);
+	/// ```
+	pub fn hit_sphere(&self, sphere : &Sphere<T>) -> Box<[Option<Hit<T>>]> {
+		self.hit_each(|r| r.hit_sphere(sphere))
+	}
+
+	pub fn hit_aabb(&self, aabb : &Aabb3<T>) -> Box<[Option<Hit<T>>]> {
+		self.hit_each(|r| r.hit_aabb(aabb))
+	}
+
+	pub fn hit_triangle(&self, tri : &Triangle3<T>) -> Box<[Option<Hit<T>>]> {
+		self.hit_each(|r| r.hit_triangle(tri))
+	}
+}
+
+//Plane Methods
+
+impl<T: Scalar> Plane<T> {
+
+	/// The plane through `point` perpendicular to `normal`.
+	pub fn new_from_point(normal : Vector3<T>, point : Vector3<T>) -> Plane<T> {
+		Plane { normal, d: normal*point }
+	}
+
+	/// The plane through three points, with the normal facing the side they wind
+	/// counterclockwise around.
+	pub fn new_from_points(a : Vector3<T>, b : Vector3<T>, c : Vector3<T>) -> Plane<T> {
+		Plane::new_from_point((b - a).cross(c - a), a)
+	}
+
+	/// The distance from the plane to a point, positive on the side the normal faces.
+	pub fn distance(&self, p : Vector3<T>) -> T {
+		(self.normal*p - self.d)/self.normal.mag()
+	}
+}
+
+//Sphere Methods
+
+impl<T: Scalar> Sphere<T> {
+
+	pub fn contains(&self, p : Vector3<T>) -> bool {
+		(p - self.center).mag2() <= self.radius*self.radius
+	}
+
+	/// The smallest box containing the sphere.
+	pub fn aabb(&self) -> Aabb3<T> {
+		let r : Vector3<T> = Vector3 { x: self.radius, y: self.radius, z: self.radius };
+		Aabb3 { min: self.center - r, max: self.center + r }
+	}
+}
+
+//Aabb3 Methods
+
+impl<T: Scalar> Aabb3<T> {
+
+	/// The smallest box containing every point, panicking if there are none.
+	pub fn new_from_points(points : &[Vector3<T>]) -> Aabb3<T> {
+		if points.is_empty() { panic!("slice is empty"); }
+		let mut aabb : Aabb3<T> = Aabb3 { min: points[0], max: points[0] };
+		for p in points.iter() {
+			aabb.min = Vector3 { x: min(aabb.min.x, p.x), y: min(aabb.min.y, p.y), z: min(aabb.min.z, p.z) };
+			aabb.max = Vector3 { x: max(aabb.max.x, p.x), y: max(aabb.max.y, p.y), z: max(aabb.max.z, p.z) };
+		}
+		aabb
+	}
+
+	pub fn center(&self) -> Vector3<T> {
+		(self.min + self.max)/(T::ONE + T::ONE)
+	}
+
+	pub fn size(&self) -> Vector3<T> {
+		self.max - self.min
+	}
+
+	pub fn contains(&self, p : Vector3<T>) -> bool {
+		p.x >= self.min.x && p.y >= self.min.y && p.z >= self.min.z
+			&& p.x <= self.max.x && p.y <= self.max.y && p.z <= self.max.z
+	}
+
+	/// Whether two boxes overlap, including when they only touch.
+	pub fn intersects(&self, other : &Aabb3<T>) -> bool {
+		self.min.x <= other.max.x && self.min.y <= other.max.y && self.min.z <= other.max.z
+			&& other.min.x <= self.max.x && other.min.y <= self.max.y && other.min.z <= self.max.z
+	}
+}
+
+//Triangle3 Methods
+
+impl<T: Scalar> Triangle3<T> {
+
+	/// The unit normal of the front face.
+	pub fn normal(&self) -> Vector3<T> {
+		(self.b - self.a).cross(self.c - self.a).norm()
+	}
+
+	pub fn area(&self) -> T {
+		(self.b - self.a).cross(self.c - self.a).mag()/(T::ONE + T::ONE)
+	}
+
+	pub fn plane(&self) -> Plane<T> {
+		Plane::new_from_points(self.a, self.b, self.c)
+	}
+
+	pub fn aabb(&self) -> Aabb3<T> {
+		Aabb3::new_from_points(&[self.a, self.b, self.c])
+	}
+}
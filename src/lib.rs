@@ -3,12 +3,35 @@
 //! This crate provides 3 main types of structs that implement basic arithmetic operations 
 //! like + and -, and also pervide useful methods:
 //!
-//! - Structs for handling vectors like [Vec2](crate::vec2::Vec2) and [Vec3](crate::vec3::Vec3).
+//! - Structs for handling vectors like [Vec2](crate::vec2::Vec2), [Vec3](crate::vec3::Vec3) and
+//!   [Vec4](crate::vec4::Vec4).
 //! - Structs for handling arrays of vectors with different types of memory orginization, like
 //!   [Vec3arr](crate::vec3arr::Vec3arr) and [Vec3box](crate::vec3arr::Vec3box), for fixed length
 //!   arrays and boxed arrays respectively.
-//! - Structs for handling square matricies like [Mat22](crate::mat::Mat22) and
-//!   [Mat33](crate::mat::Mat33).
+//! - Structs for handling square matricies like [Mat22](crate::mat::Mat22),
+//!   [Mat33](crate::mat::Mat33) and [Mat44](crate::mat::Mat44),
+//!   along with dynamically sized [MatN](crate::matn::MatN) and [VecN](crate::matn::VecN).
+//!
+//! All of these are generic over a [Scalar](crate::scalar::Scalar) type, which is
+//! implemented for [f32] and [f64]. The generic types have the long names, like
+//! [Vector3](crate::vec3::Vector3) and [Matrix33](crate::mat::Matrix33), and the short
+//! names are aliases for [f32], so `Vec3` is `Vector3<f32>`. The aliases of tuple
+//! structs, like `Vec3box`, come with a function of the same name, since a tuple
+//! struct can not be created through an alias:
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::{Vec3, Vector3};
+//! use lineq::vec3arr::{Vec3arr, Vec3box};
+//! use lineq::mat::Mat33;
+//! let a : Vector3<f64> = Vector3 { x: 1.0, y: 2.0, z: 2.0 };
+//! assert_eq!(a.mag(), 3.0);
+//! let b = Vec3::UP;
+//! let c = Vec3box(Box::new([b, b]));
+//! assert_eq!(c, Vec3box::new_from_arr(Vec3arr([Vec3::UP; 2])));
+//! let m = Mat33::IDENTITY;
+//! let z = Vec3box::new_zeroed(2);
+//! assert_eq!(&m * &c + &z, c);
+//! ```
 //!
 //! Vectors can be easily initialized and used:
 //! ```rust
@@ -35,12 +58,76 @@
 //! When adding arrays, the resulting type is deturmined by 
 //! how structured the type is, so for example from most
 //! structured to least structured we have Vec3arr, then
-//! Vec3box, then Vec3win, then finally Vec3raw.
+//! Vec3box, then the growable Vec3vec, then Vec3win, then
+//! finally Vec3raw.
 //!
-//! Only Vec2arr/Vec3arr and Vec2box/Vec3box have allocators,
-//! so when performing an operation that allocates (like +, -,
-//! *, /) one of the types needs to be a Vec2arr/Vec3arr or 
-//! Vec2box/Vec3box.
+//! Only Vec2arr/Vec3arr, Vec2box/Vec3box and Vec2vec/Vec3vec
+//! have allocators, so when performing an operation that
+//! allocates (like +, -, *, /) one of the types needs to be
+//! one of them. A Vec3vec can be pushed to and extended like
+//! a Vec, and turns into a Vec3box without copying.
+//!
+//! All five kinds of Vec3 arrays implement the
+//! [Vec3Array](crate::vec3arr::Vec3Array) trait, so functions
+//! can take any of them with `fn f<A: Vec3Array>(a: &A)`, and
+//! the Vec2 arrays implement [Vec2Array](crate::vec2arr::Vec2Array)
+//! the same way.
+//!
+//! Every array type can be iterated over, by value or by
+//! reference, and Vec2box/Vec3box, Vec2vec/Vec3vec and
+//! Vec3soa can be collected from an iterator of vectors.
+//! Vectors and matricies can be summed with `Iterator::sum`,
+//! and matricies multiplied together with `Iterator::product`.
+//!
+//! Arithmetic on Vec2box/Vec3box and the other unsized arrays
+//! runs on SIMD vectors of 8 [f32] or 4 [f64] at a time. Every
+//! lane is computed with the same operations in the same order
+//! as the scalar code, and `+`, `-`, `*`, `/` and `sqrt` are all
+//! correctly rounded, so the results agree to within 0 ULP, that
+//! is bit for bit. Fused multiply-adds are never used for this
+//! reason. Building without the default `simd` feature falls
+//! back to scalar loops, and gives the same results again:
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec2::Vector2;
+//! use lineq::vec3::Vec3;
+//! use lineq::vec2arr::Vector2box;
+//! use lineq::vec3arr::Vec3box;
+//! // 13 vectors, so that some are left over after the full lanes
+//! let a : Vec3box = Vec3box::ftoarr(|i| Vec3 { x: i as f32 + 0.1, y: 1.3, z: -0.7*i as f32 }, 13);
+//! let b : Vec3box = Vec3box::ftoarr(|i| Vec3 { x: 0.3, y: i as f32 - 4.1, z: 2.9 }, 13);
+//! let (mag, dot, cross, norm) = (a.mag(), &a * &b, a.crossarr(b.clone()), a.norm());
+//! let sum : Vec3box = &a + &b;
+//! for i in 0..13 {
+//!     assert_eq!(mag[i], a[i].mag());
+//!     assert_eq!(dot[i], a[i]*b[i]);
+//!     assert_eq!(cross[i], a[i].cross(b[i]));
+//!     assert_eq!(norm[i], a[i].norm());
+//!     assert_eq!(sum[i], a[i] + b[i]);
+//! }
+//! let mut c : Vector2box<f64> = Vector2box::ftoarr(|i| Vector2 { x: 0.1*i as f64, y: 1.7 }, 7);
+//! let d : Vector2box<f64> = c.norm();
+//! c *= 3.0;
+//! for i in 0..7 {
+//!     assert_eq!(d[i], Vector2 { x: 0.1*i as f64, y: 1.7 }.norm());
+//!     assert_eq!(c[i], Vector2 { x: 0.1*i as f64, y: 1.7 }*3.0);
+//! }
+//! ```
+//!
+//! The optional `rayon` feature adds `par_` versions of these
+//! methods on Vec2box/Vec3box, which split the work across
+//! threads in the `par` module and still give the
+//! same results.
+//!
+//! The optional `serde` feature implements Serialize and
+//! Deserialize for the vectors, arrays and matricies. Matricies
+//! are written row-major as nested arrays, like
+//! `[[x1, x2], [y1, y2]]` for a Mat22.
+//!
+//! The vector and matrix structs are `#[repr(C)]`, and the
+//! optional `bytemuck` feature implements Pod and Zeroable for
+//! them, so arrays of them can be cast to bytes for graphics
+//! and network APIs.
 //!
 //! Matricies are indexed like x1, y2, z3 ... where x, y, z
 //! are the rows and 1, 2, 3 are the columns:
@@ -60,13 +147,45 @@
 //! assert_eq!(m1,m2);
 //! ```
 
-#![feature(new_uninit)]
 #![feature(slice_ptr_get)]
-#![feature(test)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+
+pub mod scalar;
+pub(crate) mod simd;
+#[cfg(feature = "rayon")]
+pub mod par;
+pub mod error;
+pub mod approx;
 pub mod vec2;
 pub mod vec3;
+pub mod vec4;
 pub mod mat;
+pub mod quat;
+pub mod matn;
+pub mod lu;
+pub mod qr;
+pub mod chol;
+pub mod svd;
+pub mod geom;
 pub mod vec3arr;
 pub mod vec2arr;
+pub mod vec3soa;
 mod arrmacro;
+
+// The kernels that the exported array macros expand to, which have to be reachable from the
+// crates that invoke them, while the simd module itself stays private.
+#[doc(hidden)]
+pub mod __private {
+	pub use crate::simd::{zip, zip_scalar, scalar_zip, zip_assign, zip_scalar_assign, dot};
+}
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
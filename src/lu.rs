@@ -0,0 +1,321 @@
+//! Module containing LU factorization with partial pivoting.
+//!
+//! Factoring a square matrix once and reusing the factors is much cheaper than
+//! solving from scratch when the same matrix is used with many right hand sides.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::Vector3;
+//! use lineq::mat::Matrix33;
+//! use lineq::lu::LU;
+//! let a : Matrix33<f64> = Matrix33 { x1: 0.0, x2: 1.0, x3: 2.0,
+//!                              y1: 1.0, y2: 0.0, y3: 3.0,
+//!                              z1: 4.0, z2: -3.0, z3: 8.0 };
+//! let lu : LU<f64> = LU::new_from_mat33(a);
+//! assert!((lu.det() - a.det()).abs() < 1e-12);
+//! let x : Vector3<f64> = lu.solve_vec3(Vector3 { x: 8.0, y: 10.0, z: 22.0 }).unwrap();
+//! assert!((x - Vector3 { x: 1.0, y: 2.0, z: 3.0 }).mag() < 1e-12);
+//! let y : Vector3<f64> = lu.solve_vec3(Vector3 { x: 1.0, y: 1.0, z: 1.0 }).unwrap();
+//! assert!((a*y - Vector3::ONE).mag() < 1e-12);
+//! ```
+//!
+//! Only singular matricies fail to solve. How close to singular the matrix is can be checked
+//! once through [report](crate::lu::LU::report), rather than with every solve:
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec2::Vec2;
+//! use lineq::mat::Mat22;
+//! use lineq::lu::LU;
+//! let lu : LU = LU::new_from_mat22(Mat22 { x1: 1.0, x2: 0.0, y1: 0.0, y2: 1e-4 });
+//! assert_eq!(lu.solve_vec2(Vec2 { x: 1.0, y: 1e-4 }), Ok(Vec2::ONE));
+//! let report = lu.report();
+//! assert_eq!(report.status, Ok(()));
+//! assert_eq!(report.conditioning.rcond, 1e-4);
+//! assert!(report.conditioning.is_ill());
+//! ```
+
+use crate::scalar::Scalar;
+use crate::vec2::Vector2;
+use crate::vec3::Vector3;
+use crate::mat::{Matrix22, Matrix33, Conditioning, check_pivots};
+use crate::error::LineqError;
+use crate::matn::{MatrixN, VectorN};
+use crate::vec3arr::Vector3box;
+
+/// The LU factorization `P A = L U` of a square matrix A, where P is a permutation, L is
+/// lower triangular with ones on the diagonal and U is upper triangular.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LU<T = f32> {
+        n : usize,
+        lu : Box<[T]>,
+        perm : Box<[usize]>,
+        swaps : usize,
+        norm : T,
+        status : Result<(), LineqError>,
+}
+
+/// A summary of the pivots found while factoring.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PivotReport<T = f32> {
+	/// The number of row swaps.
+	pub swaps : usize,
+	/// The smallest pivot magnitude.
+	pub min_pivot : T,
+	/// The largest pivot magnitude.
+	pub max_pivot : T,
+	/// How close to singular the matrix is, with an rcond of zero if it is singular.
+	pub conditioning : Conditioning<T>,
+	/// Whether the matrix was found to be singular, see [LineqError].
+	pub status : Result<(), LineqError>,
+}
+
+// Factors the n by n row-major matrix in `a` in place, where n is the length of `perm`, storing
+// L below the diagonal and U on and above it, and returns the number of row swaps.
+pub(crate) fn factor<T: Scalar>(a : &mut [T], perm : &mut [usize]) -> usize {
+	let n : usize = perm.len();
+	let mut swaps : usize = 0;
+	for (i, p) in perm.iter_mut().enumerate() {
+		*p = i;
+	}
+	for k in 0..n {
+		let mut m : usize = k;
+		for i in k+1..n {
+			if a[i*n + k].abs() > a[m*n + k].abs() { m = i; }
+		}
+		if m != k {
+			for j in 0..n {
+				a.swap(k*n + j, m*n + j);
+			}
+			perm.swap(k, m);
+			swaps += 1;
+		}
+		if a[k*n + k] == T::ZERO { continue; }
+		for i in k+1..n {
+			a[i*n + k] /= a[k*n + k];
+			for j in k+1..n {
+				a[i*n + j] -= a[i*n + k]*a[k*n + j];
+			}
+		}
+	}
+	swaps
+}
+
+// Solves L U x = P b using factors from `factor`, writing x into `x`.
+pub(crate) fn substitute<T: Scalar>(a : &[T], perm : &[usize], b : &[T], x : &mut [T]) {
+	let n : usize = perm.len();
+	for i in 0..n {
+		let mut sum : T = b[perm[i]];
+		for j in 0..i {
+			sum -= a[i*n + j]*x[j];
+		}
+		x[i] = sum;
+	}
+	for i in (0..n).rev() {
+		let mut sum : T = x[i];
+		for j in i+1..n {
+			sum -= a[i*n + j]*x[j];
+		}
+		x[i] = sum/a[i*n + i];
+	}
+}
+
+// The 1-norm of the n by n row-major matrix `a`, its largest column sum.
+fn norm1<T: Scalar>(a : &[T], n : usize) -> T {
+	let mut norm : T = T::ZERO;
+	for j in 0..n {
+		let mut col : T = T::ZERO;
+		for i in 0..n {
+			col += a[i*n + j].abs();
+		}
+		if col > norm { norm = col; }
+	}
+	norm
+}
+
+// The reciprocal of the 1-norm condition number of the n by n row-major matrix `entries`, given
+// its factors from `factor`. The norm of the inverse is found exactly, by solving for each of
+// its columns with `e` and `x` as scratch space of length n.
+pub(crate) fn rcond<T: Scalar>(entries : &[T], a : &[T], perm : &[usize], e : &mut [T], x : &mut [T]) -> T {
+	rcond_from_norm(norm1(entries, perm.len()), a, perm, e, x)
+}
+
+fn rcond_from_norm<T: Scalar>(norm : T, a : &[T], perm : &[usize], e : &mut [T], x : &mut [T]) -> T {
+	let n : usize = perm.len();
+	let mut inv_norm : T = T::ZERO;
+	for j in 0..n {
+		e[j] = T::ONE;
+		substitute(a, perm, e, x);
+		e[j] = T::ZERO;
+		let mut col : T = T::ZERO;
+		for xi in x.iter() {
+			col += xi.abs();
+		}
+		if col > inv_norm { inv_norm = col; }
+	}
+	if norm == T::ZERO { return T::ZERO; }
+	T::ONE/(norm*inv_norm)
+}
+
+//LU Methods
+
+impl<T: Scalar> LU<T> {
+
+	/// Factors a square [MatN](crate::matn::MatN), panicking if it is not square.
+	pub fn new_from_matn(a : &MatrixN<T>) -> LU<T> {
+		if a.rows() != a.cols() { panic!("matrix is not square"); }
+		LU::new_from_rows(a.rows(), a.as_slice().into())
+	}
+
+	pub fn new_from_mat22(a : Matrix22<T>) -> LU<T> {
+		LU::new_from_rows(2, Box::new([a.x1, a.x2, a.y1, a.y2]))
+	}
+
+	pub fn new_from_mat33(a : Matrix33<T>) -> LU<T> {
+		LU::new_from_rows(3, Box::new([a.x1, a.x2, a.x3, a.y1, a.y2, a.y3, a.z1, a.z2, a.z3]))
+	}
+
+	fn new_from_rows(n : usize, mut lu : Box<[T]>) -> LU<T> {
+		let entries : Box<[T]> = lu.clone();
+		let mut perm : Box<[usize]> = vec![0; n].into_boxed_slice();
+		let swaps : usize = factor(&mut lu, &mut perm);
+		let pivots : Box<[T]> = (0..n).map(|i| lu[i*n + i]).collect();
+		let status : Result<(), LineqError> = if n == 0 { Ok(()) } else { check_pivots(&entries, &pivots) };
+		LU { n, lu, perm, swaps, norm: norm1(&entries, n), status }
+	}
+
+	pub fn size(&self) -> usize {
+		self.n
+	}
+
+	/// The lower triangular factor L, with ones on the diagonal.
+	pub fn l(&self) -> MatrixN<T> {
+		MatrixN::ftomat(|i, j| if i == j { T::ONE } else if j < i { self.lu[i*self.n + j] } else { T::ZERO }, self.n, self.n)
+	}
+
+	/// The upper triangular factor U.
+	pub fn u(&self) -> MatrixN<T> {
+		MatrixN::ftomat(|i, j| if j >= i { self.lu[i*self.n + j] } else { T::ZERO }, self.n, self.n)
+	}
+
+	/// The permutation P, where row i of `P A` is row `perm()[i]` of A.
+	pub fn perm(&self) -> &[usize] {
+		&self.perm
+	}
+
+	/// Reports the pivots of U, whether the matrix is singular and how close to singular it is.
+	/// Finding the conditioning takes a solve for each column, as many operations as factoring.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::mat::Mat22;
+	/// use lineq::error::LineqError;
+	/// use lineq::lu::LU;
+	/// let lu : LU = LU::new_from_mat22(Mat22 { x1: 1.0, x2: 2.0, y1: 2.0, y2: 4.0 });
+	/// let report = lu.report();
+	/// assert_eq!(report.swaps, 1);
+	/// assert_eq!(report.max_pivot, 2.0);
+	/// assert_eq!(report.status, Err(LineqError::Singular));
+	/// assert_eq!(report.conditioning.rcond, 0.0);
+	/// ```
+	pub fn report(&self) -> PivotReport<T> {
+		let mut min : T = T::ZERO;
+		let mut max : T = T::ZERO;
+		for i in 0..self.n {
+			let p : T = self.lu[i*self.n + i].abs();
+			if i == 0 || p < min { min = p; }
+			if p > max { max = p; }
+		}
+		let rcond : T = if self.n == 0 { T::ONE } else if self.status.is_err() { T::ZERO } else {
+			let mut e : Box<[T]> = vec![T::ZERO; self.n].into_boxed_slice();
+			let mut x : Box<[T]> = vec![T::ZERO; self.n].into_boxed_slice();
+			rcond_from_norm(self.norm, &self.lu, &self.perm, &mut e, &mut x)
+		};
+		PivotReport { swaps: self.swaps, min_pivot: min, max_pivot: max, conditioning: Conditioning { rcond }, status: self.status }
+	}
+
+	/// The determinant, computed from the pivots, which is zero for exactly singular matricies.
+	pub fn det(&self) -> T {
+		let mut det : T = if self.swaps.is_multiple_of(2) { T::ONE } else { T::NEG_ONE };
+		for i in 0..self.n {
+			det *= self.lu[i*self.n + i];
+		}
+		det
+	}
+
+	/// Solves `A x = b`, failing only if A was found to be singular. Check how far the solution
+	/// can be trusted with [report](LU::report).
+	pub fn solve(&self, b : &VectorN<T>) -> Result<VectorN<T>, LineqError> {
+		if b.len() != self.n { panic!("matrix and vector dimensions do not match"); }
+		self.status?;
+		let mut x : VectorN<T> = VectorN::new_zeroed(self.n);
+		substitute(&self.lu, &self.perm, b, &mut x);
+		Ok(x)
+	}
+
+	pub fn solve_vec2(&self, b : Vector2<T>) -> Result<Vector2<T>, LineqError> {
+		if self.n != 2 { panic!("matrix and vector dimensions do not match"); }
+		self.status?;
+		let mut x : [T; 2] = [T::ZERO; 2];
+		substitute(&self.lu, &self.perm, &[b.x, b.y], &mut x);
+		Ok(Vector2 { x: x[0], y: x[1] })
+	}
+
+	pub fn solve_vec3(&self, b : Vector3<T>) -> Result<Vector3<T>, LineqError> {
+		if self.n != 3 { panic!("matrix and vector dimensions do not match"); }
+		self.status?;
+		let mut x : [T; 3] = [T::ZERO; 3];
+		substitute(&self.lu, &self.perm, &[b.x, b.y, b.z], &mut x);
+		Ok(Vector3 { x: x[0], y: x[1], z: x[2] })
+	}
+
+	/// Solves `A x = b` for every b in a [Vec3box](crate::vec3arr::Vec3box), returning the solutions in the same box.
+	pub fn solve_box(&self, mut b : Vector3box<T>) -> Result<Vector3box<T>, LineqError> {
+		if self.n != 3 { panic!("matrix and vector dimensions do not match"); }
+		self.status?;
+		for v in b.iter_mut() {
+			let mut x : [T; 3] = [T::ZERO; 3];
+			substitute(&self.lu, &self.perm, &[v.x, v.y, v.z], &mut x);
+			*v = Vector3 { x: x[0], y: x[1], z: x[2] };
+		}
+		Ok(b)
+	}
+
+	/// The inverse of A, found by solving against each column of the identity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::matn::MatrixN;
+	/// use lineq::lu::LU;
+	/// let a : MatrixN<f64> = MatrixN::from_rows(4, 4, Box::new([2.0, 1.0, 0.0, 0.0,
+	///                                                     1.0, 2.0, 1.0, 0.0,
+	///                                                     0.0, 1.0, 2.0, 1.0,
+	///                                                     0.0, 0.0, 1.0, 2.0]));
+	/// let lu : LU<f64> = LU::new_from_matn(&a);
+	/// assert!((lu.det() - 5.0).abs() < 1e-12);
+	/// let i : MatrixN<f64> = &a * &lu.inverse().unwrap() - MatrixN::identity(4);
+	/// assert!(i.as_slice().iter().all(|e| e.abs() < 1e-12));
+	/// ```
+	pub fn inverse(&self) -> Result<MatrixN<T>, LineqError> {
+		self.status?;
+		let n : usize = self.n;
+		let mut inv : MatrixN<T> = MatrixN::new_zeroed(n, n);
+		let mut e : Box<[T]> = vec![T::ZERO; n].into_boxed_slice();
+		let mut x : Box<[T]> = vec![T::ZERO; n].into_boxed_slice();
+		for j in 0..n {
+			e[j] = T::ONE;
+			substitute(&self.lu, &self.perm, &e, &mut x);
+			e[j] = T::ZERO;
+			for i in 0..n {
+				inv[(i, j)] = x[i];
+			}
+		}
+		Ok(inv)
+	}
+}
@@ -1,91 +1,361 @@
 //! Module containing matricies.
 
-use crate::vec2::Vec2;
-use crate::vec3::Vec3;
+use crate::scalar::Scalar;
+use crate::vec2::Vector2;
+use crate::vec3::Vector3;
+use crate::vec4::Vector4;
+use crate::vec2arr::{Vector2arr, Vector2box, Vector2vec, Vector2win, Vector2raw};
+use crate::vec3arr::{Vector3arr, Vector3box, Vector3vec, Vector3win, Vector3raw};
+use crate::simd;
+use crate::error::LineqError;
+use crate::matn::MatrixN;
+use crate::lu;
+use crate::lu::LU;
+use crate::svd::SVD;
+use crate::quat::Quaternion;
 
-/// A two by two square matrix.
+/// A two by two square matrix, laid out in memory column by column as x1, y1, x2, y2.
 ///
 /// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::mat::Mat22;
+/// let a : Mat22 = Mat22 { x1: 1.0, x2: 2.0, y1: 3.0, y2: 4.0 };
+/// let b : Mat22 = Mat22 { x1: 5.0, x2: 6.0, y1: 7.0, y2: 8.0 };
+/// assert_eq!(a * b, Mat22 { x1: 19.0, x2: 22.0, y1: 43.0, y2: 50.0 });
+/// let mut c : Mat22 = a;
+/// c *= b;
+/// assert_eq!(c, a * b);
+/// assert_eq!(a.t(), Mat22 { x1: 1.0, x2: 3.0, y1: 2.0, y2: 4.0 });
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Matrix22<T> {
+        pub x1 : T,
+        pub y1 : T,
+	pub x2 : T,
+	pub y2 : T,
+}
+
+/// A [Mat22] of [f32]s.
+pub type Mat22 = Matrix22<f32>;
+
+/// A three by three square matrix, laid out in memory column by column as x1, y1, z1, x2 ...
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec3::Vec3;
+/// use lineq::mat::{Mat33, Matrix33};
+/// let turns : [Matrix33<f64>; 3] = [Matrix33::rotation_z(0.5), Matrix33::rotation_x(0.25), Matrix33::rotation_z(-0.5)];
+/// let m : Matrix33<f64> = turns.iter().product();
+/// assert_eq!(m, turns[0] * turns[1] * turns[2]);
+/// assert_eq!(turns.iter().sum::<Matrix33<f64>>(), turns[0] + turns[1] + turns[2]);
+/// assert_eq!(std::iter::empty::<Mat33>().product::<Mat33>(), Mat33::IDENTITY);
+/// assert_eq!([Vec3::UP, Vec3::LEFT, Vec3::ONE].into_iter().sum::<Vec3>(), Vec3 { x: 0.0, y: 2.0, z: 1.0 });
+/// let a : Mat33 = Mat33 { x1: 1.0, x2: 2.0, x3: 3.0,
+///                         y1: 4.0, y2: 5.0, y3: 6.0,
+///                         z1: 7.0, z2: 8.0, z3: 10.0 };
+/// let b : Mat33 = Mat33 { x1: 2.0, x2: 0.0, x3: 1.0,
+///                         y1: 1.0, y2: 3.0, y3: 0.0,
+///                         z1: 0.0, z2: 1.0, z3: 4.0 };
+/// assert_eq!(a * b, Mat33 { x1: 4.0, x2: 9.0, x3: 13.0,
+///                           y1: 13.0, y2: 21.0, y3: 28.0,
+///                           z1: 22.0, z2: 34.0, z3: 47.0 });
+/// let mut c : Mat33 = a;
+/// c *= b;
+/// assert_eq!(c, a * b);
+/// assert_eq!(a.t(), Mat33 { x1: 1.0, x2: 4.0, x3: 7.0,
+///                           y1: 2.0, y2: 5.0, y3: 8.0,
+///                           z1: 3.0, z2: 6.0, z3: 10.0 });
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Mat22 {
-        pub x1 : f32,
-        pub y1 : f32,
-	pub x2 : f32,
-	pub y2 : f32,
+#[repr(C)]
+pub struct Matrix33<T> {
+        pub x1 : T,
+        pub y1 : T,
+	pub z1 : T,
+	pub x2 : T,
+	pub y2 : T,
+	pub z2 : T,
+	pub x3 : T,
+	pub y3 : T,
+	pub z3 : T,
 }
 
-/// A three by three square matrix.
+/// A [Mat33] of [f32]s.
+pub type Mat33 = Matrix33<f32>;
+
+/// A four by four square matrix, mostly used for homogeneous 3D transforms.
+///
+/// It is laid out in memory column by column as x1, y1, z1, w1, x2 ..., the same as the
+/// column-major matricies that graphics APIs expect.
 ///
 /// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec3::Vec3;
+/// use lineq::mat::Mat44;
+/// let m : Mat44 = Mat44::translation(Vec3 { x: 1.0, y: 2.0, z: 3.0 }) * Mat44::scale(Vec3 { x: 2.0, y: 2.0, z: 2.0 });
+/// assert_eq!(m.transform_point(Vec3::ONE), Vec3 { x: 3.0, y: 4.0, z: 5.0 });
+/// assert_eq!(m.transform_dir(Vec3::ONE), Vec3 { x: 2.0, y: 2.0, z: 2.0 });
+/// assert_eq!(m * m.inverse(), Mat44::IDENTITY);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Matrix44<T> {
+	pub x1 : T,
+	pub y1 : T,
+	pub z1 : T,
+	pub w1 : T,
+	pub x2 : T,
+	pub y2 : T,
+	pub z2 : T,
+	pub w2 : T,
+	pub x3 : T,
+	pub y3 : T,
+	pub z3 : T,
+	pub w3 : T,
+	pub x4 : T,
+	pub y4 : T,
+	pub z4 : T,
+	pub w4 : T,
+}
+
+/// A [Mat44] of [f32]s.
+pub type Mat44 = Matrix44<f32>;
+
+/// How close to singular a matrix was found to be while solving a system with it.
+///
+/// Elimination still finds a solution when the matrix is nearly singular, but small errors in
+/// the matrix or right hand side are magnified by up to the condition number in the solution.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Conditioning<T = f32> {
+	/// The reciprocal of the condition number `|A| |A^-1|` in the 1-norm, which is 1 for the
+	/// identity and shrinks toward zero as the matrix gets closer to singular. Roughly
+	/// `-log10(rcond)` decimal digits of the solution can be lost to rounding.
+	pub rcond : T,
+}
+
+impl<T: Scalar> Conditioning<T> {
+
+	/// Whether rcond is below the square root of the scalar's epsilon, so that about half of the
+	/// digits of the solution or more may be lost to rounding.
+	pub fn is_ill(&self) -> bool {
+		self.rcond < T::EPSILON.sqrt()
+	}
+}
+
+/// The order that [from_euler](crate::mat::Mat33::from_euler) applies its rotations in, about
+/// axes that stay fixed, so `Xyz` rotates about x first and z last.
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Mat33 {
-        pub x1 : f32,
-        pub y1 : f32,
-	pub z1 : f32,
-	pub x2 : f32,
-	pub y2 : f32,
-	pub z2 : f32,
-	pub x3 : f32,
-	pub y3 : f32,
-	pub z3 : f32,
+pub enum EulerOrder {
+	Xyz,
+	Xzy,
+	Yxz,
+	Yzx,
+	Zxy,
+	Zyx,
+}
+
+impl EulerOrder {
+
+	// The axes in the order they are applied, and 1 for cyclic orders or -1 for the others.
+	fn axes(self) -> ([usize; 3], i8) {
+		match self {
+			EulerOrder::Xyz => ([0, 1, 2], 1),
+			EulerOrder::Yzx => ([1, 2, 0], 1),
+			EulerOrder::Zxy => ([2, 0, 1], 1),
+			EulerOrder::Xzy => ([0, 2, 1], -1),
+			EulerOrder::Yxz => ([1, 0, 2], -1),
+			EulerOrder::Zyx => ([2, 1, 0], -1),
+		}
+	}
+}
+
+// The factors of a Mat33 from Mat33::eliminate.
+type Elimination<T> = ([T; 9], [usize; 3], Conditioning<T>);
+
+// Checks the pivots left by elimination against the largest entry of the original matrix.
+pub(crate) fn check_pivots<T: Scalar>(entries : &[T], pivots : &[T]) -> Result<(), LineqError> {
+	let mut scale : T = T::ZERO;
+	for e in entries {
+		if e.abs() > scale { scale = e.abs(); }
+	}
+	let mut min : T = pivots[0].abs();
+	for p in pivots {
+		if p.abs() < min { min = p.abs(); }
+	}
+	if scale == T::ZERO || min <= scale * T::EPSILON { return Err(LineqError::Singular); }
+	Ok(())
+}
+
+macro_rules! lhs_impl {
+        ($imp:ident;$func:ident;$op:tt; Matrix22; for $lhs:ident) => {
+                impl $imp<Matrix22<$lhs>> for $lhs {
+                        type Output = Matrix22<$lhs>;
+                        fn $func(self, rhs: Matrix22<$lhs>) -> Matrix22<$lhs> {
+                                Matrix22 { x1: self $op rhs.x1, y1: self $op rhs.y1, x2: self $op rhs.x2, y2: self $op rhs.y2 }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; Matrix33; for $lhs:ident) => {
+                impl $imp<Matrix33<$lhs>> for $lhs {
+                        type Output = Matrix33<$lhs>;
+                        fn $func(self, rhs: Matrix33<$lhs>) -> Matrix33<$lhs> {
+                                Matrix33 { x1: self $op rhs.x1, y1: self $op rhs.y1, z1: self $op rhs.z1,
+                                        x2: self $op rhs.x2, y2: self $op rhs.y2, z2: self $op rhs.z2,
+                                        x3: self $op rhs.x3, y3: self $op rhs.y3, z3: self $op rhs.z3 }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; Matrix44; for $lhs:ident) => {
+                impl $imp<Matrix44<$lhs>> for $lhs {
+                        type Output = Matrix44<$lhs>;
+                        fn $func(self, rhs: Matrix44<$lhs>) -> Matrix44<$lhs> {
+                                Matrix44 { x1: self $op rhs.x1, y1: self $op rhs.y1, z1: self $op rhs.z1, w1: self $op rhs.w1,
+                                        x2: self $op rhs.x2, y2: self $op rhs.y2, z2: self $op rhs.z2, w2: self $op rhs.w2,
+                                        x3: self $op rhs.x3, y3: self $op rhs.y3, z3: self $op rhs.z3, w3: self $op rhs.w3,
+                                        x4: self $op rhs.x4, y4: self $op rhs.y4, z4: self $op rhs.z4, w4: self $op rhs.w4 }
+                        }
+                }
+        };
+}
+
+// Sums and products of iterators of matricies, starting from $init. Products are taken in the
+// order of the iterator, so the product of [a, b] is a * b.
+macro_rules! fold_impl {
+        ($imp:ident;$func:ident;$op:tt; $t:ident; $init:ident) => {
+                impl<T: Scalar> $imp for $t<T> {
+                        fn $func<I: Iterator<Item = $t<T>>>(iter: I) -> $t<T> {
+                                iter.fold($t::$init, |a, b| a $op b)
+                        }
+                }
+
+                impl<'a, T: Scalar> $imp<&'a $t<T>> for $t<T> {
+                        fn $func<I: Iterator<Item = &'a $t<T>>>(iter: I) -> $t<T> {
+                                iter.fold($t::$init, |a, b| a $op *b)
+                        }
+                }
+        };
+}
+
+// Products of a matrix with arrays of vectors. Arrays and boxes are multiplied in place and
+// returned, while windows, raw arrays and borrowed arrays are left alone and their products
+// allocated as boxes, or copied for a borrowed Vec3arr. A borrowed matrix multiplies the same way.
+macro_rules! arr_mul_impl {
+        (Mul &$m:ident; $a:ty; out: $o:ty$(; <$lt:lifetime>)?$(; const $gen:ident: $gent:ty)?) => {
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> Mul<$a> for &$m<T> {
+                        type Output = $o;
+                        fn mul(self, rhs: $a) -> $o {
+                                *self * rhs
+                        }
+                }
+        };
+        (Mul $m:ident; $rows:ident; own $a:ty$(; const $gen:ident: $gent:ty)?) => {
+                impl<$(const $gen: $gent,)?T: Scalar> Mul<$a> for $m<T> {
+                        type Output = $a;
+                        fn mul(self, mut rhs: $a) -> $a {
+                                simd::mat_mul_assign(&mut rhs[..], self.$rows());
+                                rhs
+                        }
+                }
+                arr_mul_impl! {Mul &$m; $a; out: $a$(; const $gen: $gent)?}
+        };
+        (Mul $m:ident; $rows:ident; copy $a:ty; out: $o:ty; const $gen:ident: $gent:ty) => {
+                impl<const $gen: $gent, T: Scalar> Mul<$a> for $m<T> {
+                        type Output = $o;
+                        fn mul(self, rhs: $a) -> $o {
+                                self * *rhs
+                        }
+                }
+                arr_mul_impl! {Mul &$m; $a; out: $o; const $gen: $gent}
+        };
+        (Mul $m:ident; $rows:ident; $a:ty; out: $b:ident$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?T: Scalar> Mul<$a> for $m<T> {
+                        type Output = $b<T>;
+                        fn mul(self, rhs: $a) -> $b<T> {
+                                let mut tmp = $b::new_uninit_box(rhs.len());
+                                simd::mat_mul(&mut tmp, self.$rows(), &rhs[..]);
+                                $b(unsafe { tmp.assume_init() })
+                        }
+                }
+                arr_mul_impl! {Mul &$m; $a; out: $b<T>$(; <$lt>)?}
+        };
 }
 
 //Add
 use std::ops::Add;
 
-impl Add<f32> for Mat22 {
-        type Output = Mat22;
-        fn add(self, rhs: f32) -> Mat22 {
-                Mat22 { x1: self.x1 + rhs, y1: self.y1 + rhs, x2: self.x2 + rhs, y2: self.y2 + rhs }
+impl<T: Scalar> Add<T> for Matrix22<T> {
+        type Output = Matrix22<T>;
+        fn add(self, rhs: T) -> Matrix22<T> {
+                Matrix22 { x1: self.x1 + rhs, y1: self.y1 + rhs, x2: self.x2 + rhs, y2: self.y2 + rhs }
         }
 }
 
-impl Add<Mat22> for f32 {
-        type Output = Mat22;
-        fn add(self, rhs: Mat22) -> Mat22 {
-                Mat22 { x1: self + rhs.x1, y1: self + rhs.y1, x2: self + rhs.x2, y2: self + rhs.y2 }
-        }
-}
+lhs_impl! {Add;add;+; Matrix22; for f32}
+lhs_impl! {Add;add;+; Matrix22; for f64}
 
-impl Add<Mat22> for Mat22 {
-        type Output = Mat22;
-        fn add(self, rhs: Mat22) -> Mat22 {
-                Mat22 { x1: self.x1 + rhs.x1, y1: self.y1 + rhs.y1, x2: self.x2 + rhs.x2, y2: self.y2 + rhs.y2 }
+impl<T: Scalar> Add<Matrix22<T>> for Matrix22<T> {
+        type Output = Matrix22<T>;
+        fn add(self, rhs: Matrix22<T>) -> Matrix22<T> {
+                Matrix22 { x1: self.x1 + rhs.x1, y1: self.y1 + rhs.y1, x2: self.x2 + rhs.x2, y2: self.y2 + rhs.y2 }
         }
 }
 
-impl Add<f32> for Mat33 {
-        type Output = Mat33;
-        fn add(self, rhs: f32) -> Mat33 {
-                Mat33 { x1: self.x1 + rhs, y1: self.y1 + rhs, z1: self.z1 + rhs, 
+impl<T: Scalar> Add<T> for Matrix33<T> {
+        type Output = Matrix33<T>;
+        fn add(self, rhs: T) -> Matrix33<T> {
+                Matrix33 { x1: self.x1 + rhs, y1: self.y1 + rhs, z1: self.z1 + rhs, 
 			x2: self.x2 + rhs, y2: self.y2 + rhs, z2: self.z2 + rhs,
 			x3: self.x3 + rhs, y3: self.y3 + rhs, z3: self.z3 + rhs }
         }
 }
 
-impl Add<Mat33> for f32 {
-        type Output = Mat33;
-        fn add(self, rhs: Mat33) -> Mat33 {
-		Mat33 { x1: self + rhs.x1, y1: self + rhs.y1, z1: self + rhs.z1,
-                        x2: self + rhs.x2, y2: self + rhs.y2, z2: self + rhs.z2,
-                        x3: self + rhs.x3, y3: self + rhs.y3, z3: self + rhs.z3 }
-        }
-}
+lhs_impl! {Add;add;+; Matrix33; for f32}
+lhs_impl! {Add;add;+; Matrix33; for f64}
 
-impl Add<Mat33> for Mat33 {
-        type Output = Mat33;
-        fn add(self, rhs: Mat33) -> Mat33 {
-		Mat33 { x1: self.x1 + rhs.x1, y1: self.y1 + rhs.y1, z1: self.z1 + rhs.z1,
+impl<T: Scalar> Add<Matrix33<T>> for Matrix33<T> {
+        type Output = Matrix33<T>;
+        fn add(self, rhs: Matrix33<T>) -> Matrix33<T> {
+		Matrix33 { x1: self.x1 + rhs.x1, y1: self.y1 + rhs.y1, z1: self.z1 + rhs.z1,
                         x2: self.x2 + rhs.x2, y2: self.y2 + rhs.y2, z2: self.z2 + rhs.z2,
                         x3: self.x3 + rhs.x3, y3: self.y3 + rhs.y3, z3: self.z3 + rhs.z3 }
         }
 }
 
+impl<T: Scalar> Add<T> for Matrix44<T> {
+        type Output = Matrix44<T>;
+        fn add(self, rhs: T) -> Matrix44<T> {
+                Matrix44 { x1: self.x1 + rhs, y1: self.y1 + rhs, z1: self.z1 + rhs, w1: self.w1 + rhs,
+                        x2: self.x2 + rhs, y2: self.y2 + rhs, z2: self.z2 + rhs, w2: self.w2 + rhs,
+                        x3: self.x3 + rhs, y3: self.y3 + rhs, z3: self.z3 + rhs, w3: self.w3 + rhs,
+                        x4: self.x4 + rhs, y4: self.y4 + rhs, z4: self.z4 + rhs, w4: self.w4 + rhs }
+        }
+}
+
+lhs_impl! {Add;add;+; Matrix44; for f32}
+lhs_impl! {Add;add;+; Matrix44; for f64}
+
+impl<T: Scalar> Add<Matrix44<T>> for Matrix44<T> {
+        type Output = Matrix44<T>;
+        fn add(self, rhs: Matrix44<T>) -> Matrix44<T> {
+                Matrix44 { x1: self.x1 + rhs.x1, y1: self.y1 + rhs.y1, z1: self.z1 + rhs.z1, w1: self.w1 + rhs.w1,
+                        x2: self.x2 + rhs.x2, y2: self.y2 + rhs.y2, z2: self.z2 + rhs.z2, w2: self.w2 + rhs.w2,
+                        x3: self.x3 + rhs.x3, y3: self.y3 + rhs.y3, z3: self.z3 + rhs.z3, w3: self.w3 + rhs.w3,
+                        x4: self.x4 + rhs.x4, y4: self.y4 + rhs.y4, z4: self.z4 + rhs.z4, w4: self.w4 + rhs.w4 }
+        }
+}
+
 //AddAssign
 use std::ops::AddAssign;
 
-impl AddAssign<f32> for Mat22 {
-        fn add_assign(&mut self, rhs: f32) {
+impl<T: Scalar> AddAssign<T> for Matrix22<T> {
+        fn add_assign(&mut self, rhs: T) {
                 self.x1 += rhs;
                 self.y1 += rhs;
 		self.x2 += rhs;
@@ -93,8 +363,8 @@ impl AddAssign<f32> for Mat22 {
         }
 }
 
-impl AddAssign<Mat22> for Mat22 {
-        fn add_assign(&mut self, rhs: Mat22) {
+impl<T: Scalar> AddAssign<Matrix22<T>> for Matrix22<T> {
+        fn add_assign(&mut self, rhs: Matrix22<T>) {
                 self.x1 += rhs.x1;
                 self.y1 += rhs.y1;
 		self.x2 += rhs.x2;
@@ -102,61 +372,95 @@ impl AddAssign<Mat22> for Mat22 {
         }
 }
 
-impl AddAssign<f32> for Mat33 {
-        fn add_assign(&mut self, rhs: f32) {
+impl<T: Scalar> AddAssign<T> for Matrix33<T> {
+        fn add_assign(&mut self, rhs: T) {
                 self.x1 += rhs; self.y1 += rhs; self.z1 += rhs;
 		self.x2 += rhs; self.y2 += rhs; self.z2 += rhs;
 		self.x3 += rhs; self.y3 += rhs; self.z3 += rhs;
         }
 }
 
-impl AddAssign<Mat33> for Mat33 {
-        fn add_assign(&mut self, rhs: Mat33) {
+impl<T: Scalar> AddAssign<Matrix33<T>> for Matrix33<T> {
+        fn add_assign(&mut self, rhs: Matrix33<T>) {
 		self.x1 += rhs.x1; self.y1 += rhs.y1; self.z1 += rhs.z1;
                 self.x2 += rhs.x2; self.y2 += rhs.y2; self.z2 += rhs.z2;
                 self.x3 += rhs.x3; self.y3 += rhs.y3; self.z3 += rhs.z3;
         }
 }
 
+impl<T: Scalar> AddAssign<T> for Matrix44<T> {
+        fn add_assign(&mut self, rhs: T) {
+                self.x1 += rhs; self.y1 += rhs; self.z1 += rhs; self.w1 += rhs;
+                self.x2 += rhs; self.y2 += rhs; self.z2 += rhs; self.w2 += rhs;
+                self.x3 += rhs; self.y3 += rhs; self.z3 += rhs; self.w3 += rhs;
+                self.x4 += rhs; self.y4 += rhs; self.z4 += rhs; self.w4 += rhs;
+        }
+}
+
+impl<T: Scalar> AddAssign<Matrix44<T>> for Matrix44<T> {
+        fn add_assign(&mut self, rhs: Matrix44<T>) {
+                self.x1 += rhs.x1; self.y1 += rhs.y1; self.z1 += rhs.z1; self.w1 += rhs.w1;
+                self.x2 += rhs.x2; self.y2 += rhs.y2; self.z2 += rhs.z2; self.w2 += rhs.w2;
+                self.x3 += rhs.x3; self.y3 += rhs.y3; self.z3 += rhs.z3; self.w3 += rhs.w3;
+                self.x4 += rhs.x4; self.y4 += rhs.y4; self.z4 += rhs.z4; self.w4 += rhs.w4;
+        }
+}
+
 //Display
 use std::fmt;
 
-impl fmt::Display for Mat22 {
+impl<T: Scalar> fmt::Display for Matrix22<T> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "[[{}, {}][{}, {}]]", self.x1, self.x2, self.y1, self.y2)
         }
 }
 
-impl fmt::Display for Mat33 {
+impl<T: Scalar> fmt::Display for Matrix33<T> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "[[{}, {}, {}][{}, {}, {}][{}, {}, {}]]", self.x1, self.x2, self.x3, self.y1, self.y2, self.y3, self.z1, self.z2, self.z3)
         }
 }
 
+impl<T: Scalar> fmt::Display for Matrix44<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "[[{}, {}, {}, {}][{}, {}, {}, {}][{}, {}, {}, {}][{}, {}, {}, {}]]", self.x1, self.x2, self.x3, self.x4, self.y1, self.y2, self.y3, self.y4, self.z1, self.z2, self.z3, self.z4, self.w1, self.w2, self.w3, self.w4)
+        }
+}
+
 //Div
 use std::ops::Div;
 
-impl Div<f32> for Mat22 {
-        type Output = Mat22;
-        fn div(self, rhs: f32) -> Mat22 {
-                Mat22 { x1: self.x1/rhs, y1: self.y1/rhs, x2: self.x2/rhs, y2: self.y2/rhs }
+impl<T: Scalar> Div<T> for Matrix22<T> {
+        type Output = Matrix22<T>;
+        fn div(self, rhs: T) -> Matrix22<T> {
+                Matrix22 { x1: self.x1/rhs, y1: self.y1/rhs, x2: self.x2/rhs, y2: self.y2/rhs }
         }
 }
 
-impl Div<f32> for Mat33 {
-        type Output = Mat33;
-        fn div(self, rhs: f32) -> Mat33 {
-                Mat33 { x1: self.x1/rhs, y1: self.y1/rhs, z1: self.z1/rhs,
+impl<T: Scalar> Div<T> for Matrix33<T> {
+        type Output = Matrix33<T>;
+        fn div(self, rhs: T) -> Matrix33<T> {
+                Matrix33 { x1: self.x1/rhs, y1: self.y1/rhs, z1: self.z1/rhs,
                         x2: self.x2/rhs, y2: self.y2/rhs, z2: self.z2/rhs,
                         x3: self.x3/rhs, y3: self.y3/rhs, z3: self.z3/rhs }
         }
 }
 
+impl<T: Scalar> Div<T> for Matrix44<T> {
+        type Output = Matrix44<T>;
+        fn div(self, rhs: T) -> Matrix44<T> {
+                Matrix44 { x1: self.x1/rhs, y1: self.y1/rhs, z1: self.z1/rhs, w1: self.w1/rhs,
+                        x2: self.x2/rhs, y2: self.y2/rhs, z2: self.z2/rhs, w2: self.w2/rhs,
+                        x3: self.x3/rhs, y3: self.y3/rhs, z3: self.z3/rhs, w3: self.w3/rhs,
+                        x4: self.x4/rhs, y4: self.y4/rhs, z4: self.z4/rhs, w4: self.w4/rhs }
+        }
+}
+
 //DivAssign
 use std::ops::DivAssign;
 
-impl DivAssign<f32> for Mat22 {
-        fn div_assign(&mut self, rhs: f32) {
+impl<T: Scalar> DivAssign<T> for Matrix22<T> {
+        fn div_assign(&mut self, rhs: T) {
                 self.x1 /= rhs;
                 self.y1 /= rhs;
 		self.x2 /= rhs;
@@ -164,84 +468,135 @@ impl DivAssign<f32> for Mat22 {
         }
 }
 
-impl DivAssign<f32> for Mat33 {
-        fn div_assign(&mut self, rhs: f32) {
+impl<T: Scalar> DivAssign<T> for Matrix33<T> {
+        fn div_assign(&mut self, rhs: T) {
                 self.x1 /= rhs; self.y1 /= rhs; self.z1 /= rhs;
                 self.x2 /= rhs; self.y2 /= rhs; self.z2 /= rhs;
                 self.x3 /= rhs; self.y3 /= rhs; self.z3 /= rhs;
         }
 }
 
+impl<T: Scalar> DivAssign<T> for Matrix44<T> {
+        fn div_assign(&mut self, rhs: T) {
+                self.x1 /= rhs; self.y1 /= rhs; self.z1 /= rhs; self.w1 /= rhs;
+                self.x2 /= rhs; self.y2 /= rhs; self.z2 /= rhs; self.w2 /= rhs;
+                self.x3 /= rhs; self.y3 /= rhs; self.z3 /= rhs; self.w3 /= rhs;
+                self.x4 /= rhs; self.y4 /= rhs; self.z4 /= rhs; self.w4 /= rhs;
+        }
+}
+
 //Mult
 use std::ops::Mul;
 
-impl Mul<f32> for Mat22 {
-        type Output = Mat22;
-        fn mul(self, rhs: f32) -> Mat22 {
-                Mat22 { x1: self.x1 * rhs, y1: self.y1 * rhs, x2: self.x2 * rhs, y2: self.y2 * rhs }
+impl<T: Scalar> Mul<T> for Matrix22<T> {
+        type Output = Matrix22<T>;
+        fn mul(self, rhs: T) -> Matrix22<T> {
+                Matrix22 { x1: self.x1 * rhs, y1: self.y1 * rhs, x2: self.x2 * rhs, y2: self.y2 * rhs }
         }
 }
 
-impl Mul<Mat22> for f32 {
-        type Output = Mat22;
-        fn mul(self, rhs: Mat22) -> Mat22 {
-                Mat22 { x1: self*rhs.x1, y1: self*rhs.y1, x2: self*rhs.x2, y2: self*rhs.y2 }
-        }
-}
+lhs_impl! {Mul;mul;*; Matrix22; for f32}
+lhs_impl! {Mul;mul;*; Matrix22; for f64}
 
-impl Mul<Mat22> for Mat22 {
-        type Output = Mat22;
-        fn mul(self, rhs: Mat22) -> Mat22 {
-                Mat22 { x1: self.x1*rhs.x1 + self.x2*rhs.y1, y1: self.y1*rhs.x1 + self.y2*rhs.y1, x2: self.x1*rhs.x2 + self.x2*rhs.y2, y2: self.y1*rhs.x2 + self.y2*rhs.y2 }
+impl<T: Scalar> Mul<Matrix22<T>> for Matrix22<T> {
+        type Output = Matrix22<T>;
+        fn mul(self, rhs: Matrix22<T>) -> Matrix22<T> {
+                Matrix22 { x1: self.x1*rhs.x1 + self.x2*rhs.y1, y1: self.y1*rhs.x1 + self.y2*rhs.y1, x2: self.x1*rhs.x2 + self.x2*rhs.y2, y2: self.y1*rhs.x2 + self.y2*rhs.y2 }
         }
 }
 
-impl Mul<Vec2> for Mat22 {
-	type Output = Vec2;
-	fn mul(self, rhs: Vec2) -> Vec2 {
-		Vec2 { x: self.x1*rhs.x + self.x2*rhs.y , y: self.y1*rhs.x + self.y2*rhs.y }
+impl<T: Scalar> Mul<Vector2<T>> for Matrix22<T> {
+	type Output = Vector2<T>;
+	fn mul(self, rhs: Vector2<T>) -> Vector2<T> {
+		Vector2 { x: self.x1*rhs.x + self.x2*rhs.y , y: self.y1*rhs.x + self.y2*rhs.y }
 	}
 }
 
-impl Mul<f32> for Mat33 {
-        type Output = Mat33;
-        fn mul(self, rhs: f32) -> Mat33 {
-                Mat33 { x1: self.x1 * rhs, y1: self.y1 * rhs, z1: self.z1 * rhs,
+arr_mul_impl! {Mul Matrix22; rows3; own Vector2arr<N, T>; const N: usize}
+arr_mul_impl! {Mul Matrix22; rows3; own Vector2box<T>}
+arr_mul_impl! {Mul Matrix22; rows3; own Vector2vec<T>}
+arr_mul_impl! {Mul Matrix22; rows3; Vector2win<'a, T>; out: Vector2box; <'a>}
+arr_mul_impl! {Mul Matrix22; rows3; Vector2raw<T>; out: Vector2box}
+arr_mul_impl! {Mul Matrix22; rows3; copy &Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize}
+arr_mul_impl! {Mul Matrix22; rows3; &Vector2box<T>; out: Vector2box}
+arr_mul_impl! {Mul Matrix22; rows3; &Vector2vec<T>; out: Vector2box}
+arr_mul_impl! {Mul Matrix22; rows3; &Vector2win<'a, T>; out: Vector2box; <'a>}
+arr_mul_impl! {Mul Matrix22; rows3; &Vector2raw<T>; out: Vector2box}
+
+impl<T: Scalar> Mul<T> for Matrix33<T> {
+        type Output = Matrix33<T>;
+        fn mul(self, rhs: T) -> Matrix33<T> {
+                Matrix33 { x1: self.x1 * rhs, y1: self.y1 * rhs, z1: self.z1 * rhs,
                         x2: self.x2 * rhs, y2: self.y2 * rhs, z2: self.z2 * rhs,
                         x3: self.x3 * rhs, y3: self.y3 * rhs, z3: self.z3 * rhs }
         }
 }
 
-impl Mul<Mat33> for f32 {
-        type Output = Mat33;
-        fn mul(self, rhs: Mat33) -> Mat33 {
-                Mat33 { x1: self * rhs.x1, y1: self * rhs.y1, z1: self * rhs.z1,
-                        x2: self * rhs.x2, y2: self * rhs.y2, z2: self * rhs.z2,
-                        x3: self * rhs.x3, y3: self * rhs.y3, z3: self * rhs.z3 }
+lhs_impl! {Mul;mul;*; Matrix33; for f32}
+lhs_impl! {Mul;mul;*; Matrix33; for f64}
+
+impl<T: Scalar> Mul<Matrix33<T>> for Matrix33<T> {
+        type Output = Matrix33<T>;
+        fn mul(self, rhs: Matrix33<T>) -> Matrix33<T> {
+                Matrix33 { x1: self.x1*rhs.x1 + self.x2*rhs.y1 + self.x3*rhs.z1, y1: self.y1*rhs.x1 + self.y2*rhs.y1 + self.y3*rhs.z1, z1: self.z1*rhs.x1 + self.z2*rhs.y1 + self.z3*rhs.z1,
+                        x2: self.x1*rhs.x2 + self.x2*rhs.y2 + self.x3*rhs.z2, y2: self.y1*rhs.x2 + self.y2*rhs.y2 + self.y3*rhs.z2, z2: self.z1*rhs.x2 + self.z2*rhs.y2 + self.z3*rhs.z2,
+                        x3: self.x1*rhs.x3 + self.x2*rhs.y3 + self.x3*rhs.z3, y3: self.y1*rhs.x3 + self.y2*rhs.y3 + self.y3*rhs.z3, z3: self.z1*rhs.x3 + self.z2*rhs.y3 + self.z3*rhs.z3, }
         }
 }
 
-impl Mul<Mat33> for Mat33 {
-        type Output = Mat33;
-        fn mul(self, rhs: Mat33) -> Mat33 {
-                Mat33 { x1: self.x1*rhs.x1 + self.x2*rhs.y1 + self.x3*rhs.z1, y1: self.y1*rhs.x1 + self.y2*rhs.y1 + self.y3*rhs.z1, z1: self.z1*rhs.x1 + self.z2*rhs.y1 + self.z3*rhs.z1,
-                        x2: self.x1*rhs.x2 + self.x2*rhs.y2 + self.x3*rhs.z2, y2: self.y1*rhs.x2 + self.y2*rhs.y2 + self.y3*rhs.z2, z2: self.z1*rhs.x2 + self.z2*rhs.y2 + self.z3*rhs.z2,
-                        x3: self.x1*rhs.x3 + self.x2*rhs.y3 + self.x3*rhs.z3, y3: self.y1*rhs.x3 + self.y2*rhs.y3 + self.y3*rhs.z3, z3: self.x1*rhs.x3 + self.z2*rhs.y3 + self.z3*rhs.z3, }
+impl<T: Scalar> Mul<Vector3<T>> for Matrix33<T> {
+	type Output = Vector3<T>;
+	fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+		Vector3 { x: self.x1*rhs.x + self.x2*rhs.y + self.x3*rhs.z, y: self.y1*rhs.x + self.y2*rhs.y + self.y3*rhs.z, z: self.z1*rhs.x + self.z2*rhs.y + self.z3*rhs.z }
+	}
+}
+
+arr_mul_impl! {Mul Matrix33; to_rows; own Vector3arr<N, T>; const N: usize}
+arr_mul_impl! {Mul Matrix33; to_rows; own Vector3box<T>}
+arr_mul_impl! {Mul Matrix33; to_rows; own Vector3vec<T>}
+arr_mul_impl! {Mul Matrix33; to_rows; Vector3win<'a, T>; out: Vector3box; <'a>}
+arr_mul_impl! {Mul Matrix33; to_rows; Vector3raw<T>; out: Vector3box}
+arr_mul_impl! {Mul Matrix33; to_rows; copy &Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize}
+arr_mul_impl! {Mul Matrix33; to_rows; &Vector3box<T>; out: Vector3box}
+arr_mul_impl! {Mul Matrix33; to_rows; &Vector3vec<T>; out: Vector3box}
+arr_mul_impl! {Mul Matrix33; to_rows; &Vector3win<'a, T>; out: Vector3box; <'a>}
+arr_mul_impl! {Mul Matrix33; to_rows; &Vector3raw<T>; out: Vector3box}
+
+impl<T: Scalar> Mul<T> for Matrix44<T> {
+        type Output = Matrix44<T>;
+        fn mul(self, rhs: T) -> Matrix44<T> {
+                Matrix44 { x1: self.x1 * rhs, y1: self.y1 * rhs, z1: self.z1 * rhs, w1: self.w1 * rhs,
+                        x2: self.x2 * rhs, y2: self.y2 * rhs, z2: self.z2 * rhs, w2: self.w2 * rhs,
+                        x3: self.x3 * rhs, y3: self.y3 * rhs, z3: self.z3 * rhs, w3: self.w3 * rhs,
+                        x4: self.x4 * rhs, y4: self.y4 * rhs, z4: self.z4 * rhs, w4: self.w4 * rhs }
+        }
+}
+
+lhs_impl! {Mul;mul;*; Matrix44; for f32}
+lhs_impl! {Mul;mul;*; Matrix44; for f64}
+
+impl<T: Scalar> Mul<Matrix44<T>> for Matrix44<T> {
+        type Output = Matrix44<T>;
+        fn mul(self, rhs: Matrix44<T>) -> Matrix44<T> {
+                Matrix44 { x1: self.x1*rhs.x1 + self.x2*rhs.y1 + self.x3*rhs.z1 + self.x4*rhs.w1, y1: self.y1*rhs.x1 + self.y2*rhs.y1 + self.y3*rhs.z1 + self.y4*rhs.w1, z1: self.z1*rhs.x1 + self.z2*rhs.y1 + self.z3*rhs.z1 + self.z4*rhs.w1, w1: self.w1*rhs.x1 + self.w2*rhs.y1 + self.w3*rhs.z1 + self.w4*rhs.w1,
+                        x2: self.x1*rhs.x2 + self.x2*rhs.y2 + self.x3*rhs.z2 + self.x4*rhs.w2, y2: self.y1*rhs.x2 + self.y2*rhs.y2 + self.y3*rhs.z2 + self.y4*rhs.w2, z2: self.z1*rhs.x2 + self.z2*rhs.y2 + self.z3*rhs.z2 + self.z4*rhs.w2, w2: self.w1*rhs.x2 + self.w2*rhs.y2 + self.w3*rhs.z2 + self.w4*rhs.w2,
+                        x3: self.x1*rhs.x3 + self.x2*rhs.y3 + self.x3*rhs.z3 + self.x4*rhs.w3, y3: self.y1*rhs.x3 + self.y2*rhs.y3 + self.y3*rhs.z3 + self.y4*rhs.w3, z3: self.z1*rhs.x3 + self.z2*rhs.y3 + self.z3*rhs.z3 + self.z4*rhs.w3, w3: self.w1*rhs.x3 + self.w2*rhs.y3 + self.w3*rhs.z3 + self.w4*rhs.w3,
+                        x4: self.x1*rhs.x4 + self.x2*rhs.y4 + self.x3*rhs.z4 + self.x4*rhs.w4, y4: self.y1*rhs.x4 + self.y2*rhs.y4 + self.y3*rhs.z4 + self.y4*rhs.w4, z4: self.z1*rhs.x4 + self.z2*rhs.y4 + self.z3*rhs.z4 + self.z4*rhs.w4, w4: self.w1*rhs.x4 + self.w2*rhs.y4 + self.w3*rhs.z4 + self.w4*rhs.w4 }
         }
 }
 
-impl Mul<Vec3> for Mat33 {
-	type Output = Vec3;
-	fn mul(self, rhs: Vec3) -> Vec3 {
-		Vec3 { x: self.x1*rhs.x + self.x2*rhs.y + self.x3*rhs.z, y: self.y1*rhs.x + self.y2*rhs.y + self.y3*rhs.z, z: self.z1*rhs.x + self.z2*rhs.y + self.z3*rhs.z }
+impl<T: Scalar> Mul<Vector4<T>> for Matrix44<T> {
+	type Output = Vector4<T>;
+	fn mul(self, rhs: Vector4<T>) -> Vector4<T> {
+		Vector4 { x: self.x1*rhs.x + self.x2*rhs.y + self.x3*rhs.z + self.x4*rhs.w, y: self.y1*rhs.x + self.y2*rhs.y + self.y3*rhs.z + self.y4*rhs.w, z: self.z1*rhs.x + self.z2*rhs.y + self.z3*rhs.z + self.z4*rhs.w, w: self.w1*rhs.x + self.w2*rhs.y + self.w3*rhs.z + self.w4*rhs.w }
 	}
 }
 
 //MultAssign
 use std::ops::MulAssign;
 
-impl MulAssign<f32> for Mat22 {
-        fn mul_assign(&mut self, rhs: f32) {
+impl<T: Scalar> MulAssign<T> for Matrix22<T> {
+        fn mul_assign(&mut self, rhs: T) {
                 self.x1 *= rhs;
                 self.y1 *= rhs;
 		self.x2 *= rhs;
@@ -249,90 +604,137 @@ impl MulAssign<f32> for Mat22 {
         }
 }
 
-impl MulAssign<Mat22> for Mat22 {
-	fn mul_assign(&mut self, rhs : Mat22) {
-		self.x1 = self.x1*rhs.x1 + self.x2*rhs.y1;
-		self.y1 = self.y1*rhs.x1 + self.y2*rhs.y1;
-		self.x2 = self.x1*rhs.x2 + self.x2*rhs.y2;
-		self.y2 = self.y1*rhs.x2 + self.y2*rhs.y2;
+impl<T: Scalar> MulAssign<Matrix22<T>> for Matrix22<T> {
+	fn mul_assign(&mut self, rhs: Matrix22<T>) {
+		*self = *self * rhs;
 	}
 }
 
-impl MulAssign<f32> for Mat33 {
-        fn mul_assign(&mut self, rhs: f32) {
+impl<T: Scalar> MulAssign<T> for Matrix33<T> {
+        fn mul_assign(&mut self, rhs: T) {
                 self.x1 *= rhs; self.y1 *= rhs; self.z1 *= rhs;
                 self.x2 *= rhs; self.y2 *= rhs; self.z2 *= rhs;
                 self.x3 *= rhs; self.y3 *= rhs; self.z3 *= rhs;
         }
 }
 
-impl MulAssign<Mat33> for Mat33 {
-	fn mul_assign(&mut self, rhs : Mat33) {
-		self.x1 = self.x1*rhs.x1 + self.x2*rhs.y1 + self.x3*rhs.z1; self.y1 = self.y1*rhs.x1 + self.y2*rhs.y1 + self.y3*rhs.z1; self.z1 = self.z1*rhs.x1 + self.z2*rhs.y1 + self.z3*rhs.z1;
-                self.x2 = self.x1*rhs.x2 + self.x2*rhs.y2 + self.x3*rhs.z2; self.y2 = self.y1*rhs.x2 + self.y2*rhs.y2 + self.y3*rhs.z2; self.z2 = self.z1*rhs.x2 + self.z2*rhs.y2 + self.z3*rhs.z2;
-                self.x3 = self.x1*rhs.x3 + self.x2*rhs.y3 + self.x3*rhs.z3; self.y3 = self.y1*rhs.x3 + self.y2*rhs.y3 + self.y3*rhs.z3; self.z3 = self.x1*rhs.x3 + self.z2*rhs.y3 + self.z3*rhs.z3;
+impl<T: Scalar> MulAssign<Matrix33<T>> for Matrix33<T> {
+	fn mul_assign(&mut self, rhs: Matrix33<T>) {
+		*self = *self * rhs;
+	}
+}
+
+impl<T: Scalar> MulAssign<T> for Matrix44<T> {
+        fn mul_assign(&mut self, rhs: T) {
+                self.x1 *= rhs; self.y1 *= rhs; self.z1 *= rhs; self.w1 *= rhs;
+                self.x2 *= rhs; self.y2 *= rhs; self.z2 *= rhs; self.w2 *= rhs;
+                self.x3 *= rhs; self.y3 *= rhs; self.z3 *= rhs; self.w3 *= rhs;
+                self.x4 *= rhs; self.y4 *= rhs; self.z4 *= rhs; self.w4 *= rhs;
+        }
+}
+
+impl<T: Scalar> MulAssign<Matrix44<T>> for Matrix44<T> {
+	fn mul_assign(&mut self, rhs: Matrix44<T>) {
+		*self = *self * rhs;
 	}
 }
 
 //Neg
 use std::ops::Neg;
 
-impl Neg for Mat22 {
-        type Output = Mat22;
-        fn neg(self) -> Mat22 {
-                Mat22 { x1: -self.x1, y1: -self.y1, x2: -self.x2, y2: -self.y2 }
+impl<T: Scalar> Neg for Matrix22<T> {
+        type Output = Matrix22<T>;
+        fn neg(self) -> Matrix22<T> {
+                Matrix22 { x1: -self.x1, y1: -self.y1, x2: -self.x2, y2: -self.y2 }
         }
 }
 
-impl Neg for Mat33 {
-        type Output = Mat33;
-        fn neg(self) -> Mat33 {
-                Mat33 { x1: -self.x1, y1: -self.y1, z1: -self.z1,
+impl<T: Scalar> Neg for Matrix33<T> {
+        type Output = Matrix33<T>;
+        fn neg(self) -> Matrix33<T> {
+                Matrix33 { x1: -self.x1, y1: -self.y1, z1: -self.z1,
                         x2: -self.x2, y2: -self.y2, z2: -self.z2,
                         x3: -self.x3, y3: -self.y3, z3: -self.z3 }
         }
 }
 
+impl<T: Scalar> Neg for Matrix44<T> {
+        type Output = Matrix44<T>;
+        fn neg(self) -> Matrix44<T> {
+                Matrix44 { x1: -self.x1, y1: -self.y1, z1: -self.z1, w1: -self.w1,
+                        x2: -self.x2, y2: -self.y2, z2: -self.z2, w2: -self.w2,
+                        x3: -self.x3, y3: -self.y3, z3: -self.z3, w3: -self.w3,
+                        x4: -self.x4, y4: -self.y4, z4: -self.z4, w4: -self.w4 }
+        }
+}
+
+//Product
+use std::iter::Product;
+
+fold_impl! {Product;product;*; Matrix22; IDENTITY}
+fold_impl! {Product;product;*; Matrix33; IDENTITY}
+fold_impl! {Product;product;*; Matrix44; IDENTITY}
+
 //Sub
 use std::ops::Sub;
 
-impl Sub<f32> for Mat22 {
-        type Output = Mat22;
-        fn sub(self, rhs: f32) -> Mat22 {
-                Mat22 { x1: self.x1 - rhs, y1: self.y1 - rhs, x2: self.x2 - rhs, y2: self.y2 - rhs }
+impl<T: Scalar> Sub<T> for Matrix22<T> {
+        type Output = Matrix22<T>;
+        fn sub(self, rhs: T) -> Matrix22<T> {
+                Matrix22 { x1: self.x1 - rhs, y1: self.y1 - rhs, x2: self.x2 - rhs, y2: self.y2 - rhs }
         }
 }
 
-impl Sub<Mat22> for Mat22 {
-        type Output = Mat22;
-        fn sub(self, rhs: Mat22) -> Mat22 {
-                Mat22 { x1: self.x1 - rhs.x1, y1: self.y1 - rhs.y1, x2: self.x2 - rhs.x2, y2: self.y2 - rhs.y2 }
+impl<T: Scalar> Sub<Matrix22<T>> for Matrix22<T> {
+        type Output = Matrix22<T>;
+        fn sub(self, rhs: Matrix22<T>) -> Matrix22<T> {
+                Matrix22 { x1: self.x1 - rhs.x1, y1: self.y1 - rhs.y1, x2: self.x2 - rhs.x2, y2: self.y2 - rhs.y2 }
         }
 }
 
-impl Sub<f32> for Mat33 {
-        type Output = Mat33;
-        fn sub(self, rhs: f32) -> Mat33 {
-                Mat33 { x1: self.x1 - rhs, y1: self.y1 - rhs, z1: self.z1 - rhs,
+impl<T: Scalar> Sub<T> for Matrix33<T> {
+        type Output = Matrix33<T>;
+        fn sub(self, rhs: T) -> Matrix33<T> {
+                Matrix33 { x1: self.x1 - rhs, y1: self.y1 - rhs, z1: self.z1 - rhs,
                         x2: self.x2 - rhs, y2: self.y2 - rhs, z2: self.z2 - rhs,
                         x3: self.x3 - rhs, y3: self.y3 - rhs, z3: self.z3 - rhs }
         }
 }
 
-impl Sub<Mat33> for Mat33 {
-        type Output = Mat33;
-        fn sub(self, rhs: Mat33) -> Mat33 {
-		Mat33 { x1: self.x1 - rhs.x1, y1: self.y1 - rhs.y1, z1: self.z1 - rhs.z1,
+impl<T: Scalar> Sub<Matrix33<T>> for Matrix33<T> {
+        type Output = Matrix33<T>;
+        fn sub(self, rhs: Matrix33<T>) -> Matrix33<T> {
+		Matrix33 { x1: self.x1 - rhs.x1, y1: self.y1 - rhs.y1, z1: self.z1 - rhs.z1,
                         x2: self.x2 - rhs.x2, y2: self.y2 - rhs.y2, z2: self.z2 - rhs.z2,
                         x3: self.x3 - rhs.x3, y3: self.y3 - rhs.y3, z3: self.z3 - rhs.z3 }
         }
 }
 
+impl<T: Scalar> Sub<T> for Matrix44<T> {
+        type Output = Matrix44<T>;
+        fn sub(self, rhs: T) -> Matrix44<T> {
+                Matrix44 { x1: self.x1 - rhs, y1: self.y1 - rhs, z1: self.z1 - rhs, w1: self.w1 - rhs,
+                        x2: self.x2 - rhs, y2: self.y2 - rhs, z2: self.z2 - rhs, w2: self.w2 - rhs,
+                        x3: self.x3 - rhs, y3: self.y3 - rhs, z3: self.z3 - rhs, w3: self.w3 - rhs,
+                        x4: self.x4 - rhs, y4: self.y4 - rhs, z4: self.z4 - rhs, w4: self.w4 - rhs }
+        }
+}
+
+impl<T: Scalar> Sub<Matrix44<T>> for Matrix44<T> {
+        type Output = Matrix44<T>;
+        fn sub(self, rhs: Matrix44<T>) -> Matrix44<T> {
+                Matrix44 { x1: self.x1 - rhs.x1, y1: self.y1 - rhs.y1, z1: self.z1 - rhs.z1, w1: self.w1 - rhs.w1,
+                        x2: self.x2 - rhs.x2, y2: self.y2 - rhs.y2, z2: self.z2 - rhs.z2, w2: self.w2 - rhs.w2,
+                        x3: self.x3 - rhs.x3, y3: self.y3 - rhs.y3, z3: self.z3 - rhs.z3, w3: self.w3 - rhs.w3,
+                        x4: self.x4 - rhs.x4, y4: self.y4 - rhs.y4, z4: self.z4 - rhs.z4, w4: self.w4 - rhs.w4 }
+        }
+}
+
 //SubAssign
 use std::ops::SubAssign;
 
-impl SubAssign<f32> for Mat22 {
-        fn sub_assign(&mut self, rhs: f32) {
+impl<T: Scalar> SubAssign<T> for Matrix22<T> {
+        fn sub_assign(&mut self, rhs: T) {
                 self.x1 -= rhs;
                 self.y1 -= rhs;
 		self.x2 -= rhs;
@@ -340,8 +742,8 @@ impl SubAssign<f32> for Mat22 {
         }
 }
 
-impl SubAssign<Mat22> for Mat22 {
-        fn sub_assign(&mut self, rhs: Mat22) {
+impl<T: Scalar> SubAssign<Matrix22<T>> for Matrix22<T> {
+        fn sub_assign(&mut self, rhs: Matrix22<T>) {
                 self.x1 -= rhs.x1;
                 self.y1 -= rhs.y1;
 		self.x2 -= rhs.x2;
@@ -349,88 +751,826 @@ impl SubAssign<Mat22> for Mat22 {
         }
 }
 
-impl SubAssign<f32> for Mat33 {
-        fn sub_assign(&mut self, rhs: f32) {
+impl<T: Scalar> SubAssign<T> for Matrix33<T> {
+        fn sub_assign(&mut self, rhs: T) {
                 self.x1 -= rhs; self.y1 -= rhs; self.z1 -= rhs;
                 self.x2 -= rhs; self.y2 -= rhs; self.z2 -= rhs;
                 self.x3 -= rhs; self.y3 -= rhs; self.z3 -= rhs;
         }
 }
 
-impl SubAssign<Mat33> for Mat33 {
-        fn sub_assign(&mut self, rhs: Mat33) {
+impl<T: Scalar> SubAssign<Matrix33<T>> for Matrix33<T> {
+        fn sub_assign(&mut self, rhs: Matrix33<T>) {
                 self.x1 -= rhs.x1; self.y1 -= rhs.y1; self.z1 -= rhs.z1;
                 self.x2 -= rhs.x2; self.y2 -= rhs.y2; self.z2 -= rhs.z2;
                 self.x3 -= rhs.x3; self.y3 -= rhs.y3; self.z3 -= rhs.z3;
         }
 }
 
+impl<T: Scalar> SubAssign<T> for Matrix44<T> {
+        fn sub_assign(&mut self, rhs: T) {
+                self.x1 -= rhs; self.y1 -= rhs; self.z1 -= rhs; self.w1 -= rhs;
+                self.x2 -= rhs; self.y2 -= rhs; self.z2 -= rhs; self.w2 -= rhs;
+                self.x3 -= rhs; self.y3 -= rhs; self.z3 -= rhs; self.w3 -= rhs;
+                self.x4 -= rhs; self.y4 -= rhs; self.z4 -= rhs; self.w4 -= rhs;
+        }
+}
+
+impl<T: Scalar> SubAssign<Matrix44<T>> for Matrix44<T> {
+        fn sub_assign(&mut self, rhs: Matrix44<T>) {
+                self.x1 -= rhs.x1; self.y1 -= rhs.y1; self.z1 -= rhs.z1; self.w1 -= rhs.w1;
+                self.x2 -= rhs.x2; self.y2 -= rhs.y2; self.z2 -= rhs.z2; self.w2 -= rhs.w2;
+                self.x3 -= rhs.x3; self.y3 -= rhs.y3; self.z3 -= rhs.z3; self.w3 -= rhs.w3;
+                self.x4 -= rhs.x4; self.y4 -= rhs.y4; self.z4 -= rhs.z4; self.w4 -= rhs.w4;
+        }
+}
+
+//Sum
+use std::iter::Sum;
+
+fold_impl! {Sum;sum;+; Matrix22; ZERO}
+fold_impl! {Sum;sum;+; Matrix33; ZERO}
+fold_impl! {Sum;sum;+; Matrix44; ZERO}
+
 //Mat22 Methods
 
-impl Mat22 {
-	pub const IDENTITY : Mat22 = Mat22 { x1: 1.0, y1: 0.0, x2: 0.0, y2: 1.0 };
-	pub const ZERO : Mat22 = Mat22 { x1: 0.0, y1: 0.0, x2: 0.0, y2: 0.0 };
+impl<T: Scalar> Matrix22<T> {
+	pub const IDENTITY : Matrix22<T> = Matrix22 { x1: T::ONE, y1: T::ZERO, x2: T::ZERO, y2: T::ONE };
+	pub const ZERO : Matrix22<T> = Matrix22 { x1: T::ZERO, y1: T::ZERO, x2: T::ZERO, y2: T::ZERO };
 	
-	pub fn augment(v1 : Vec2, v2 : Vec2) -> Mat22 {
-		Mat22 { x1: v1.x, y1: v1.y, x2: v2.x, y2: v2.y }
+	pub fn augment(v1 : Vector2<T>, v2 : Vector2<T>) -> Matrix22<T> {
+		Matrix22 { x1: v1.x, y1: v1.y, x2: v2.x, y2: v2.y }
+	}
+
+	/// Creates a counterclockwise rotation of `angle` radians.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[macro_use] extern crate lineq;
+	/// use lineq::vec2::Vector2;
+	/// use lineq::mat::Matrix22;
+	/// # fn main() {
+	/// let r : Matrix22<f64> = Matrix22::rotation(std::f64::consts::FRAC_PI_2);
+	/// assert_approx_eq!(r * Vector2 { x: 1.0, y: 0.0 }, Vector2 { x: 0.0, y: 1.0 }, abs = 1e-15);
+	/// assert_approx_eq!(Matrix22::rotation(r.angle()), r);
+	/// # }
+	/// ```
+	pub fn rotation(angle : T) -> Matrix22<T> {
+		let (c, s) = (angle.cos(), angle.sin());
+		Matrix22 { x1: c, y1: s, x2: -s, y2: c }
+	}
+
+	/// The angle in radians of a rotation matrix, in [-pi, pi].
+	pub fn angle(&self) -> T {
+		self.y1.atan2(self.x1)
+	}
+
+	// The rows padded to three by three with zeros, as the simd kernels take them.
+	fn rows3(self) -> [[T; 3]; 3] {
+		[[self.x1, self.x2, T::ZERO], [self.y1, self.y2, T::ZERO], [T::ZERO; 3]]
 	}
 
-	pub fn det(&self) -> f32 {
+	/// Multiplies every vector of `a` by the matrix in place, without allocating. `a` can be a
+	/// [Vec2win](crate::vec2arr::Vec2win), or any of the other arrays of vectors. It takes a slice
+	/// rather than a [Vec2win](crate::vec2arr::Vec2win) so that every array, which all dereference to
+	/// slices, can be passed as it is, like `&mut a` for a [Vec2box](crate::vec2arr::Vec2box), instead
+	/// of being wrapped in a window first.
+	///
+	/// Multiplying an array by a matrix gives the same array, with the same memory for a
+	/// [Vec2arr](crate::vec2arr::Vec2arr) or [Vec2box](crate::vec2arr::Vec2box), while windows, raw
+	/// arrays and borrowed arrays are left alone and the product is a new
+	/// [Vec2box](crate::vec2arr::Vec2box), or a new [Vec2arr](crate::vec2arr::Vec2arr) for a borrowed
+	/// one.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vector2;
+	/// use lineq::mat::Matrix22;
+	/// use lineq::vec2arr::{Vector2box, Vector2win};
+	/// let m : Matrix22<f64> = Matrix22 { x1: 1.0, x2: 2.0, y1: -3.0, y2: 0.5 };
+	/// let mut a : Vector2box<f64> = Vector2box::ftoarr(|i| Vector2 { x: i as f64, y: 1.5 }, 11);
+	/// let b : Vector2box<f64> = m * Vector2win(&mut a[..]);
+	/// assert_eq!(&m * &a, b);
+	/// m.apply_to(&mut Vector2win(&mut a[2..]));
+	/// for i in 0..11 {
+	///     assert_eq!(b[i], m * Vector2 { x: i as f64, y: 1.5 });
+	///     assert_eq!(a[i], if i < 2 { Vector2 { x: i as f64, y: 1.5 } } else { b[i] });
+	/// }
+	/// ```
+	pub fn apply_to(&self, a : &mut [Vector2<T>]) {
+		simd::mat_mul_assign(a, self.rows3());
+	}
+
+	pub fn det(&self) -> T {
                 self.x1*self.y2-self.y1*self.x2
 	}
 
-        pub fn inverse(&self) -> Mat22 {
-		let det : f32 = self.x1*self.y2-self.y1*self.x2;
-		if det == 0.0 { panic!("non-invertible matrix"); }
-		Mat22 { x1: self.y2/det, y1: -self.y1/det, x2: -self.x2/det, y2: self.x1/det }
+        pub fn inverse(&self) -> Matrix22<T> {
+		let det : T = self.x1*self.y2-self.y1*self.x2;
+		if det == T::ZERO { panic!("non-invertible matrix"); }
+		Matrix22 { x1: self.y2/det, y1: -self.y1/det, x2: -self.x2/det, y2: self.x1/det }
+	}
+
+	/// The inverse, failing instead of panicking when the determinant is zero.
+	pub fn try_inverse(&self) -> Result<Matrix22<T>, LineqError> {
+		let det : T = self.det();
+		if det == T::ZERO { return Err(LineqError::Singular); }
+		Ok(Matrix22 { x1: self.y2/det, y1: -self.y1/det, x2: -self.x2/det, y2: self.x1/det })
+	}
+
+	pub fn t(&self) -> Matrix22<T> {
+		Matrix22 { x1: self.x1, y1: self.x2, x2: self.y1, y2: self.y2 }
+	}
+
+	/// Solves `self * x = b` for x by Gaussian elimination with partial pivoting, failing only
+	/// if the matrix is singular. The solution comes with the [Conditioning] of the matrix, to
+	/// check how far it can be trusted.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vec2;
+	/// use lineq::mat::Mat22;
+	/// use lineq::error::LineqError;
+	/// let a : Mat22 = Mat22 { x1: 0.0, x2: 2.0, y1: 1.0, y2: 1.0 };
+	/// let (x, cond) = a.solve(Vec2 { x: 4.0, y: 3.0 }).unwrap();
+	/// assert_eq!(x, Vec2 { x: 1.0, y: 2.0 });
+	/// assert!(!cond.is_ill());
+	/// let s : Mat22 = Mat22 { x1: 1.0, x2: 2.0, y1: 2.0, y2: 4.0 };
+	/// assert_eq!(s.solve(Vec2::ONE), Err(LineqError::Singular));
+	/// // a diagonal matrix is solved exactly, but still has a condition number of 10^4
+	/// let d : Mat22 = Mat22 { x1: 1.0, x2: 0.0, y1: 0.0, y2: 1e-4 };
+	/// let (x, cond) = d.solve(Vec2 { x: 1.0, y: 1e-4 }).unwrap();
+	/// assert_eq!(x, Vec2::ONE);
+	/// assert_eq!(cond.rcond, 1e-4);
+	/// let i : Mat22 = Mat22 { x1: 1.0, x2: 1.0, y1: 1.0, y2: 1.000001 };
+	/// assert!(i.solve(Vec2::ONE).unwrap().1.is_ill());
+	/// ```
+	pub fn solve(&self, b : Vector2<T>) -> Result<(Vector2<T>, Conditioning<T>), LineqError> {
+		let (mut a, mut b) = ([[self.x1, self.x2], [self.y1, self.y2]], [b.x, b.y]);
+		if a[1][0].abs() > a[0][0].abs() {
+			a.swap(0, 1);
+			b.swap(0, 1);
+		}
+		let l : T = if a[0][0] == T::ZERO { T::ZERO } else { a[1][0]/a[0][0] };
+		let u : T = a[1][1] - l*a[0][1];
+		check_pivots(&[self.x1, self.y1, self.x2, self.y2], &[a[0][0], u])?;
+		let y : T = (b[1] - l*b[0])/u;
+		// the inverse is the adjugate over the determinant, which is the product of the pivots
+		let (c1, c2) = (self.x1.abs() + self.y1.abs(), self.x2.abs() + self.y2.abs());
+		let (a1, a2) = (self.y2.abs() + self.y1.abs(), self.x2.abs() + self.x1.abs());
+		let norm : T = if c1 > c2 { c1 } else { c2 };
+		let adj_norm : T = if a1 > a2 { a1 } else { a2 };
+		let rcond : T = (a[0][0]*u).abs()/(norm*adj_norm);
+		Ok((Vector2 { x: (b[0] - a[0][1]*y)/a[0][0], y }, Conditioning { rcond }))
+	}
+
+	/// The eigenvalues of a symmetric matrix in ascending order, and the matching unit eigenvectors
+	/// as the columns of a rotation matrix. Only the average of x2 and y1 is used, so a matrix that
+	/// is not quite symmetric from rounding is treated as symmetric.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vector2;
+	/// use lineq::mat::Matrix22;
+	/// let a : Matrix22<f64> = Matrix22 { x1: 2.0, x2: 1.0, y1: 1.0, y2: 2.0 };
+	/// let (l, v) = a.eigen_sym();
+	/// assert!((l - Vector2 { x: 1.0, y: 3.0 }).mag() < 1e-12);
+	/// let d : Matrix22<f64> = a*v - v*Matrix22 { x1: l.x, x2: 0.0, y1: 0.0, y2: l.y };
+	/// assert!(d.x1.abs() + d.x2.abs() + d.y1.abs() + d.y2.abs() < 1e-12);
+	/// assert!((v.det() - 1.0).abs() < 1e-12);
+	/// assert_eq!(Matrix22::<f64>::IDENTITY.eigen_sym(), (Vector2::ONE, Matrix22::IDENTITY));
+	/// ```
+	pub fn eigen_sym(&self) -> (Vector2<T>, Matrix22<T>) {
+		let two : T = T::ONE + T::ONE;
+		let b : T = (self.x2 + self.y1)/two;
+		let mean : T = (self.x1 + self.y2)/two;
+		let half : T = (self.x1 - self.y2)/two;
+		let r : T = (half*half + b*b).sqrt();
+		// the smaller eigenvalue's eigenvector makes an angle of atan2(-2b, d - a)/2 with the x axis,
+		// which is zero when the eigenvalues are repeated
+		let phi : T = (-(b + b)).atan2(self.y2 - self.x1)/two;
+		let (s, c) = (phi.sin(), phi.cos());
+		(Vector2 { x: mean - r, y: mean + r }, Matrix22::augment(Vector2 { x: c, y: s }, Vector2 { x: -s, y: c }))
+	}
+
+	/// The singular value decomposition `self = U Σ Vᵀ`, returned as U, the diagonal of Σ in
+	/// descending order and Vᵀ, see [SVD].
+	pub fn svd(&self) -> (Matrix22<T>, Vector2<T>, Matrix22<T>) {
+		let svd : SVD<T> = SVD::new_from_matn(&MatrixN::new_from_mat22(*self));
+		(svd.u().to_mat22(), Vector2 { x: svd.s()[0], y: svd.s()[1] }, svd.vt().to_mat22())
 	}
 
-	pub fn t(&self) -> Mat22 {
-		Mat22 { x1: self.x1, y1: self.x2, x2: self.y1, y2: self.y2 }
+	/// The singular value decomposition with U and Vᵀ both rotations, where the last singular
+	/// value is negative if the determinant is.
+	pub fn svd_proper(&self) -> (Matrix22<T>, Vector2<T>, Matrix22<T>) {
+		let svd : SVD<T> = SVD::new_from_matn_proper(&MatrixN::new_from_mat22(*self));
+		(svd.u().to_mat22(), Vector2 { x: svd.s()[0], y: svd.s()[1] }, svd.vt().to_mat22())
 	}
 }
 
 //Mat33 Methods
 
-impl Mat33 {
-	pub const IDENTITY : Mat33 = 	Mat33 { x1: 1.0, y1: 0.0, z1: 0.0, 
-						x2: 0.0, y2: 1.0, z2: 0.0,
-						x3: 0.0, y3: 0.0, z3: 1.0 };
-	pub const ZERO : Mat33 = 	Mat33 { x1: 0.0, y1: 0.0, z1: 0.0, 
-						x2: 0.0, y2: 0.0, z2: 0.0,
-						x3: 0.0, y3: 0.0, z3: 0.0 };
+impl<T: Scalar> Matrix33<T> {
+	pub const IDENTITY : Matrix33<T> = 	Matrix33 { x1: T::ONE, y1: T::ZERO, z1: T::ZERO, 
+						x2: T::ZERO, y2: T::ONE, z2: T::ZERO,
+						x3: T::ZERO, y3: T::ZERO, z3: T::ONE };
+	pub const ZERO : Matrix33<T> = 	Matrix33 { x1: T::ZERO, y1: T::ZERO, z1: T::ZERO, 
+						x2: T::ZERO, y2: T::ZERO, z2: T::ZERO,
+						x3: T::ZERO, y3: T::ZERO, z3: T::ZERO };
 	
-	pub fn adj(&self) -> Mat33 {
-                Mat33 { x1: self.y2*self.z3-self.y3*self.z2, y1: self.y3*self.z1-self.y1*self.z3, z1: self.y1*self.z2-self.y2*self.z1,
+	pub fn adj(&self) -> Matrix33<T> {
+                Matrix33 { x1: self.y2*self.z3-self.y3*self.z2, y1: self.y3*self.z1-self.y1*self.z3, z1: self.y1*self.z2-self.y2*self.z1,
                         x2: self.x3*self.z2-self.x2*self.z3, y2: self.x1*self.z3-self.x3*self.z1, z2: self.x2*self.z1-self.x1*self.z2,
                         x3: self.x2*self.y3-self.x3*self.y2, y3: self.x3*self.y1-self.x1*self.y3, z3: self.x1*self.y2-self.x2*self.y1 }
         }
 
-	pub fn augment(v1 : Vec3, v2 : Vec3, v3 : Vec3) -> Mat33 {
-		Mat33 { x1: v1.x, y1: v1.y, z1: v1.z,
+	pub fn augment(v1 : Vector3<T>, v2 : Vector3<T>, v3 : Vector3<T>) -> Matrix33<T> {
+		Matrix33 { x1: v1.x, y1: v1.y, z1: v1.z,
                         x2: v2.x, y2: v2.y, z2: v2.z,
                         x3: v3.x, y3: v3.y, z3: v3.z }
 	}
+
+	fn from_rows(m : [[T; 3]; 3]) -> Matrix33<T> {
+		Matrix33 { x1: m[0][0], x2: m[0][1], x3: m[0][2],
+			y1: m[1][0], y2: m[1][1], y3: m[1][2],
+			z1: m[2][0], z2: m[2][1], z3: m[2][2] }
+	}
+
+	fn to_rows(self) -> [[T; 3]; 3] {
+		[[self.x1, self.x2, self.x3], [self.y1, self.y2, self.y3], [self.z1, self.z2, self.z3]]
+	}
+
+	/// Multiplies every vector of `a` by the matrix in place, without allocating. `a` can be a
+	/// [Vec3win](crate::vec3arr::Vec3win), or any of the other arrays of vectors. It takes a slice
+	/// rather than a [Vec3win](crate::vec3arr::Vec3win) so that every array, which all dereference to
+	/// slices, can be passed as it is, like `&mut a` for a [Vec3box](crate::vec3arr::Vec3box), instead
+	/// of being wrapped in a window first.
+	///
+	/// Multiplying an array by a matrix gives the same array, with the same memory for a
+	/// [Vec3arr](crate::vec3arr::Vec3arr) or [Vec3box](crate::vec3arr::Vec3box), while windows, raw
+	/// arrays and borrowed arrays are left alone and the product is a new
+	/// [Vec3box](crate::vec3arr::Vec3box), or a new [Vec3arr](crate::vec3arr::Vec3arr) for a borrowed
+	/// one. All of these compute with the SIMD kernels, and agree bit for bit with multiplying the
+	/// vectors one at a time.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::mat::Mat33;
+	/// use lineq::vec3arr::{Vec3arr, Vec3box, Vec3raw};
+	/// let m : Mat33 = Mat33::rotation_z(0.3) * 2.0;
+	/// let f = |i : usize| Vec3 { x: i as f32 - 4.5, y: 0.1 * i as f32, z: 1.0 };
+	/// let mut a : Vec3box = Vec3box::ftoarr(f, 13);
+	/// let (b, c) : (Vec3box, Vec3box) = (m * a.clone(), m * Vec3raw::new_from_box(a.clone()));
+	/// let d : Vec3arr<13> = m * Vec3arr::ftoarr(f);
+	/// // borrowing the array or the matrix leaves them to be used again
+	/// assert_eq!(&m * &a, b);
+	/// assert_eq!(m * &d, &m * d);
+	/// m.apply_to(&mut a);
+	/// for i in 0..13 {
+	///     assert_eq!(a[i], m * f(i));
+	///     assert_eq!((b[i], c[i], d[i]), (a[i], a[i], a[i]));
+	/// }
+	/// ```
+	pub fn apply_to(&self, a : &mut [Vector3<T>]) {
+		simd::mat_mul_assign(a, self.to_rows());
+	}
+
+	// A counterclockwise rotation about axis 0, 1 or 2, looking down the axis towards the origin.
+	fn rotation(axis : usize, angle : T) -> Matrix33<T> {
+		let (c, s) = (angle.cos(), angle.sin());
+		let (i, j) = ((axis + 1) % 3, (axis + 2) % 3);
+		let mut m : [[T; 3]; 3] = Matrix33::IDENTITY.to_rows();
+		m[i][i] = c;
+		m[i][j] = -s;
+		m[j][i] = s;
+		m[j][j] = c;
+		Matrix33::from_rows(m)
+	}
+
+	/// Creates a rotation of `angle` radians about the x axis, which turns y towards z.
+	pub fn rotation_x(angle : T) -> Matrix33<T> {
+		Matrix33::rotation(0, angle)
+	}
+
+	/// Creates a rotation of `angle` radians about the y axis, which turns z towards x.
+	pub fn rotation_y(angle : T) -> Matrix33<T> {
+		Matrix33::rotation(1, angle)
+	}
+
+	/// Creates a rotation of `angle` radians about the z axis, which turns x towards y.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[macro_use] extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::mat::Matrix33;
+	/// # fn main() {
+	/// let quarter : f64 = std::f64::consts::FRAC_PI_2;
+	/// assert_approx_eq!(Matrix33::rotation_x(quarter) * Vector3::UP, Vector3::IN, abs = 1e-15);
+	/// assert_approx_eq!(Matrix33::rotation_y(quarter) * Vector3::IN, Vector3::RIGHT, abs = 1e-15);
+	/// assert_approx_eq!(Matrix33::rotation_z(quarter) * Vector3::RIGHT, Vector3::UP, abs = 1e-15);
+	/// # }
+	/// ```
+	pub fn rotation_z(angle : T) -> Matrix33<T> {
+		Matrix33::rotation(2, angle)
+	}
+
+	/// Creates a rotation of `angle` radians about `axis`, which does not need to be normalized,
+	/// with Rodrigues' formula.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[macro_use] extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::mat::Matrix33;
+	/// # fn main() {
+	/// let axis : Vector3<f64> = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
+	/// let r : Matrix33<f64> = Matrix33::from_axis_angle(axis, 2.0*std::f64::consts::FRAC_PI_3);
+	/// assert_approx_eq!(r * Vector3::RIGHT, Vector3::UP, abs = 1e-15);
+	/// let (axis2, angle) = r.to_axis_angle();
+	/// assert_approx_eq!(axis2, axis.norm(), abs = 1e-15);
+	/// assert_approx_eq!(Matrix33::from_axis_angle(axis2, angle), r, abs = 1e-15);
+	/// # }
+	/// ```
+	pub fn from_axis_angle(axis : Vector3<T>, angle : T) -> Matrix33<T> {
+		let k : Vector3<T> = axis.norm();
+		let (c, s) = (angle.cos(), angle.sin());
+		let t : T = T::ONE - c;
+		Matrix33 { x1: c + t*k.x*k.x, x2: t*k.x*k.y - s*k.z, x3: t*k.x*k.z + s*k.y,
+			y1: t*k.y*k.x + s*k.z, y2: c + t*k.y*k.y, y3: t*k.y*k.z - s*k.x,
+			z1: t*k.z*k.x - s*k.y, z2: t*k.z*k.y + s*k.x, z3: c + t*k.z*k.z }
+	}
+
+	/// Returns the axis and angle in radians of a rotation matrix, with the angle in [0, pi],
+	/// and using [Vec3::RIGHT](crate::vec3::Vec3::RIGHT) as the axis when the rotation is close to the identity.
+	pub fn to_axis_angle(&self) -> (Vector3<T>, T) {
+		Quaternion::from_mat33(*self).to_axis_angle()
+	}
+
+	/// Creates a rotation of `x`, `y` and `z` radians about each axis, applied in the given order.
+	/// `Mat33::from_euler(x, y, z, EulerOrder::Xyz)` is the same rotation as
+	/// [Quat::from_euler](crate::quat::Quat::from_euler).
+	pub fn from_euler(x : T, y : T, z : T, order : EulerOrder) -> Matrix33<T> {
+		let angles : [T; 3] = [x, y, z];
+		let ([i, j, k], _) = order.axes();
+		Matrix33::rotation(k, angles[k]) * Matrix33::rotation(j, angles[j]) * Matrix33::rotation(i, angles[i])
+	}
+
+	/// Returns the angles (x, y, z) in radians such that [from_euler](crate::mat::Mat33::from_euler)
+	/// with the same order gives back this rotation matrix, with the angle of the middle rotation
+	/// in [-pi/2, pi/2].
+	///
+	/// When the middle angle is ±pi/2 the first and last rotations are about the same axis, which
+	/// is gimbal lock, and only their sum or difference can be recovered. The first angle is then
+	/// zero and the last holds all of the rotation.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # #[macro_use] extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::mat::{Matrix33, EulerOrder};
+	/// # fn main() {
+	/// let quarter : f64 = std::f64::consts::FRAC_PI_2;
+	/// let orders : [EulerOrder; 6] = [EulerOrder::Xyz, EulerOrder::Xzy, EulerOrder::Yxz,
+	///                                 EulerOrder::Yzx, EulerOrder::Zxy, EulerOrder::Zyx];
+	/// for order in orders {
+	///         let r : Matrix33<f64> = Matrix33::from_euler(0.1, -0.4, 0.7, order);
+	///         let (x, y, z) = r.to_euler(order);
+	///         assert_approx_eq!(Vector3 { x, y, z }, Vector3 { x: 0.1, y: -0.4, z: 0.7 }, abs = 1e-14);
+	/// }
+	/// // gimbal lock, where rotating 0.3 about x and then pi/2 about y turns x
+	/// // onto the axis that z is rotated about
+	/// let r : Matrix33<f64> = Matrix33::from_euler(0.3, quarter, 0.5, EulerOrder::Xyz);
+	/// let (x, y, z) = r.to_euler(EulerOrder::Xyz);
+	/// assert_approx_eq!(Vector3 { x, y, z }, Vector3 { x: 0.0, y: quarter, z: 0.5 - 0.3 }, abs = 1e-14);
+	/// assert_approx_eq!(Matrix33::from_euler(x, y, z, EulerOrder::Xyz), r, abs = 1e-15);
+	/// let r : Matrix33<f64> = Matrix33::from_euler(0.3, -quarter, 0.5, EulerOrder::Xyz);
+	/// let (x, y, z) = r.to_euler(EulerOrder::Xyz);
+	/// assert_approx_eq!(Vector3 { x, y, z }, Vector3 { x: 0.0, y: -quarter, z: 0.5 + 0.3 }, abs = 1e-14);
+	/// # }
+	/// ```
+	pub fn to_euler(&self, order : EulerOrder) -> (T, T, T) {
+		let m : [[T; 3]; 3] = self.to_rows();
+		let ([i, j, k], parity) = order.axes();
+		let s : T = if parity > 0 { T::ONE } else { T::NEG_ONE };
+		let mut angles : [T; 3] = [T::ZERO; 3];
+		let cos_j : T = (m[k][k]*m[k][k] + m[k][j]*m[k][j]).sqrt();
+		angles[j] = (-s*m[k][i]).atan2(cos_j);
+		if cos_j <= T::EPSILON*(T::ONE + T::ONE + T::ONE + T::ONE) {
+			angles[k] = (-s*m[i][j]).atan2(m[j][j]);
+		} else {
+			angles[i] = (s*m[k][j]).atan2(m[k][k]);
+			angles[k] = (s*m[j][i]).atan2(m[i][i]);
+		}
+		(angles[0], angles[1], angles[2])
+	}
 	
-	pub fn cofactor(&self) -> Mat33 {
-		Mat33 { x1: self.y2*self.z3-self.y3*self.z2, y1: self.x3*self.z2-self.x2*self.z3, z1: self.x2*self.y3-self.x3*self.y2,
+	pub fn cofactor(&self) -> Matrix33<T> {
+		Matrix33 { x1: self.y2*self.z3-self.y3*self.z2, y1: self.x3*self.z2-self.x2*self.z3, z1: self.x2*self.y3-self.x3*self.y2,
 			x2: self.y3*self.z1-self.y1*self.z3, y2: self.x1*self.z3-self.x3*self.z1, z2: self.x3*self.y1-self.x1*self.y3,
 			x3: self.y1*self.z2-self.y2*self.z1, y3: self.x2*self.z1-self.x1*self.z2, z3: self.x1*self.y2-self.x2*self.y1 }
 	}
 
-	pub fn det(&self) -> f32 {
+	pub fn det(&self) -> T {
                  self.x1*(self.y2*self.z3-self.y3*self.z2)
 		-self.x2*(self.y1*self.z3-self.y3*self.z1)
 		+self.x3*(self.y1*self.z2-self.y2*self.z1)
 	}
 
-        pub fn inverse(&self) -> Mat33 {
+        pub fn inverse(&self) -> Matrix33<T> {
 		self.adj()/self.det()
 	}
 
-	pub fn t(&self) -> Mat33 {
-		Mat33 { x1: self.x1, y1: self.x2, z1: self.x3,
+	/// The inverse, failing instead of returning infinities when the determinant is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::mat::Mat33;
+	/// use lineq::error::LineqError;
+	/// let a : Mat33 = Mat33::augment(Vec3::UP, Vec3::IN, Vec3::RIGHT);
+	/// assert_eq!(a.try_inverse(), Ok(Mat33::augment(Vec3::IN, Vec3::RIGHT, Vec3::UP)));
+	/// let s : Mat33 = Mat33::augment(Vec3::UP, Vec3::DOWN, Vec3::RIGHT);
+	/// assert_eq!(s.try_inverse(), Err(LineqError::Singular));
+	/// ```
+	pub fn try_inverse(&self) -> Result<Matrix33<T>, LineqError> {
+		let det : T = self.det();
+		if det == T::ZERO { return Err(LineqError::Singular); }
+		Ok(self.adj()/det)
+	}
+
+	pub fn t(&self) -> Matrix33<T> {
+		Matrix33 { x1: self.x1, y1: self.x2, z1: self.x3,
                         x2: self.y1, y2: self.y2, z2: self.y3,
-                        x3: self.x1, y3: self.z2, z3: self.z3 }
+                        x3: self.z1, y3: self.z2, z3: self.z3 }
+	}
+
+	// Gaussian elimination with partial pivoting on the stack, returning the combined L and U
+	// factors in row-major order, the order the rows of the right hand side need to be taken in
+	// and the conditioning of the matrix.
+	fn eliminate(&self) -> Result<Elimination<T>, LineqError> {
+		let entries : [T; 9] = [self.x1, self.x2, self.x3, self.y1, self.y2, self.y3, self.z1, self.z2, self.z3];
+		let mut a : [T; 9] = entries;
+		let mut p : [usize; 3] = [0; 3];
+		lu::factor(&mut a, &mut p);
+		check_pivots(&entries, &[a[0], a[4], a[8]])?;
+		let rcond : T = lu::rcond(&entries, &a, &p, &mut [T::ZERO; 3], &mut [T::ZERO; 3]);
+		Ok((a, p, Conditioning { rcond }))
+	}
+
+	/// Solves `self * x = b` for x by Gaussian elimination with partial pivoting, failing only
+	/// if the matrix is singular. The solution comes with the [Conditioning] of the matrix.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::mat::Matrix33;
+	/// use lineq::error::LineqError;
+	/// let a : Matrix33<f64> = Matrix33 { x1: 0.0, x2: 1.0, x3: 2.0,
+	///                              y1: 1.0, y2: 0.0, y3: 3.0,
+	///                              z1: 4.0, z2: -3.0, z3: 8.0 };
+	/// let (x, cond) = a.solve(Vector3 { x: 8.0, y: 10.0, z: 22.0 }).unwrap();
+	/// assert!((x - Vector3 { x: 1.0, y: 2.0, z: 3.0 }).mag() < 1e-12);
+	/// assert!((cond.rcond - 1.0/169.0).abs() < 1e-12 && !cond.is_ill());
+	/// assert_eq!(Matrix33::<f64>::ZERO.solve(Vector3::ONE), Err(LineqError::Singular));
+	/// assert_eq!(Matrix33::<f64>::IDENTITY.solve(Vector3::ONE).unwrap().1.rcond, 1.0);
+	/// ```
+	pub fn solve(&self, b : Vector3<T>) -> Result<(Vector3<T>, Conditioning<T>), LineqError> {
+		let (a, p, cond) = self.eliminate()?;
+		let mut x : [T; 3] = [T::ZERO; 3];
+		lu::substitute(&a, &p, &[b.x, b.y, b.z], &mut x);
+		Ok((Vector3 { x: x[0], y: x[1], z: x[2] }, cond))
+	}
+
+	/// Solves `self * x = b` for every b in a [Vec3box](crate::vec3arr::Vec3box), only eliminating once, and returns
+	/// the solutions in the same box.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::Vec3box;
+	/// use lineq::mat::Mat33;
+	/// let a : Mat33 = Mat33::augment(Vec3 { x: 2.0, y: 0.0, z: 0.0 }, Vec3::UP, Vec3::IN);
+	/// let b : Vec3box = Vec3box(Box::new([Vec3 { x: 2.0, y: 1.0, z: 1.0 }, Vec3 { x: 4.0, y: 0.0, z: 0.0 }]));
+	/// let (x, cond) = a.solve_box(b).unwrap();
+	/// assert_eq!(x, Vec3box(Box::new([Vec3::ONE, Vec3 { x: 2.0, y: 0.0, z: 0.0 }])));
+	/// assert_eq!(cond.rcond, 0.5);
+	/// ```
+	pub fn solve_box(&self, mut b : Vector3box<T>) -> Result<(Vector3box<T>, Conditioning<T>), LineqError> {
+		let (a, p, cond) = self.eliminate()?;
+		b.transform(|v| {
+			let mut x : [T; 3] = [T::ZERO; 3];
+			lu::substitute(&a, &p, &[v.x, v.y, v.z], &mut x);
+			Vector3 { x: x[0], y: x[1], z: x[2] }
+		});
+		Ok((b, cond))
+	}
+
+	/// The determinant, found by LU factorization with partial pivoting, which is more accurate
+	/// than [det](crate::mat::Mat33::det) when the matrix is close to singular.
+	pub fn det_lu(&self) -> T {
+		LU::new_from_mat33(*self).det()
+	}
+
+	/// The inverse, found by LU factorization with partial pivoting, which unlike
+	/// [inverse](crate::mat::Mat33::inverse) reports singular matricies.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::mat::Mat33;
+	/// use lineq::error::LineqError;
+	/// let a : Mat33 = Mat33::augment(Vec3::UP, Vec3::IN, Vec3::RIGHT);
+	/// assert_eq!(a.inverse_lu(), Ok(Mat33::augment(Vec3::IN, Vec3::RIGHT, Vec3::UP)));
+	/// let s : Mat33 = Mat33::augment(Vec3::UP, Vec3::UP, Vec3::RIGHT);
+	/// assert_eq!(s.inverse_lu(), Err(LineqError::Singular));
+	/// ```
+	pub fn inverse_lu(&self) -> Result<Matrix33<T>, LineqError> {
+		Ok(LU::new_from_mat33(*self).inverse()?.to_mat33())
+	}
+
+	/// The eigenvalues of a symmetric matrix in ascending order, and the matching unit eigenvectors
+	/// as the columns of a rotation matrix, found by the cyclic Jacobi method. Each pair of
+	/// off-diagonal entries is averaged, so a matrix that is not quite symmetric from rounding is
+	/// treated as symmetric. Repeated eigenvalues still get orthonormal eigenvectors.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::mat::Matrix33;
+	/// // an inertia tensor with two equal principal moments
+	/// let a : Matrix33<f64> = Matrix33 { x1: 2.0, x2: 1.0, x3: 0.0,
+	///                              y1: 1.0, y2: 2.0, y3: 0.0,
+	///                              z1: 0.0, z2: 0.0, z3: 3.0 };
+	/// let (l, v) = a.eigen_sym();
+	/// assert!((l - Vector3 { x: 1.0, y: 3.0, z: 3.0 }).mag() < 1e-12);
+	/// let cols : [Vector3<f64>; 3] = [Vector3 { x: v.x1, y: v.y1, z: v.z1 },
+	///                              Vector3 { x: v.x2, y: v.y2, z: v.z2 },
+	///                              Vector3 { x: v.x3, y: v.y3, z: v.z3 }];
+	/// for (i, c) in cols.iter().enumerate() {
+	///     let li : f64 = [l.x, l.y, l.z][i];
+	///     assert!((a*(*c) - *c*li).mag() < 1e-12);
+	///     assert!((c.mag() - 1.0).abs() < 1e-12);
+	/// }
+	/// assert!((cols[0]*cols[1]).abs() < 1e-12 && (cols[1]*cols[2]).abs() < 1e-12);
+	/// assert!((v.det() - 1.0).abs() < 1e-12);
+	/// ```
+	pub fn eigen_sym(&self) -> (Vector3<T>, Matrix33<T>) {
+		let two : T = T::ONE + T::ONE;
+		let mut a : [[T; 3]; 3] = [[self.x1, (self.x2 + self.y1)/two, (self.x3 + self.z1)/two],
+			[(self.x2 + self.y1)/two, self.y2, (self.y3 + self.z2)/two],
+			[(self.x3 + self.z1)/two, (self.y3 + self.z2)/two, self.z3]];
+		let mut v : [[T; 3]; 3] = [[T::ONE, T::ZERO, T::ZERO], [T::ZERO, T::ONE, T::ZERO], [T::ZERO, T::ZERO, T::ONE]];
+		let mut norm : T = T::ZERO;
+		for row in a.iter() {
+			for e in row.iter() {
+				norm += *e**e;
+			}
+		}
+		for _ in 0..32 {
+			let off : T = a[0][1]*a[0][1] + a[0][2]*a[0][2] + a[1][2]*a[1][2];
+			if off <= norm*T::EPSILON*T::EPSILON { break; }
+			for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+				if a[p][q] == T::ZERO { continue; }
+				// the rotation by t = tan(angle) in the p, q plane that zeros a[p][q]
+				let theta : T = (a[q][q] - a[p][p])/(a[p][q] + a[p][q]);
+				let t : T = if theta < T::ZERO { T::NEG_ONE } else { T::ONE }/(theta.abs() + (theta*theta + T::ONE).sqrt());
+				let c : T = T::ONE/(t*t + T::ONE).sqrt();
+				let s : T = t*c;
+				for k in 0..3 {
+					let (akp, akq) = (a[k][p], a[k][q]);
+					a[k][p] = c*akp - s*akq;
+					a[k][q] = s*akp + c*akq;
+					let (vkp, vkq) = (v[k][p], v[k][q]);
+					v[k][p] = c*vkp - s*vkq;
+					v[k][q] = s*vkp + c*vkq;
+				}
+				let (rp, rq) = (a[p], a[q]);
+				for (k, (&apk, &aqk)) in rp.iter().zip(rq.iter()).enumerate() {
+					a[p][k] = c*apk - s*aqk;
+					a[q][k] = s*apk + c*aqk;
+				}
+				a[p][q] = T::ZERO;
+				a[q][p] = T::ZERO;
+			}
+		}
+		let mut order : [usize; 3] = [0, 1, 2];
+		order.sort_by(|i, j| a[*i][*i].partial_cmp(&a[*j][*j]).unwrap_or(std::cmp::Ordering::Equal));
+		let col = |j : usize| Vector3 { x: v[0][j], y: v[1][j], z: v[2][j] };
+		let (v1, v2) = (col(order[0]), col(order[1]));
+		(Vector3 { x: a[order[0]][order[0]], y: a[order[1]][order[1]], z: a[order[2]][order[2]] },
+			Matrix33::augment(v1, v2, v1.cross(v2)))
+	}
+
+	/// The singular value decomposition `self = U Σ Vᵀ`, returned as U, the diagonal of Σ in
+	/// descending order and Vᵀ, see [SVD].
+	pub fn svd(&self) -> (Matrix33<T>, Vector3<T>, Matrix33<T>) {
+		let svd : SVD<T> = SVD::new_from_matn(&MatrixN::new_from_mat33(*self));
+		(svd.u().to_mat33(), Vector3 { x: svd.s()[0], y: svd.s()[1], z: svd.s()[2] }, svd.vt().to_mat33())
+	}
+
+	/// The singular value decomposition with U and Vᵀ both rotations, where the last singular
+	/// value is negative if the determinant is. `U Vᵀ` is then the rotation closest to the matrix,
+	/// which gives the Kabsch alignment when the matrix is the covariance of two point sets.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::mat::Matrix33;
+	/// use lineq::quat::Quaternion;
+	/// let r : Matrix33<f64> = Quaternion::from_axis_angle(Vector3 { x: 1.0, y: 2.0, z: 2.0 }.norm(), 0.7).to_mat33();
+	/// // a rotation that has picked up some error
+	/// let noisy : Matrix33<f64> = r + Matrix33 { x1: 0.01, x2: -0.02, x3: 0.0,
+	///                                      y1: 0.0, y2: 0.01, y3: 0.03,
+	///                                      z1: -0.01, z2: 0.0, z3: 0.02 };
+	/// let (u, s, vt) = noisy.svd_proper();
+	/// let nearest : Matrix33<f64> = u*vt;
+	/// assert!((nearest.det() - 1.0).abs() < 1e-12);
+	/// assert!(s.x >= s.y && s.y >= s.z);
+	/// let e : Matrix33<f64> = nearest*nearest.t() - Matrix33::IDENTITY;
+	/// assert!([e.x1, e.x2, e.x3, e.y1, e.y2, e.y3, e.z1, e.z2, e.z3].iter().all(|e| e.abs() < 1e-12));
+	/// let d : Matrix33<f64> = nearest - r;
+	/// assert!([d.x1, d.x2, d.x3, d.y1, d.y2, d.y3, d.z1, d.z2, d.z3].iter().all(|e| e.abs() < 0.05));
+	/// ```
+	pub fn svd_proper(&self) -> (Matrix33<T>, Vector3<T>, Matrix33<T>) {
+		let svd : SVD<T> = SVD::new_from_matn_proper(&MatrixN::new_from_mat33(*self));
+		(svd.u().to_mat33(), Vector3 { x: svd.s()[0], y: svd.s()[1], z: svd.s()[2] }, svd.vt().to_mat33())
+	}
+}
+
+
+//Mat44 Methods
+
+impl<T: Scalar> Matrix44<T> {
+	pub const IDENTITY : Matrix44<T> = Matrix44 { x1: T::ONE, y1: T::ZERO, z1: T::ZERO, w1: T::ZERO,
+					x2: T::ZERO, y2: T::ONE, z2: T::ZERO, w2: T::ZERO,
+					x3: T::ZERO, y3: T::ZERO, z3: T::ONE, w3: T::ZERO,
+					x4: T::ZERO, y4: T::ZERO, z4: T::ZERO, w4: T::ONE };
+	pub const ZERO : Matrix44<T> = Matrix44 { x1: T::ZERO, y1: T::ZERO, z1: T::ZERO, w1: T::ZERO,
+					x2: T::ZERO, y2: T::ZERO, z2: T::ZERO, w2: T::ZERO,
+					x3: T::ZERO, y3: T::ZERO, z3: T::ZERO, w3: T::ZERO,
+					x4: T::ZERO, y4: T::ZERO, z4: T::ZERO, w4: T::ZERO };
+
+	pub fn augment(v1 : Vector4<T>, v2 : Vector4<T>, v3 : Vector4<T>, v4 : Vector4<T>) -> Matrix44<T> {
+		Matrix44 { x1: v1.x, y1: v1.y, z1: v1.z, w1: v1.w,
+			x2: v2.x, y2: v2.y, z2: v2.z, w2: v2.w,
+			x3: v3.x, y3: v3.y, z3: v3.z, w3: v3.w,
+			x4: v4.x, y4: v4.y, z4: v4.z, w4: v4.w }
+	}
+
+	pub fn det(&self) -> T {
+		let s0 : T = self.x1*self.y2 - self.y1*self.x2;
+		let s1 : T = self.x1*self.y3 - self.y1*self.x3;
+		let s2 : T = self.x1*self.y4 - self.y1*self.x4;
+		let s3 : T = self.x2*self.y3 - self.y2*self.x3;
+		let s4 : T = self.x2*self.y4 - self.y2*self.x4;
+		let s5 : T = self.x3*self.y4 - self.y3*self.x4;
+		let c0 : T = self.z1*self.w2 - self.w1*self.z2;
+		let c1 : T = self.z1*self.w3 - self.w1*self.z3;
+		let c2 : T = self.z1*self.w4 - self.w1*self.z4;
+		let c3 : T = self.z2*self.w3 - self.w2*self.z3;
+		let c4 : T = self.z2*self.w4 - self.w2*self.z4;
+		let c5 : T = self.z3*self.w4 - self.w3*self.z4;
+		s0*c5 - s1*c4 + s2*c3 + s3*c2 - s4*c1 + s5*c0
+	}
+
+        pub fn inverse(&self) -> Matrix44<T> {
+		match self.try_inverse() {
+			Ok(inv) => inv,
+			Err(_) => panic!("non-invertible matrix"),
+		}
+	}
+
+	/// The inverse, failing instead of panicking when the determinant is zero.
+	pub fn try_inverse(&self) -> Result<Matrix44<T>, LineqError> {
+		let s0 : T = self.x1*self.y2 - self.y1*self.x2;
+		let s1 : T = self.x1*self.y3 - self.y1*self.x3;
+		let s2 : T = self.x1*self.y4 - self.y1*self.x4;
+		let s3 : T = self.x2*self.y3 - self.y2*self.x3;
+		let s4 : T = self.x2*self.y4 - self.y2*self.x4;
+		let s5 : T = self.x3*self.y4 - self.y3*self.x4;
+		let c0 : T = self.z1*self.w2 - self.w1*self.z2;
+		let c1 : T = self.z1*self.w3 - self.w1*self.z3;
+		let c2 : T = self.z1*self.w4 - self.w1*self.z4;
+		let c3 : T = self.z2*self.w3 - self.w2*self.z3;
+		let c4 : T = self.z2*self.w4 - self.w2*self.z4;
+		let c5 : T = self.z3*self.w4 - self.w3*self.z4;
+		let det : T = s0*c5 - s1*c4 + s2*c3 + s3*c2 - s4*c1 + s5*c0;
+		if det == T::ZERO { return Err(LineqError::Singular); }
+		Ok(Matrix44 { x1: (self.y2*c5 - self.y3*c4 + self.y4*c3)/det, y1: (-self.y1*c5 + self.y3*c2 - self.y4*c1)/det, z1: (self.y1*c4 - self.y2*c2 + self.y4*c0)/det, w1: (-self.y1*c3 + self.y2*c1 - self.y3*c0)/det,
+			x2: (-self.x2*c5 + self.x3*c4 - self.x4*c3)/det, y2: (self.x1*c5 - self.x3*c2 + self.x4*c1)/det, z2: (-self.x1*c4 + self.x2*c2 - self.x4*c0)/det, w2: (self.x1*c3 - self.x2*c1 + self.x3*c0)/det,
+			x3: (self.w2*s5 - self.w3*s4 + self.w4*s3)/det, y3: (-self.w1*s5 + self.w3*s2 - self.w4*s1)/det, z3: (self.w1*s4 - self.w2*s2 + self.w4*s0)/det, w3: (-self.w1*s3 + self.w2*s1 - self.w3*s0)/det,
+			x4: (-self.z2*s5 + self.z3*s4 - self.z4*s3)/det, y4: (self.z1*s5 - self.z3*s2 + self.z4*s1)/det, z4: (-self.z1*s4 + self.z2*s2 - self.z4*s0)/det, w4: (self.z1*s3 - self.z2*s1 + self.z3*s0)/det })
+	}
+
+	pub fn t(&self) -> Matrix44<T> {
+		Matrix44 { x1: self.x1, y1: self.x2, z1: self.x3, w1: self.x4,
+			x2: self.y1, y2: self.y2, z2: self.y3, w2: self.y4,
+			x3: self.z1, y3: self.z2, z3: self.z3, w3: self.z4,
+			x4: self.w1, y4: self.w2, z4: self.w3, w4: self.w4 }
+	}
+
+	/// Creates a matrix that translates homogeneous points by `v`.
+	pub fn translation(v : Vector3<T>) -> Matrix44<T> {
+		Matrix44 { x1: T::ONE, y1: T::ZERO, z1: T::ZERO, w1: T::ZERO,
+			x2: T::ZERO, y2: T::ONE, z2: T::ZERO, w2: T::ZERO,
+			x3: T::ZERO, y3: T::ZERO, z3: T::ONE, w3: T::ZERO,
+			x4: v.x, y4: v.y, z4: v.z, w4: T::ONE }
+	}
+
+	/// Creates a matrix that scales each axis by the matching component of `v`.
+	pub fn scale(v : Vector3<T>) -> Matrix44<T> {
+		Matrix44 { x1: v.x, y1: T::ZERO, z1: T::ZERO, w1: T::ZERO,
+			x2: T::ZERO, y2: v.y, z2: T::ZERO, w2: T::ZERO,
+			x3: T::ZERO, y3: T::ZERO, z3: v.z, w3: T::ZERO,
+			x4: T::ZERO, y4: T::ZERO, z4: T::ZERO, w4: T::ONE }
+	}
+
+	/// Creates a right handed view matrix for a camera at `eye` looking towards `target`,
+	/// where the camera looks down its negative z axis.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::mat::Mat44;
+	/// let view : Mat44 = Mat44::look_at(Vec3 { x: 0.0, y: 0.0, z: 5.0 }, Vec3::ZERO, Vec3::UP);
+	/// assert_eq!(view.transform_point(Vec3::ZERO), Vec3 { x: 0.0, y: 0.0, z: -5.0 });
+	/// ```
+	pub fn look_at(eye : Vector3<T>, target : Vector3<T>, up : Vector3<T>) -> Matrix44<T> {
+		let f : Vector3<T> = (target - eye).norm();
+		let s : Vector3<T> = f.cross(up).norm();
+		let u : Vector3<T> = s.cross(f);
+		Matrix44 { x1: s.x, y1: u.x, z1: -f.x, w1: T::ZERO,
+			x2: s.y, y2: u.y, z2: -f.y, w2: T::ZERO,
+			x3: s.z, y3: u.z, z3: -f.z, w3: T::ZERO,
+			x4: -(s*eye), y4: -(u*eye), z4: f*eye, w4: T::ONE }
+	}
+
+	/// Creates a right handed perspective projection, mapping depths between `near` and `far`
+	/// to [-1, 1], where `fovy` is the vertical field of view in radians.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::mat::Matrix44;
+	/// let proj : Matrix44<f64> = Matrix44::perspective(std::f64::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+	/// assert!((proj.transform_point(Vector3 { x: 0.0, y: 0.0, z: -1.0 }).z + 1.0).abs() < 1e-12);
+	/// assert!((proj.transform_point(Vector3 { x: 0.0, y: 0.0, z: -10.0 }).z - 1.0).abs() < 1e-12);
+	/// ```
+	pub fn perspective(fovy : T, aspect : T, near : T, far : T) -> Matrix44<T> {
+		let two : T = T::ONE + T::ONE;
+		let f : T = T::ONE/(fovy/two).tan();
+		Matrix44 { x1: f/aspect, y1: T::ZERO, z1: T::ZERO, w1: T::ZERO,
+			x2: T::ZERO, y2: f, z2: T::ZERO, w2: T::ZERO,
+			x3: T::ZERO, y3: T::ZERO, z3: (far + near)/(near - far), w3: T::NEG_ONE,
+			x4: T::ZERO, y4: T::ZERO, z4: two*far*near/(near - far), w4: T::ZERO }
+	}
+
+	/// Creates a right handed orthographic projection, mapping the given box to [-1, 1] on every axis.
+	pub fn orthographic(left : T, right : T, bottom : T, top : T, near : T, far : T) -> Matrix44<T> {
+		let two : T = T::ONE + T::ONE;
+		Matrix44 { x1: two/(right - left), y1: T::ZERO, z1: T::ZERO, w1: T::ZERO,
+			x2: T::ZERO, y2: two/(top - bottom), z2: T::ZERO, w2: T::ZERO,
+			x3: T::ZERO, y3: T::ZERO, z3: -two/(far - near), w3: T::ZERO,
+			x4: -(right + left)/(right - left), y4: -(top + bottom)/(top - bottom), z4: -(far + near)/(far - near), w4: T::ONE }
+	}
+
+	/// Transforms `v` as a point, so that translations apply, and divides through by w.
+	pub fn transform_point(&self, v : Vector3<T>) -> Vector3<T> {
+		(*self * Vector4::from_point(v)).to_point()
+	}
+
+	/// Transforms `v` as a direction, so that translations do not apply.
+	pub fn transform_dir(&self, v : Vector3<T>) -> Vector3<T> {
+		(*self * Vector4::from_dir(v)).to_dir()
 	}
 }
@@ -0,0 +1,484 @@
+//! Module containing dynamically sized, heap backed matricies and vectors.
+//!
+//! [MatN](crate::matn::MatN) stores its entries contiguously in row-major order and
+//! is indexed by `(row, col)`, and [VecN](crate::matn::VecN) is a boxed slice of scalars.
+//! Operations between values whose sizes do not match panic, the same as the arrays of vectors.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::matn::{MatN, VecN};
+//! let a : MatN = MatN::from_rows(2, 3, Box::new([1.0, 2.0, 3.0,
+//!                                                4.0, 5.0, 6.0]));
+//! let x : VecN = VecN(Box::new([1.0, 0.0, -1.0]));
+//! assert_eq!(a[(1, 2)], 6.0);
+//! assert_eq!(&a * &x, VecN(Box::new([-2.0, -2.0])));
+//! assert_eq!((&a * &a.t())[(0, 1)], 32.0);
+//! ```
+
+use crate::scalar::Scalar;
+use crate::mat::Matrix22;
+use crate::mat::Matrix33;
+use crate::vec3::Vector3;
+use ::deref_impl;
+use ::deref_mut_impl;
+
+/// A matrix with any number of rows and columns, stored row-major on the heap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixN<T> {
+        rows : usize,
+        cols : usize,
+        data : Box<[T]>,
+}
+
+/// A [MatN] of [f32]s.
+pub type MatN = MatrixN<f32>;
+
+/// A vector with any number of components, stored on the heap.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VectorN<T>(pub Box<[T]>);
+
+/// A [VecN] of [f32]s.
+pub type VecN = VectorN<f32>;
+
+/// Creates a [VecN] from its components.
+#[allow(non_snake_case)]
+pub const fn VecN(v : Box<[f32]>) -> VecN {
+        VectorN(v)
+}
+
+macro_rules! value_impl {
+        ($imp:ident;$func:ident;$aimp:ident;$afunc:ident; $rhs:ty; for $lhs:ident) => {
+                impl<T: Scalar> $imp<$rhs> for $lhs<T> {
+                        type Output = $lhs<T>;
+                        #[inline]
+                        fn $func(mut self, rhs: $rhs) -> $lhs<T> {
+                                $aimp::$afunc(&mut self, rhs);
+                                self
+                        }
+                }
+                impl<T: Scalar> $imp<$rhs> for &$lhs<T> {
+                        type Output = $lhs<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> $lhs<T> {
+                                let mut tmp : $lhs<T> = self.clone();
+                                $aimp::$afunc(&mut tmp, rhs);
+                                tmp
+                        }
+                }
+        };
+}
+
+macro_rules! inplace_impl {
+        ($imp:ident;$func:ident;$op:tt; 0 T; for $lhs:ident) => {
+                impl<T: Scalar> $imp<T> for $lhs<T> {
+                        #[inline]
+                        fn $func(&mut self, rhs: T) {
+                                for a in self.as_mut_slice() {
+                                        *a $op rhs;
+                                }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 $lhs:ident) => {
+                impl<T: Scalar> $imp<&$lhs<T>> for $lhs<T> {
+                        #[inline]
+                        fn $func(&mut self, rhs: &$lhs<T>) {
+                                self.check_size(rhs);
+                                for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+                                        *a $op *b;
+                                }
+                        }
+                }
+                impl<T: Scalar> $imp<$lhs<T>> for $lhs<T> {
+                        #[inline]
+                        fn $func(&mut self, rhs: $lhs<T>) {
+                                $imp::$func(self, &rhs);
+                        }
+                }
+        };
+}
+
+macro_rules! lhs_impl {
+        ($imp:ident;$func:ident;$aimp:ident;$afunc:ident; $rhs:ident; for $lhs:ident) => {
+                impl $imp<$rhs<$lhs>> for $lhs {
+                        type Output = $rhs<$lhs>;
+                        #[inline]
+                        fn $func(self, mut rhs: $rhs<$lhs>) -> $rhs<$lhs> {
+                                $aimp::$afunc(&mut rhs, self);
+                                rhs
+                        }
+                }
+        };
+}
+
+//Deref
+use std::ops::Deref;
+
+deref_impl! {Deref val VectorN<T>; to [T]; T: Scalar}
+
+//DerefMut
+use std::ops::DerefMut;
+
+deref_mut_impl! {DerefMut val VectorN<T>; to [T]; T: Scalar}
+
+//Add
+use std::ops::Add;
+
+value_impl! {Add;add;AddAssign;add_assign; T; for MatrixN}
+value_impl! {Add;add;AddAssign;add_assign; MatrixN<T>; for MatrixN}
+value_impl! {Add;add;AddAssign;add_assign; &MatrixN<T>; for MatrixN}
+value_impl! {Add;add;AddAssign;add_assign; T; for VectorN}
+value_impl! {Add;add;AddAssign;add_assign; VectorN<T>; for VectorN}
+value_impl! {Add;add;AddAssign;add_assign; &VectorN<T>; for VectorN}
+lhs_impl! {Add;add;AddAssign;add_assign; MatrixN; for f32}
+lhs_impl! {Add;add;AddAssign;add_assign; MatrixN; for f64}
+lhs_impl! {Add;add;AddAssign;add_assign; VectorN; for f32}
+lhs_impl! {Add;add;AddAssign;add_assign; VectorN; for f64}
+
+//AddAssign
+use std::ops::AddAssign;
+
+inplace_impl! {AddAssign;add_assign;+=; 0 T; for MatrixN}
+inplace_impl! {AddAssign;add_assign;+=; 1 MatrixN}
+inplace_impl! {AddAssign;add_assign;+=; 0 T; for VectorN}
+inplace_impl! {AddAssign;add_assign;+=; 1 VectorN}
+
+//Display
+use std::fmt;
+
+impl<T: Scalar> fmt::Display for MatrixN<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "[")?;
+                for i in 0..self.rows {
+                        write!(f, "[")?;
+                        for j in 0..self.cols {
+                                if j != 0 { write!(f, ", ")?; }
+                                write!(f, "{}", self[(i, j)])?;
+                        }
+                        write!(f, "]")?;
+                }
+                write!(f, "]")
+        }
+}
+
+impl<T: Scalar> fmt::Display for VectorN<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "<")?;
+                for i in 0..self.len() {
+                        if i != 0 { write!(f, ", ")?; }
+                        write!(f, "{}", self[i])?;
+                }
+                write!(f, ">")
+        }
+}
+
+//Div
+use std::ops::Div;
+
+value_impl! {Div;div;DivAssign;div_assign; T; for MatrixN}
+value_impl! {Div;div;DivAssign;div_assign; T; for VectorN}
+
+//DivAssign
+use std::ops::DivAssign;
+
+inplace_impl! {DivAssign;div_assign;/=; 0 T; for MatrixN}
+inplace_impl! {DivAssign;div_assign;/=; 0 T; for VectorN}
+
+//Index
+use std::ops::Index;
+
+impl<T: Scalar> Index<(usize, usize)> for MatrixN<T> {
+        type Output = T;
+        #[inline]
+        fn index(&self, (row, col): (usize, usize)) -> &T {
+                if row >= self.rows || col >= self.cols { panic!("index out of matrix bounds"); }
+                &self.data[row*self.cols + col]
+        }
+}
+
+//IndexMut
+use std::ops::IndexMut;
+
+impl<T: Scalar> IndexMut<(usize, usize)> for MatrixN<T> {
+        #[inline]
+        fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+                if row >= self.rows || col >= self.cols { panic!("index out of matrix bounds"); }
+                &mut self.data[row*self.cols + col]
+        }
+}
+
+//Mult
+use std::ops::Mul;
+
+value_impl! {Mul;mul;MulAssign;mul_assign; T; for MatrixN}
+value_impl! {Mul;mul;MulAssign;mul_assign; T; for VectorN}
+lhs_impl! {Mul;mul;MulAssign;mul_assign; MatrixN; for f32}
+lhs_impl! {Mul;mul;MulAssign;mul_assign; MatrixN; for f64}
+lhs_impl! {Mul;mul;MulAssign;mul_assign; VectorN; for f32}
+lhs_impl! {Mul;mul;MulAssign;mul_assign; VectorN; for f64}
+
+impl<T: Scalar> Mul<&MatrixN<T>> for &MatrixN<T> {
+        type Output = MatrixN<T>;
+        fn mul(self, rhs: &MatrixN<T>) -> MatrixN<T> {
+                self.mul_mat(rhs)
+        }
+}
+
+impl<T: Scalar> Mul<MatrixN<T>> for MatrixN<T> {
+        type Output = MatrixN<T>;
+        fn mul(self, rhs: MatrixN<T>) -> MatrixN<T> {
+                &self * &rhs
+        }
+}
+
+impl<T: Scalar> Mul<&VectorN<T>> for &MatrixN<T> {
+        type Output = VectorN<T>;
+        fn mul(self, rhs: &VectorN<T>) -> VectorN<T> {
+                self.mul_vec(rhs)
+        }
+}
+
+impl<T: Scalar> Mul<VectorN<T>> for MatrixN<T> {
+        type Output = VectorN<T>;
+        fn mul(self, rhs: VectorN<T>) -> VectorN<T> {
+                &self * &rhs
+        }
+}
+
+impl<T: Scalar> Mul<&VectorN<T>> for &VectorN<T> {
+        type Output = T;
+        fn mul(self, rhs: &VectorN<T>) -> T {
+                self.dot(rhs)
+        }
+}
+
+impl<T: Scalar> Mul<VectorN<T>> for VectorN<T> {
+        type Output = T;
+        fn mul(self, rhs: VectorN<T>) -> T {
+                &self * &rhs
+        }
+}
+
+//MultAssign
+use std::ops::MulAssign;
+
+inplace_impl! {MulAssign;mul_assign;*=; 0 T; for MatrixN}
+inplace_impl! {MulAssign;mul_assign;*=; 0 T; for VectorN}
+
+//Neg
+use std::ops::Neg;
+
+impl<T: Scalar> Neg for MatrixN<T> {
+        type Output = MatrixN<T>;
+        fn neg(mut self) -> MatrixN<T> {
+                for a in self.data.iter_mut() {
+                        *a = -*a;
+                }
+                self
+        }
+}
+
+impl<T: Scalar> Neg for VectorN<T> {
+        type Output = VectorN<T>;
+        fn neg(mut self) -> VectorN<T> {
+                for a in self.0.iter_mut() {
+                        *a = -*a;
+                }
+                self
+        }
+}
+
+//Sub
+use std::ops::Sub;
+
+value_impl! {Sub;sub;SubAssign;sub_assign; T; for MatrixN}
+value_impl! {Sub;sub;SubAssign;sub_assign; MatrixN<T>; for MatrixN}
+value_impl! {Sub;sub;SubAssign;sub_assign; &MatrixN<T>; for MatrixN}
+value_impl! {Sub;sub;SubAssign;sub_assign; T; for VectorN}
+value_impl! {Sub;sub;SubAssign;sub_assign; VectorN<T>; for VectorN}
+value_impl! {Sub;sub;SubAssign;sub_assign; &VectorN<T>; for VectorN}
+
+//SubAssign
+use std::ops::SubAssign;
+
+inplace_impl! {SubAssign;sub_assign;-=; 0 T; for MatrixN}
+inplace_impl! {SubAssign;sub_assign;-=; 1 MatrixN}
+inplace_impl! {SubAssign;sub_assign;-=; 0 T; for VectorN}
+inplace_impl! {SubAssign;sub_assign;-=; 1 VectorN}
+
+//MatN Methods
+
+impl<T: Scalar> MatrixN<T> {
+
+	/// Creates a matrix from its entries in row-major order.
+	pub fn from_rows(rows : usize, cols : usize, data : Box<[T]>) -> MatrixN<T> {
+		if data.len() != rows*cols { panic!("data does not match matrix dimensions"); }
+		MatrixN { rows, cols, data }
+	}
+
+	pub fn new_zeroed(rows : usize, cols : usize) -> MatrixN<T> {
+		MatrixN { rows, cols, data: vec![T::ZERO; rows*cols].into_boxed_slice() }
+	}
+
+	pub fn identity(n : usize) -> MatrixN<T> {
+		MatrixN::ftomat(|i, j| if i == j { T::ONE } else { T::ZERO }, n, n)
+	}
+
+	/// Creates a matrix where the entry at `(row, col)` is `f(row, col)`.
+	pub fn ftomat<F : Fn(usize, usize) -> T>(f: F, rows : usize, cols : usize) -> MatrixN<T> {
+		let mut data : Vec<T> = Vec::with_capacity(rows*cols);
+		for i in 0..rows {
+			for j in 0..cols {
+				data.push(f(i, j));
+			}
+		}
+		MatrixN { rows, cols, data: data.into_boxed_slice() }
+	}
+
+	pub fn new_from_mat22(m : Matrix22<T>) -> MatrixN<T> {
+		MatrixN::from_rows(2, 2, Box::new([m.x1, m.x2, m.y1, m.y2]))
+	}
+
+	pub fn new_from_mat33(m : Matrix33<T>) -> MatrixN<T> {
+		MatrixN::from_rows(3, 3, Box::new([m.x1, m.x2, m.x3, m.y1, m.y2, m.y3, m.z1, m.z2, m.z3]))
+	}
+
+	/// Creates an N×3 matrix whose rows are the vectors of a [Vec3box](crate::vec3arr::Vec3box), or of any other array of
+	/// vectors, borrowed as a slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::Vec3box;
+	/// use lineq::matn::MatN;
+	/// let a : MatN = MatN::new_from_box(&Vec3box(Box::new([Vec3::UP, Vec3::IN])));
+	/// assert_eq!((a.rows(), a.cols()), (2, 3));
+	/// assert_eq!(a, MatN::from_rows(2, 3, Box::new([0.0, 1.0, 0.0, 0.0, 0.0, 1.0])));
+	/// ```
+	pub fn new_from_box(arr : &[Vector3<T>]) -> MatrixN<T> {
+		MatrixN::ftomat(|i, j| match j { 0 => arr[i].x, 1 => arr[i].y, _ => arr[i].z }, arr.len(), 3)
+	}
+
+	/// Converts a 2 by 2 matrix to a [Mat22](crate::mat::Mat22), panicking if it is any other size.
+	pub fn to_mat22(&self) -> Matrix22<T> {
+		if self.rows != 2 || self.cols != 2 { panic!("matrix dimensions do not match"); }
+		Matrix22 { x1: self.data[0], x2: self.data[1], y1: self.data[2], y2: self.data[3] }
+	}
+
+	/// Converts a 3 by 3 matrix to a [Mat33](crate::mat::Mat33), panicking if it is any other size.
+	pub fn to_mat33(&self) -> Matrix33<T> {
+		if self.rows != 3 || self.cols != 3 { panic!("matrix dimensions do not match"); }
+		Matrix33 { x1: self.data[0], x2: self.data[1], x3: self.data[2],
+			y1: self.data[3], y2: self.data[4], y3: self.data[5],
+			z1: self.data[6], z2: self.data[7], z3: self.data[8] }
+	}
+
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// The entries in row-major order.
+	pub fn as_slice(&self) -> &[T] {
+		&self.data
+	}
+
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		&mut self.data
+	}
+
+	pub fn row(&self, row : usize) -> VectorN<T> {
+		VectorN::ftoarr(|j| self[(row, j)], self.cols)
+	}
+
+	pub fn col(&self, col : usize) -> VectorN<T> {
+		VectorN::ftoarr(|i| self[(i, col)], self.rows)
+	}
+
+	pub fn t(&self) -> MatrixN<T> {
+		MatrixN::ftomat(|i, j| self[(j, i)], self.cols, self.rows)
+	}
+
+	fn mul_mat(&self, rhs : &MatrixN<T>) -> MatrixN<T> {
+		if self.cols != rhs.rows { panic!("matrix dimensions do not match"); }
+		let mut tmp : MatrixN<T> = MatrixN::new_zeroed(self.rows, rhs.cols);
+		for i in 0..self.rows {
+			for k in 0..self.cols {
+				let a : T = self.data[i*self.cols + k];
+				for j in 0..rhs.cols {
+					tmp.data[i*rhs.cols + j] += a*rhs.data[k*rhs.cols + j];
+				}
+			}
+		}
+		tmp
+	}
+
+	fn mul_vec(&self, rhs : &VectorN<T>) -> VectorN<T> {
+		if self.cols != rhs.len() { panic!("matrix and vector dimensions do not match"); }
+		VectorN::ftoarr(|i| {
+			let mut sum : T = T::ZERO;
+			for j in 0..self.cols {
+				sum += self.data[i*self.cols + j]*rhs[j];
+			}
+			sum
+		}, self.rows)
+	}
+
+	fn check_size(&self, rhs : &MatrixN<T>) {
+		if self.rows != rhs.rows || self.cols != rhs.cols { panic!("matrices inequal size"); }
+	}
+}
+
+//VecN Methods
+
+impl<T: Scalar> VectorN<T> {
+
+	pub fn new_zeroed(len : usize) -> VectorN<T> {
+		VectorN(vec![T::ZERO; len].into_boxed_slice())
+	}
+
+	pub fn ftoarr<F : Fn(usize) -> T>(f: F, n : usize) -> VectorN<T> {
+		VectorN((0..n).map(f).collect())
+	}
+
+	pub fn as_slice(&self) -> &[T] {
+		&self.0
+	}
+
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		&mut self.0
+	}
+
+	pub fn dot(&self, rhs : &VectorN<T>) -> T {
+		self.check_size(rhs);
+		let mut sum : T = T::ZERO;
+		for i in 0..self.len() {
+			sum += self[i]*rhs[i];
+		}
+		sum
+	}
+
+        pub fn mag(&self) -> T {
+                self.mag2().sqrt()
+        }
+
+        pub fn mag2(&self) -> T {
+                self.dot(self)
+        }
+
+        pub fn norm(&self) -> VectorN<T> {
+                self / self.mag()
+        }
+
+	fn check_size(&self, rhs : &VectorN<T>) {
+		if self.len() != rhs.len() { panic!("slices inequal length"); }
+	}
+}
@@ -0,0 +1,169 @@
+//! Module containing the parallel versions of the methods on boxed arrays of vectors.
+//!
+//! With the `rayon` feature enabled, [Vec2box](crate::vec2arr::Vec2box) and
+//! [Vec3box](crate::vec3arr::Vec3box) get `par_` versions of `transform`, `mag`, `mag2`, `norm`,
+//! `crossarr` or `detarr`, the dot product and the elementwise operators. Each splits the array
+//! into chunks of `chunk` vectors, which are the units of work handed to the rayon thread pool,
+//! and runs the same SIMD kernels as the serial methods on every chunk.
+//! [CHUNK_LEN] is a good default, smaller chunks balance better across threads and larger ones
+//! have less overhead.
+//! They all panic if `chunk` is zero.
+//!
+//! Every vector is computed on its own, so the results are bit for bit the same as the serial
+//! methods, whatever the chunk size or number of threads.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::Vec3;
+//! use lineq::vec3arr::Vec3box;
+//! use lineq::par::CHUNK_LEN;
+//! let a : Vec3box = Vec3box::ftoarr(|i| Vec3 { x: i as f32 + 0.1, y: 1.3, z: -0.7*i as f32 }, 1000);
+//! let b : Vec3box = Vec3box::ftoarr(|i| Vec3 { x: 0.3, y: i as f32 - 4.1, z: 2.9 }, 1000);
+//! assert_eq!(a.par_norm(CHUNK_LEN), a.norm());
+//! assert_eq!(a.par_mag(CHUNK_LEN), a.mag());
+//! assert_eq!(a.par_crossarr(&b, CHUNK_LEN), a.crossarr(b.clone()));
+//! // the chunk size only changes how the work is split up
+//! for chunk in [1, 7, 64, 1000] {
+//!         assert_eq!(a.par_add(&b, chunk), &a + &b);
+//!         assert_eq!(a.par_dot(&b, chunk), &a * &b);
+//!         assert_eq!(a.par_div(3.0, chunk), &a / 3.0);
+//! }
+//! ```
+
+use crate::scalar::Scalar;
+use crate::simd;
+use crate::simd::{Components, Lanes};
+use crate::vec2::Vector2;
+use crate::vec2arr::Vector2box;
+use crate::vec3::Vector3;
+use crate::vec3arr::Vector3box;
+use std::mem::MaybeUninit;
+use rayon::prelude::*;
+
+/// The suggested number of vectors in each chunk of work.
+pub const CHUNK_LEN : usize = 1 << 14;
+
+// Runs `f` on matching chunks of `out` and `a` in parallel.
+fn par_map<O: Send, V: Sync, F: Fn(&mut [O], &[V]) + Sync>(out : &mut [O], a : &[V], chunk : usize, f : F) {
+	if out.len() != a.len() { panic!("slices inequal length"); }
+	out.par_chunks_mut(chunk).zip(a.par_chunks(chunk)).for_each(|(out, a)| f(out, a));
+}
+
+// Runs `f` on matching chunks of `out`, `a` and `b` in parallel.
+fn par_map2<O: Send, V: Sync, F: Fn(&mut [O], &[V], &[V]) + Sync>(out : &mut [O], a : &[V], b : &[V], chunk : usize, f : F) {
+	if out.len() != a.len() || a.len() != b.len() { panic!("slices inequal length"); }
+	out.par_chunks_mut(chunk).zip(a.par_chunks(chunk)).zip(b.par_chunks(chunk)).for_each(|((out, a), b)| f(out, a, b));
+}
+
+fn par_zip<V: Components + Send + Sync, F: Fn(Lanes<V>, Lanes<V>) -> Lanes<V> + Sync>(a : &[V], b : &[V], chunk : usize, f : F) -> Box<[V]> {
+	let mut tmp = Box::<[V]>::new_uninit_slice(a.len());
+	par_map2(&mut tmp, a, b, chunk, |out, a, b| simd::zip(out, a, b, &f));
+	unsafe { tmp.assume_init() }
+}
+
+fn par_zip_scalar<V: Components + Send + Sync, F: Fn(Lanes<V>, Lanes<V>) -> Lanes<V> + Sync>(a : &[V], s : V::Scalar, chunk : usize, f : F) -> Box<[V]> where V::Scalar: Sync {
+	let mut tmp = Box::<[V]>::new_uninit_slice(a.len());
+	par_map(&mut tmp, a, chunk, |out, a| simd::zip_scalar(out, a, s, &f));
+	unsafe { tmp.assume_init() }
+}
+
+fn par_scalars<V: Components + Sync, F: Fn(&mut [MaybeUninit<V::Scalar>], &[V]) + Sync>(a : &[V], chunk : usize, f : F) -> Box<[V::Scalar]> where V::Scalar: Send {
+	let mut tmp = Box::<[V::Scalar]>::new_uninit_slice(a.len());
+	par_map(&mut tmp, a, chunk, f);
+	unsafe { tmp.assume_init() }
+}
+
+macro_rules! par_impl {
+        (Par $t:ident, $v:ident) => {
+                impl<T: Scalar + Send + Sync> $t<T> {
+                        /// Applies `f` to every vector in parallel, like [transform](Self::transform).
+                        pub fn par_transform<F: Fn($v<T>) -> $v<T> + Sync>(&mut self, f : F, chunk : usize) {
+                                self.par_chunks_mut(chunk).for_each(|a| for e in a.iter_mut() { *e = f(*e); });
+                        }
+
+                        pub fn par_mag(&self, chunk : usize) -> Box<[T]> {
+                                par_scalars(self, chunk, simd::mag)
+                        }
+
+                        pub fn par_mag2(&self, chunk : usize) -> Box<[T]> {
+                                par_scalars(self, chunk, simd::mag2)
+                        }
+
+                        pub fn par_norm(&self, chunk : usize) -> $t<T> {
+                                let mut tmp = $t::new_uninit_box(self.len());
+                                par_map(&mut tmp, self, chunk, simd::norm);
+                                $t(unsafe { tmp.assume_init() })
+                        }
+
+                        /// The dot products of matching vectors, the same as `&self * rhs`, panicking if the lengths differ.
+                        pub fn par_dot(&self, rhs : &[$v<T>], chunk : usize) -> Box<[T]> {
+                                let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+                                par_map2(&mut tmp, self, rhs, chunk, simd::dot);
+                                unsafe { tmp.assume_init() }
+                        }
+
+                        /// The same as `&self + rhs`, panicking if the lengths differ.
+                        pub fn par_add(&self, rhs : &[$v<T>], chunk : usize) -> $t<T> {
+                                $t(par_zip(self, rhs, chunk, |a, b| a + b))
+                        }
+
+                        /// The same as `&self - rhs`, panicking if the lengths differ.
+                        pub fn par_sub(&self, rhs : &[$v<T>], chunk : usize) -> $t<T> {
+                                $t(par_zip(self, rhs, chunk, |a, b| a - b))
+                        }
+
+                        pub fn par_mul(&self, rhs : T, chunk : usize) -> $t<T> {
+                                $t(par_zip_scalar(self, rhs, chunk, |a, s| a * s))
+                        }
+
+                        pub fn par_div(&self, rhs : T, chunk : usize) -> $t<T> {
+                                $t(par_zip_scalar(self, rhs, chunk, |a, s| a / s))
+                        }
+
+                        /// The same as `self += rhs`, panicking if the lengths differ.
+                        pub fn par_add_assign(&mut self, rhs : &[$v<T>], chunk : usize) {
+                                if self.len() != rhs.len() { panic!("slices inequal length"); }
+                                self.par_chunks_mut(chunk).zip(rhs.par_chunks(chunk)).for_each(|(a, b)| simd::zip_assign(a, b, |mut a, b| { a += b; a }));
+                        }
+
+                        /// The same as `self -= rhs`, panicking if the lengths differ.
+                        pub fn par_sub_assign(&mut self, rhs : &[$v<T>], chunk : usize) {
+                                if self.len() != rhs.len() { panic!("slices inequal length"); }
+                                self.par_chunks_mut(chunk).zip(rhs.par_chunks(chunk)).for_each(|(a, b)| simd::zip_assign(a, b, |mut a, b| { a -= b; a }));
+                        }
+
+                        pub fn par_mul_assign(&mut self, rhs : T, chunk : usize) {
+                                self.par_chunks_mut(chunk).for_each(|a| simd::zip_scalar_assign(a, rhs, |mut a, s| { a *= s; a }));
+                        }
+
+                        pub fn par_div_assign(&mut self, rhs : T, chunk : usize) {
+                                self.par_chunks_mut(chunk).for_each(|a| simd::zip_scalar_assign(a, rhs, |mut a, s| { a /= s; a }));
+                        }
+                }
+        };
+}
+
+par_impl! {Par Vector2box, Vector2}
+par_impl! {Par Vector3box, Vector3}
+
+impl<T: Scalar + Send + Sync> Vector3box<T> {
+	/// The cross products of matching vectors, like [crossarr](crate::vec3arr::Vec3box::crossarr),
+	/// panicking if the lengths differ.
+	pub fn par_crossarr(&self, rhs : &[Vector3<T>], chunk : usize) -> Vector3box<T> {
+		let mut tmp = Vector3box::new_uninit_box(self.len());
+		par_map2(&mut tmp, self, rhs, chunk, simd::cross);
+		Vector3box(unsafe { tmp.assume_init() })
+	}
+}
+
+impl<T: Scalar + Send + Sync> Vector2box<T> {
+	/// The determinants of matching vectors, like [detarr](crate::vec2arr::Vec2box::detarr),
+	/// panicking if the lengths differ.
+	pub fn par_detarr(&self, rhs : &[Vector2<T>], chunk : usize) -> Box<[T]> {
+		let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+		par_map2(&mut tmp, self, rhs, chunk, simd::det);
+		unsafe { tmp.assume_init() }
+	}
+}
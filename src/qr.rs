@@ -0,0 +1,285 @@
+//! Module containing Householder QR factorization and least squares solving.
+//!
+//! Tall matricies are factored with column pivoting so that the rank can be read off the
+//! diagonal of R, and rank deficient systems still get a solution, with the components
+//! belonging to dependent columns set to zero.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::matn::{MatrixN, VectorN};
+//! use lineq::qr::{lstsq, Lstsq};
+//! // fitting y = c0 + c1 t to four samples of y = 1 + 2t with some noise
+//! let a : MatrixN<f64> = MatrixN::from_rows(4, 2, Box::new([1.0, 0.0,
+//!                                                     1.0, 1.0,
+//!                                                     1.0, 2.0,
+//!                                                     1.0, 3.0]));
+//! let b : VectorN<f64> = VectorN(Box::new([1.0, 3.1, 4.9, 7.0]));
+//! let fit : Lstsq<f64> = lstsq(&a, &b);
+//! assert_eq!(fit.rank, 2);
+//! assert!((fit.solution[0] - 1.03).abs() < 1e-12 && (fit.solution[1] - 1.98).abs() < 1e-12);
+//! assert!((fit.residual - (&a * &fit.solution - b).mag()).abs() < 1e-12);
+//! ```
+
+use crate::scalar::Scalar;
+use crate::vec2::Vector2;
+use crate::mat::{Matrix22, Matrix33};
+use crate::matn::{MatrixN, VectorN};
+use crate::vec2arr::Vector2box;
+use crate::vec3arr::Vector3box;
+
+/// The QR factorization `A P = Q R` of an m by n matrix A with m >= n, where P is a column
+/// permutation, Q has orthonormal columns and R is upper triangular with a non-increasing diagonal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QR<T = f32> {
+        rows : usize,
+        cols : usize,
+        qr : Box<[T]>,
+        beta : Box<[T]>,
+        rdiag : Box<[T]>,
+        perm : Box<[usize]>,
+        rank : usize,
+}
+
+/// The result of a least squares solve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lstsq<T = f32, S = VectorN<T>> {
+	/// The x minimizing `|A x - b|`.
+	pub solution : S,
+	/// The norm of the residual `A x - b` at the solution.
+	pub residual : T,
+	/// The numerical rank of A.
+	pub rank : usize,
+}
+
+/// Finds the x minimizing `|A x - b|` for a tall matrix A.
+///
+/// When A is rank deficient the components of x belonging to dependent columns are zero.
+pub fn lstsq<T: Scalar>(a : &MatrixN<T>, b : &VectorN<T>) -> Lstsq<T> {
+	QR::new_from_matn(a).solve(b)
+}
+
+/// Fits the line `y = m x + c` through the points of a [Vec2box](crate::vec2arr::Vec2box), returning the slope m
+/// as x and the intercept c as y of the solution.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec2::Vector2;
+/// use lineq::vec2arr::Vector2box;
+/// use lineq::qr::fit_line;
+/// let points : Vector2box<f64> = Vector2box::ftoarr(|i| Vector2 { x: i as f64, y: 0.5*(i as f64) - 2.0 }, 10);
+/// let fit = fit_line(&points);
+/// assert!((fit.solution - Vector2 { x: 0.5, y: -2.0 }).mag() < 1e-12);
+/// assert!(fit.residual < 1e-12);
+/// ```
+pub fn fit_line<T: Scalar>(points : &Vector2box<T>) -> Lstsq<T, Vector2<T>> {
+	let a : MatrixN<T> = MatrixN::ftomat(|i, j| if j == 0 { points[i].x } else { T::ONE }, points.len(), 2);
+	let fit : Lstsq<T> = lstsq(&a, &VectorN::ftoarr(|i| points[i].y, points.len()));
+	Lstsq { solution: Vector2 { x: fit.solution[0], y: fit.solution[1] }, residual: fit.residual, rank: fit.rank }
+}
+
+/// Finds the matrix M minimizing the sum of `|M a[i] - b[i]|²` over two [Vec2box](crate::vec2arr::Vec2box)s of the same length.
+pub fn lstsq_vec2box<T: Scalar>(a : &Vector2box<T>, b : &Vector2box<T>) -> Lstsq<T, Matrix22<T>> {
+	if a.len() != b.len() { panic!("slices inequal length"); }
+	let qr : QR<T> = QR::new_from_matn(&MatrixN::ftomat(|i, j| if j == 0 { a[i].x } else { a[i].y }, a.len(), 2));
+	let r1 : Lstsq<T> = qr.solve(&VectorN::ftoarr(|i| b[i].x, b.len()));
+	let r2 : Lstsq<T> = qr.solve(&VectorN::ftoarr(|i| b[i].y, b.len()));
+	Lstsq {
+		solution: Matrix22 { x1: r1.solution[0], x2: r1.solution[1],
+			y1: r2.solution[0], y2: r2.solution[1] },
+		residual: (r1.residual*r1.residual + r2.residual*r2.residual).sqrt(),
+		rank: qr.rank,
+	}
+}
+
+/// Finds the matrix M minimizing the sum of `|M a[i] - b[i]|²` over two [Vec3box](crate::vec3arr::Vec3box)s of the same length,
+/// such as a calibration matrix from measured and expected samples.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec3::Vector3;
+/// use lineq::mat::Matrix33;
+/// use lineq::vec3arr::Vector3box;
+/// use lineq::qr::lstsq_vec3box;
+/// let m : Matrix33<f64> = Matrix33 { x1: 2.0, x2: 0.1, x3: 0.0,
+///                              y1: 0.0, y2: 1.5, y3: -0.2,
+///                              z1: 0.3, z2: 0.0, z3: 1.0 };
+/// let a : Vector3box<f64> = Vector3box::ftoarr(|i| Vector3 { x: i as f64, y: (i*i) as f64, z: 1.0 }, 6);
+/// let b : Vector3box<f64> = Vector3box::ftoarr(|i| m*a[i], 6);
+/// let fit = lstsq_vec3box(&a, &b);
+/// assert_eq!(fit.rank, 3);
+/// assert!(fit.residual < 1e-10);
+/// assert!((fit.solution.x2 - 0.1).abs() < 1e-10 && (fit.solution.z1 - 0.3).abs() < 1e-10);
+/// ```
+pub fn lstsq_vec3box<T: Scalar>(a : &Vector3box<T>, b : &Vector3box<T>) -> Lstsq<T, Matrix33<T>> {
+	if a.len() != b.len() { panic!("slices inequal length"); }
+	let qr : QR<T> = QR::new_from_matn(&MatrixN::new_from_box(a));
+	let r1 : Lstsq<T> = qr.solve(&VectorN::ftoarr(|i| b[i].x, b.len()));
+	let r2 : Lstsq<T> = qr.solve(&VectorN::ftoarr(|i| b[i].y, b.len()));
+	let r3 : Lstsq<T> = qr.solve(&VectorN::ftoarr(|i| b[i].z, b.len()));
+	Lstsq {
+		solution: Matrix33 { x1: r1.solution[0], x2: r1.solution[1], x3: r1.solution[2],
+			y1: r2.solution[0], y2: r2.solution[1], y3: r2.solution[2],
+			z1: r3.solution[0], z2: r3.solution[1], z3: r3.solution[2] },
+		residual: (r1.residual*r1.residual + r2.residual*r2.residual + r3.residual*r3.residual).sqrt(),
+		rank: qr.rank,
+	}
+}
+
+//QR Methods
+
+impl<T: Scalar> QR<T> {
+
+	/// Factors a [MatN](crate::matn::MatN), panicking if it has more columns than rows.
+	pub fn new_from_matn(a : &MatrixN<T>) -> QR<T> {
+		let m : usize = a.rows();
+		let n : usize = a.cols();
+		if m < n { panic!("matrix has more columns than rows"); }
+		let mut qr : Box<[T]> = a.as_slice().into();
+		let mut beta : Box<[T]> = vec![T::ZERO; n].into_boxed_slice();
+		let mut rdiag : Box<[T]> = vec![T::ZERO; n].into_boxed_slice();
+		let mut perm : Box<[usize]> = (0..n).collect();
+		for k in 0..n {
+			// pivot the remaining column with the largest norm into place
+			let mut p : usize = k;
+			let mut pnorm : T = T::NEG_ONE;
+			for j in k..n {
+				let mut norm : T = T::ZERO;
+				for i in k..m {
+					norm += qr[i*n + j]*qr[i*n + j];
+				}
+				if norm > pnorm { p = j; pnorm = norm; }
+			}
+			if p != k {
+				for i in 0..m {
+					qr.swap(i*n + k, i*n + p);
+				}
+				perm.swap(k, p);
+			}
+			if pnorm == T::ZERO { continue; }
+			// reflect x onto alpha e1, storing v = x - alpha e1 in place of x
+			let x0 : T = qr[k*n + k];
+			let alpha : T = if x0 > T::ZERO { -pnorm.sqrt() } else { pnorm.sqrt() };
+			qr[k*n + k] = x0 - alpha;
+			rdiag[k] = alpha;
+			beta[k] = T::ONE/(pnorm - x0*alpha);
+			for j in k+1..n {
+				let mut s : T = T::ZERO;
+				for i in k..m {
+					s += qr[i*n + k]*qr[i*n + j];
+				}
+				s *= beta[k];
+				for i in k..m {
+					qr[i*n + j] -= s*qr[i*n + k];
+				}
+			}
+		}
+		let mut tol : T = T::ZERO;
+		for _ in 0..m {
+			tol += T::EPSILON;
+		}
+		if n > 0 { tol *= rdiag[0].abs(); }
+		let rank : usize = rdiag.iter().take_while(|r| r.abs() > tol).count();
+		QR { rows: m, cols: n, qr, beta, rdiag, perm, rank }
+	}
+
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// The numerical rank, the number of diagonal entries of R that are not negligible
+	/// compared to the largest.
+	pub fn rank(&self) -> usize {
+		self.rank
+	}
+
+	/// The column permutation P, where column j of `A P` is column `perm()[j]` of A.
+	pub fn perm(&self) -> &[usize] {
+		&self.perm
+	}
+
+	/// The m by n factor Q, with orthonormal columns.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::matn::MatrixN;
+	/// use lineq::qr::QR;
+	/// let a : MatrixN<f64> = MatrixN::ftomat(|i, j| ((i + 1)*(j + 2)) as f64 + if i == j { 1.0 } else { 0.0 }, 5, 3);
+	/// let qr : QR<f64> = QR::new_from_matn(&a);
+	/// let q : MatrixN<f64> = qr.q();
+	/// let ap : MatrixN<f64> = MatrixN::ftomat(|i, j| a[(i, qr.perm()[j])], 5, 3);
+	/// let e1 : MatrixN<f64> = &q.t() * &q - MatrixN::identity(3);
+	/// let e2 : MatrixN<f64> = &q * &qr.r() - ap;
+	/// assert!(e1.as_slice().iter().chain(e2.as_slice()).all(|e| e.abs() < 1e-12));
+	/// ```
+	pub fn q(&self) -> MatrixN<T> {
+		let (m, n) = (self.rows, self.cols);
+		let mut q : MatrixN<T> = MatrixN::ftomat(|i, j| if i == j { T::ONE } else { T::ZERO }, m, n);
+		for k in (0..n).rev() {
+			for j in 0..n {
+				let mut s : T = T::ZERO;
+				for i in k..m {
+					s += self.qr[i*n + k]*q[(i, j)];
+				}
+				s *= self.beta[k];
+				for i in k..m {
+					q[(i, j)] -= s*self.qr[i*n + k];
+				}
+			}
+		}
+		q
+	}
+
+	/// The n by n upper triangular factor R.
+	pub fn r(&self) -> MatrixN<T> {
+		let n : usize = self.cols;
+		MatrixN::ftomat(|i, j| if i == j { self.rdiag[i] } else if j > i { self.qr[i*n + j] } else { T::ZERO }, n, n)
+	}
+
+	/// Finds the x minimizing `|A x - b|`, see [lstsq].
+	pub fn solve(&self, b : &VectorN<T>) -> Lstsq<T> {
+		let (m, n) = (self.rows, self.cols);
+		if b.len() != m { panic!("matrix and vector dimensions do not match"); }
+		// y = Qᵀ b
+		let mut y : Box<[T]> = b.as_slice().into();
+		for k in 0..n {
+			let mut s : T = T::ZERO;
+			for i in k..m {
+				s += self.qr[i*n + k]*y[i];
+			}
+			s *= self.beta[k];
+			for i in k..m {
+				y[i] -= s*self.qr[i*n + k];
+			}
+		}
+		// back substitute through the leading rank by rank block of R
+		let mut z : Box<[T]> = vec![T::ZERO; n].into_boxed_slice();
+		for i in (0..self.rank).rev() {
+			let mut sum : T = y[i];
+			for j in i+1..self.rank {
+				sum -= self.qr[i*n + j]*z[j];
+			}
+			z[i] = sum/self.rdiag[i];
+		}
+		let mut x : VectorN<T> = VectorN::new_zeroed(n);
+		for j in 0..n {
+			x[self.perm[j]] = z[j];
+		}
+		let mut residual : T = T::ZERO;
+		for e in &y[self.rank..] {
+			residual += *e**e;
+		}
+		Lstsq { solution: x, residual: residual.sqrt(), rank: self.rank }
+	}
+}
@@ -0,0 +1,331 @@
+//! Module containing quaternions for composing and interpolating 3D rotations.
+//!
+//! Quaternions are written as w + xi + yj + zk, and only unit quaternions
+//! represent rotations, so [norm](crate::quat::Quat::norm) should be used after
+//! long chains of products to remove drift.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::Vector3;
+//! use lineq::quat::Quaternion;
+//! let q : Quaternion<f64> = Quaternion::from_axis_angle(Vector3::IN, std::f64::consts::FRAC_PI_2);
+//! let v : Vector3<f64> = q.rotate(Vector3::RIGHT);
+//! assert!((v - Vector3::UP).mag() < 1e-12);
+//! assert!(((q*q).rotate(Vector3::RIGHT) - Vector3::LEFT).mag() < 1e-12);
+//! ```
+//!
+//! Whole arrays of vectors can be rotated at once, which converts the quaternion
+//! to a [Mat33](crate::mat::Mat33) a single time:
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::Vector3;
+//! use lineq::vec3arr::Vector3box;
+//! use lineq::quat::Quaternion;
+//! let q : Quaternion<f64> = Quaternion::from_euler(0.3, 0.2, 0.1);
+//! let a : Vector3box<f64> = Vector3box(Box::new([Vector3::UP, Vector3::IN]));
+//! let b : Vector3box<f64> = q * a.clone();
+//! assert!((b[0] - q.rotate(a[0])).mag() < 1e-12);
+//! assert!((b[1] - q.rotate(a[1])).mag() < 1e-12);
+//! ```
+
+use crate::scalar::Scalar;
+use crate::error::LineqError;
+use crate::vec3::Vector3;
+use crate::mat::Matrix33;
+use crate::vec3arr::Vector3arr;
+use crate::vec3arr::{Vector3box, Vector3vec};
+
+/// A quaternion w + xi + yj + zk.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Quaternion<T> {
+        pub w : T,
+        pub x : T,
+        pub y : T,
+        pub z : T,
+}
+
+/// A [Quat] of [f32]s.
+pub type Quat = Quaternion<f32>;
+
+//Add
+use std::ops::Add;
+
+impl<T: Scalar> Add<Quaternion<T>> for Quaternion<T> {
+        type Output = Quaternion<T>;
+        fn add(self, rhs: Quaternion<T>) -> Quaternion<T> {
+                Quaternion { w: self.w + rhs.w, x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+        }
+}
+
+//Display
+use std::fmt;
+
+impl<T: Scalar> fmt::Display for Quaternion<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} + {}i + {}j + {}k", self.w, self.x, self.y, self.z)
+        }
+}
+
+//Div
+use std::ops::Div;
+
+impl<T: Scalar> Div<T> for Quaternion<T> {
+        type Output = Quaternion<T>;
+        fn div(self, rhs: T) -> Quaternion<T> {
+                Quaternion { w: self.w/rhs, x: self.x/rhs, y: self.y/rhs, z: self.z/rhs }
+        }
+}
+
+//Mult
+use std::ops::Mul;
+
+impl<T: Scalar> Mul<T> for Quaternion<T> {
+        type Output = Quaternion<T>;
+        fn mul(self, rhs: T) -> Quaternion<T> {
+                Quaternion { w: self.w * rhs, x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+        }
+}
+
+impl<T: Scalar> Mul<Quaternion<T>> for Quaternion<T> {
+        type Output = Quaternion<T>;
+        fn mul(self, rhs: Quaternion<T>) -> Quaternion<T> {
+                Quaternion { w: self.w*rhs.w - self.x*rhs.x - self.y*rhs.y - self.z*rhs.z,
+                        x: self.w*rhs.x + self.x*rhs.w + self.y*rhs.z - self.z*rhs.y,
+                        y: self.w*rhs.y - self.x*rhs.z + self.y*rhs.w + self.z*rhs.x,
+                        z: self.w*rhs.z + self.x*rhs.y - self.y*rhs.x + self.z*rhs.w }
+        }
+}
+
+impl<T: Scalar> Mul<Vector3<T>> for Quaternion<T> {
+	type Output = Vector3<T>;
+	fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+		self.rotate(rhs)
+	}
+}
+
+impl<const N: usize, T: Scalar> Mul<Vector3arr<N, T>> for Quaternion<T> {
+	type Output = Vector3arr<N, T>;
+	fn mul(self, rhs: Vector3arr<N, T>) -> Vector3arr<N, T> {
+		self.to_mat33() * rhs
+	}
+}
+
+impl<T: Scalar> Mul<Vector3box<T>> for Quaternion<T> {
+	type Output = Vector3box<T>;
+	fn mul(self, rhs: Vector3box<T>) -> Vector3box<T> {
+		self.to_mat33() * rhs
+	}
+}
+
+impl<T: Scalar> Mul<Vector3vec<T>> for Quaternion<T> {
+	type Output = Vector3vec<T>;
+	fn mul(self, rhs: Vector3vec<T>) -> Vector3vec<T> {
+		self.to_mat33() * rhs
+	}
+}
+
+//MultAssign
+use std::ops::MulAssign;
+
+impl<T: Scalar> MulAssign<Quaternion<T>> for Quaternion<T> {
+	fn mul_assign(&mut self, rhs: Quaternion<T>) {
+		*self = *self * rhs;
+	}
+}
+
+//Neg
+use std::ops::Neg;
+
+impl<T: Scalar> Neg for Quaternion<T> {
+        type Output = Quaternion<T>;
+        fn neg(self) -> Quaternion<T> {
+                Quaternion { w: -self.w, x: -self.x, y: -self.y, z: -self.z }
+        }
+}
+
+//Sub
+use std::ops::Sub;
+
+impl<T: Scalar> Sub<Quaternion<T>> for Quaternion<T> {
+        type Output = Quaternion<T>;
+        fn sub(self, rhs: Quaternion<T>) -> Quaternion<T> {
+                Quaternion { w: self.w - rhs.w, x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+        }
+}
+
+//Quat Methods
+
+impl<T: Scalar> Quaternion<T> {
+	pub const IDENTITY : Quaternion<T> = Quaternion { w: T::ONE, x: T::ZERO, y: T::ZERO, z: T::ZERO };
+
+	/// Creates a rotation of `angle` radians about `axis`, which does not need to be normalized.
+	pub fn from_axis_angle(axis : Vector3<T>, angle : T) -> Quaternion<T> {
+		let half : T = angle/(T::ONE + T::ONE);
+		let v : Vector3<T> = axis.norm() * half.sin();
+		Quaternion { w: half.cos(), x: v.x, y: v.y, z: v.z }
+	}
+
+	/// Returns the axis and angle in radians of the rotation, using [Vec3::RIGHT](crate::vec3::Vec3::RIGHT) as the
+	/// axis when the rotation is close to the identity.
+	pub fn to_axis_angle(&self) -> (Vector3<T>, T) {
+		let q : Quaternion<T> = if self.w < T::ZERO { -self.norm() } else { self.norm() };
+		let s : T = (q.x*q.x + q.y*q.y + q.z*q.z).sqrt();
+		let angle : T = (T::ONE + T::ONE)*s.atan2(q.w);
+		if s <= T::EPSILON { return (Vector3::RIGHT, angle); }
+		(Vector3 { x: q.x/s, y: q.y/s, z: q.z/s }, angle)
+	}
+
+	/// Creates a rotation that rotates about the x axis by `x`, then the y axis by `y`,
+	/// then the z axis by `z`, all in radians.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::quat::Quaternion;
+	/// let q : Quaternion<f64> = Quaternion::from_euler(0.1, -0.4, 2.0);
+	/// let (x, y, z) = q.to_euler();
+	/// assert!((x - 0.1).abs() < 1e-12 && (y + 0.4).abs() < 1e-12 && (z - 2.0).abs() < 1e-12);
+	/// ```
+	pub fn from_euler(x : T, y : T, z : T) -> Quaternion<T> {
+		let two : T = T::ONE + T::ONE;
+		let qx : Quaternion<T> = Quaternion { w: (x/two).cos(), x: (x/two).sin(), y: T::ZERO, z: T::ZERO };
+		let qy : Quaternion<T> = Quaternion { w: (y/two).cos(), x: T::ZERO, y: (y/two).sin(), z: T::ZERO };
+		let qz : Quaternion<T> = Quaternion { w: (z/two).cos(), x: T::ZERO, y: T::ZERO, z: (z/two).sin() };
+		qz*qy*qx
+	}
+
+	/// Returns the angles (x, y, z) in radians such that [from_euler](crate::quat::Quat::from_euler)
+	/// gives back this rotation, with y in [-pi/2, pi/2].
+	pub fn to_euler(&self) -> (T, T, T) {
+		let two : T = T::ONE + T::ONE;
+		let q : Quaternion<T> = self.norm();
+		let mut sy : T = two*(q.w*q.y - q.z*q.x);
+		if sy > T::ONE { sy = T::ONE; }
+		if sy < T::NEG_ONE { sy = T::NEG_ONE; }
+		((two*(q.w*q.x + q.y*q.z)).atan2(T::ONE - two*(q.x*q.x + q.y*q.y)),
+		 sy.asin(),
+		 (two*(q.w*q.z + q.x*q.y)).atan2(T::ONE - two*(q.y*q.y + q.z*q.z)))
+	}
+
+	/// Creates a quaternion from a rotation matrix.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::quat::Quaternion;
+	/// let q : Quaternion<f64> = Quaternion::from_euler(1.0, 2.0, 3.0);
+	/// let p : Quaternion<f64> = Quaternion::from_mat33(q.to_mat33());
+	/// assert!((q.dot(p).abs() - 1.0).abs() < 1e-12);
+	/// ```
+	pub fn from_mat33(m : Matrix33<T>) -> Quaternion<T> {
+		let two : T = T::ONE + T::ONE;
+		let four : T = two + two;
+		let tr : T = m.x1 + m.y2 + m.z3;
+		if tr > T::ZERO {
+			let s : T = (tr + T::ONE).sqrt()*two;
+			Quaternion { w: s/four, x: (m.z2 - m.y3)/s, y: (m.x3 - m.z1)/s, z: (m.y1 - m.x2)/s }
+		} else if m.x1 > m.y2 && m.x1 > m.z3 {
+			let s : T = (T::ONE + m.x1 - m.y2 - m.z3).sqrt()*two;
+			Quaternion { w: (m.z2 - m.y3)/s, x: s/four, y: (m.x2 + m.y1)/s, z: (m.x3 + m.z1)/s }
+		} else if m.y2 > m.z3 {
+			let s : T = (T::ONE + m.y2 - m.x1 - m.z3).sqrt()*two;
+			Quaternion { w: (m.x3 - m.z1)/s, x: (m.x2 + m.y1)/s, y: s/four, z: (m.y3 + m.z2)/s }
+		} else {
+			let s : T = (T::ONE + m.z3 - m.x1 - m.y2).sqrt()*two;
+			Quaternion { w: (m.y1 - m.x2)/s, x: (m.x3 + m.z1)/s, y: (m.y3 + m.z2)/s, z: s/four }
+		}
+	}
+
+	/// Returns the rotation matrix of a unit quaternion.
+	pub fn to_mat33(&self) -> Matrix33<T> {
+		let two : T = T::ONE + T::ONE;
+		Matrix33 { x1: T::ONE - two*(self.y*self.y + self.z*self.z), y1: two*(self.x*self.y + self.w*self.z), z1: two*(self.x*self.z - self.w*self.y),
+			x2: two*(self.x*self.y - self.w*self.z), y2: T::ONE - two*(self.x*self.x + self.z*self.z), z2: two*(self.y*self.z + self.w*self.x),
+			x3: two*(self.x*self.z + self.w*self.y), y3: two*(self.y*self.z - self.w*self.x), z3: T::ONE - two*(self.x*self.x + self.y*self.y) }
+	}
+
+	pub fn conj(&self) -> Quaternion<T> {
+		Quaternion { w: self.w, x: -self.x, y: -self.y, z: -self.z }
+	}
+
+	pub fn inverse(&self) -> Quaternion<T> {
+		let mag2 : T = self.mag2();
+		if mag2 == T::ZERO { panic!("non-invertible quaternion"); }
+		self.conj()/mag2
+	}
+
+	/// The inverse, failing instead of panicking for the zero quaternion.
+	pub fn try_inverse(&self) -> Result<Quaternion<T>, LineqError> {
+		let mag2 : T = self.mag2();
+		if mag2 == T::ZERO { return Err(LineqError::ZeroVector); }
+		Ok(self.conj()/mag2)
+	}
+
+	pub fn dot(&self, rhs : Quaternion<T>) -> T {
+		self.w*rhs.w + self.x*rhs.x + self.y*rhs.y + self.z*rhs.z
+	}
+
+        pub fn mag(&self) -> T {
+                self.mag2().sqrt()
+        }
+
+        pub fn mag2(&self) -> T {
+                self.w*self.w + self.x*self.x + self.y*self.y + self.z*self.z
+        }
+
+        pub fn norm(&self) -> Quaternion<T> {
+                *self/self.mag()
+        }
+
+	/// Normalizes the quaternion, failing instead of dividing by zero when its magnitude is zero.
+	pub fn try_norm(&self) -> Result<Quaternion<T>, LineqError> {
+		let mag : T = self.mag();
+		if mag == T::ZERO { return Err(LineqError::ZeroVector); }
+		Ok(*self/mag)
+	}
+
+	/// Rotates `v` by a unit quaternion.
+	pub fn rotate(&self, v : Vector3<T>) -> Vector3<T> {
+		let two : T = T::ONE + T::ONE;
+		let q : Vector3<T> = Vector3 { x: self.x, y: self.y, z: self.z };
+		let t : Vector3<T> = q.cross(v) * two;
+		v + t * self.w + q.cross(t)
+	}
+
+	/// Normalized linear interpolation from `self` at `t = 0` to `rhs` at `t = 1`, along the shorter path.
+	pub fn nlerp(&self, rhs : Quaternion<T>, t : T) -> Quaternion<T> {
+		let rhs : Quaternion<T> = if self.dot(rhs) < T::ZERO { -rhs } else { rhs };
+		(*self*(T::ONE - t) + rhs*t).norm()
+	}
+
+	/// Spherical linear interpolation from `self` at `t = 0` to `rhs` at `t = 1`, along the shorter path,
+	/// so that the angular velocity is constant.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::quat::Quaternion;
+	/// let a : Quaternion<f64> = Quaternion::IDENTITY;
+	/// let b : Quaternion<f64> = Quaternion::from_axis_angle(Vector3::UP, 2.0);
+	/// let c : Quaternion<f64> = a.slerp(b, 0.25);
+	/// assert!((c.to_axis_angle().1 - 0.5).abs() < 1e-12);
+	/// ```
+	pub fn slerp(&self, rhs : Quaternion<T>, t : T) -> Quaternion<T> {
+		let mut d : T = self.dot(rhs);
+		let rhs : Quaternion<T> = if d < T::ZERO { d = -d; -rhs } else { rhs };
+		if T::ONE - d <= T::EPSILON.sqrt() { return self.nlerp(rhs, t); }
+		let theta : T = d.acos();
+		let s : T = theta.sin();
+		(*self*(((T::ONE - t)*theta).sin()/s) + rhs*((t*theta).sin()/s)).norm()
+	}
+}
@@ -0,0 +1,125 @@
+//! Module containing the scalar trait that vectors and matricies are generic over.
+//!
+//! Every vector, matrix and array of vectors in this crate stores its components
+//! as some type implementing [Scalar](crate::scalar::Scalar). The trait is
+//! implemented for [f32] and [f64], and the short names like `Vec3` are aliases of
+//! the generic types for [f32], so `Vec3` is the same type as `Vector3<f32>`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::{Vec3, Vector3};
+//! let a : Vector3<f64> = Vector3 { x: 3.0, y: 4.0, z: 0.0 };
+//! let b : Vec3 = Vec3 { x: 3.0, y: 4.0, z: 0.0 };
+//! assert_eq!(a.mag(), 5.0f64);
+//! assert_eq!(b.mag(), 5.0f32);
+//! ```
+
+use std::fmt;
+use crate::simd::LaneScalar;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A floating point type that can be used as the components of vectors and matricies.
+///
+/// The trait is sealed, since arrays of vectors need the SIMD lanes of their scalars, and it is
+/// only implemented for [f32] and [f64].
+pub trait Scalar:
+        LaneScalar + Copy + fmt::Debug + fmt::Display + PartialEq + PartialOrd
+        + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+        + AddAssign + SubAssign + MulAssign + DivAssign
+{
+        const ZERO : Self;
+        const ONE : Self;
+        const NEG_ONE : Self;
+        const EPSILON : Self;
+
+        fn sqrt(self) -> Self;
+
+        fn abs(self) -> Self;
+
+        fn ln(self) -> Self;
+
+        fn tan(self) -> Self;
+
+        fn sin(self) -> Self;
+
+        fn cos(self) -> Self;
+
+        fn asin(self) -> Self;
+
+        fn acos(self) -> Self;
+
+        fn atan2(self, other: Self) -> Self;
+
+        /// The number of representable values from `self` to `other`, counting `0.0` and `-0.0`
+        /// as the same value and NaN as the furthest from everything.
+        fn ulps(self, other: Self) -> u64;
+}
+
+macro_rules! scalar_impl {
+        ($t:ident, $bits:ident) => {
+                impl Scalar for $t {
+                        const ZERO : $t = 0.0;
+                        const ONE : $t = 1.0;
+                        const NEG_ONE : $t = -1.0;
+                        const EPSILON : $t = $t::EPSILON;
+
+                        #[inline]
+                        fn sqrt(self) -> $t {
+                                self.sqrt()
+                        }
+
+                        #[inline]
+                        fn abs(self) -> $t {
+                                self.abs()
+                        }
+
+                        #[inline]
+                        fn ln(self) -> $t {
+                                self.ln()
+                        }
+
+                        #[inline]
+                        fn tan(self) -> $t {
+                                self.tan()
+                        }
+
+                        #[inline]
+                        fn sin(self) -> $t {
+                                self.sin()
+                        }
+
+                        #[inline]
+                        fn cos(self) -> $t {
+                                self.cos()
+                        }
+
+                        #[inline]
+                        fn asin(self) -> $t {
+                                self.asin()
+                        }
+
+                        #[inline]
+                        fn acos(self) -> $t {
+                                self.acos()
+                        }
+
+                        #[inline]
+                        fn atan2(self, other: $t) -> $t {
+                                self.atan2(other)
+                        }
+
+                        #[inline]
+                        fn ulps(self, other: $t) -> u64 {
+                                if self.is_nan() || other.is_nan() { return u64::MAX; }
+                                // sign and magnitude bits to a signed integer in the same order as the floats
+                                let ordered = |a : $t| { let b : $bits = a.to_bits() as $bits; if b < 0 { $bits::MIN - b } else { b } };
+                                ordered(self).abs_diff(ordered(other)) as u64
+                        }
+                }
+        };
+}
+
+scalar_impl! {f32, i32}
+scalar_impl! {f64, i64}
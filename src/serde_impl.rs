@@ -0,0 +1,161 @@
+//! Serialize and Deserialize implementations for the types that serde cannot derive them for.
+//!
+//! Vectors and quaternions are derived, and serialize as structs with their named components.
+//! [Vec2box](crate::vec2arr::Vec2box), [Vec3box](crate::vec3arr::Vec3box),
+//! [Vec2vec](crate::vec2arr::Vec2vec), [Vec3vec](crate::vec3arr::Vec3vec) and
+//! [VecN](crate::matn::VecN) are derived as newtypes, and along with
+//! [Vec2arr](crate::vec2arr::Vec2arr) and [Vec3arr](crate::vec3arr::Vec3arr) serialize as
+//! sequences. Matricies serialize row-major as nested arrays, so that `[[x1, x2], [y1, y2]]` is a
+//! [Mat22](crate::mat::Mat22), and a [MatN](crate::matn::MatN) is a sequence of its rows.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! # extern crate serde_json;
+//! # extern crate bincode;
+//! use lineq::vec2::Vec2;
+//! use lineq::vec3::Vec3;
+//! use lineq::mat::Mat33;
+//! use lineq::vec3arr::{Vec3arr, Vec3box};
+//! let m : Mat33 = Mat33 { x1: 1.0, x2: 2.0, x3: 3.0,
+//!                         y1: 4.0, y2: 5.0, y3: 6.0,
+//!                         z1: 7.0, z2: 8.0, z3: 9.0 };
+//! assert_eq!(serde_json::to_string(&m).unwrap(), "[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+//! assert_eq!(serde_json::to_string(&Vec2 { x: 1.0, y: 2.0 }).unwrap(), r#"{"x":1.0,"y":2.0}"#);
+//! let a : Vec3arr<2> = Vec3arr([Vec3::UP, Vec3::LEFT]);
+//! let json : String = serde_json::to_string(&a).unwrap();
+//! assert_eq!(json, r#"[{"x":0.0,"y":1.0,"z":0.0},{"x":-1.0,"y":0.0,"z":0.0}]"#);
+//! // the same json is also a Vec3box, but not a Vec3arr of another length
+//! assert_eq!(serde_json::from_str::<Vec3box>(&json).unwrap(), Vec3box::new_from_arr(a));
+//! assert!(serde_json::from_str::<Vec3arr<3>>(&json).is_err());
+//! let config = bincode::config::standard();
+//! let bytes : Vec<u8> = bincode::serde::encode_to_vec(m, config).unwrap();
+//! assert_eq!(bincode::serde::decode_from_slice::<Mat33, _>(&bytes, config).unwrap().0, m);
+//! ```
+//!
+//! Every type comes back unchanged from both formats:
+//!
+//! ```rust
+//! # extern crate lineq;
+//! # extern crate serde;
+//! # extern crate serde_json;
+//! # extern crate bincode;
+//! use lineq::vec2::{Vector2, Vec2};
+//! use lineq::vec3::Vector3;
+//! use lineq::vec4::Vector4;
+//! use lineq::quat::Quaternion;
+//! use lineq::mat::{Matrix22, Matrix33, Matrix44};
+//! use lineq::matn::{MatrixN, VectorN};
+//! use lineq::vec2arr::{Vec2arr, Vector2box};
+//! use lineq::vec3arr::{Vector3arr, Vector3box, Vector3vec};
+//! fn round_trip<A: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug>(a : A) {
+//!         let json : String = serde_json::to_string(&a).unwrap();
+//!         assert_eq!(serde_json::from_str::<A>(&json).unwrap(), a);
+//!         let config = bincode::config::standard();
+//!         let bytes : Vec<u8> = bincode::serde::encode_to_vec(&a, config).unwrap();
+//!         assert_eq!(bincode::serde::decode_from_slice::<A, _>(&bytes, config).unwrap().0, a);
+//! }
+//! let v : Vector3<f64> = Vector3 { x: 0.1, y: -2.5, z: 1e300 };
+//! round_trip(Vec2 { x: 0.1f32, y: -2.5 });
+//! round_trip(v);
+//! round_trip(Vector4 { x: 0.1, y: 0.2, z: 0.3, w: 1.0 });
+//! round_trip(Quaternion { w: 1.0, x: 0.0, y: -0.5, z: 0.25 });
+//! round_trip(Matrix22 { x1: 1.0, x2: 2.0, y1: 3.0, y2: 4.0 });
+//! round_trip(Matrix33::augment(v, Vector3::UP, Vector3::LEFT));
+//! round_trip(Matrix44::<f64>::translation(v));
+//! round_trip(MatrixN::<f64>::from_rows(2, 3, Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])));
+//! round_trip(MatrixN::<f64>::from_rows(2, 0, Box::new([])));
+//! round_trip(VectorN::<f64>(Box::new([1.0, 2.0, 3.0])));
+//! round_trip(Vec2arr([Vec2 { x: 1.0f32, y: 2.0 }, Vec2 { x: 3.0, y: 4.0 }]));
+//! round_trip(Vector3arr([v; 5]));
+//! round_trip(Vector2box::<f64>::ftoarr(|i| Vector2 { x: i as f64, y: 0.5 }, 9));
+//! round_trip(Vector3box::<f64>::ftoarr(|i| v * i as f64, 9));
+//! round_trip(Vector3vec::<f64>::from(vec![v, -v]));
+//! ```
+
+use crate::scalar::Scalar;
+use crate::mat::{Matrix22, Matrix33, Matrix44};
+use crate::matn::MatrixN;
+use crate::vec2::Vector2;
+use crate::vec2arr::Vector2arr;
+use crate::vec3::Vector3;
+use crate::vec3arr::Vector3arr;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error;
+use std::convert::TryFrom;
+
+macro_rules! arr_serde_impl {
+        (Serde $t:ident, $v:ident) => {
+                impl<const N: usize, T: Scalar + Serialize> Serialize for $t<N, T> {
+                        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                                serializer.collect_seq(self.iter())
+                        }
+                }
+
+                impl<'de, const N: usize, T: Scalar + Deserialize<'de>> Deserialize<'de> for $t<N, T> {
+                        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                                let v : Vec<$v<T>> = Vec::deserialize(deserializer)?;
+                                let len : usize = v.len();
+                                match <[$v<T>; N]>::try_from(v) {
+                                        Ok(arr) => Ok($t(arr)),
+                                        Err(_) => Err(D::Error::invalid_length(len, &format!("an array of {} vectors", N).as_str())),
+                                }
+                        }
+                }
+        };
+}
+
+arr_serde_impl! {Serde Vector2arr, Vector2}
+arr_serde_impl! {Serde Vector3arr, Vector3}
+
+macro_rules! mat_serde_impl {
+        (Serde $t:ident; $n:literal; $([$($e:ident),+]),+) => {
+                impl<T: Scalar + Serialize> Serialize for $t<T> {
+                        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                                [$([$(self.$e),+]),+].serialize(serializer)
+                        }
+                }
+
+                impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for $t<T> {
+                        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                                let [$([$($e),+]),+] = <[[T; $n]; $n]>::deserialize(deserializer)?;
+                                Ok($t { $($($e),+),+ })
+                        }
+                }
+        };
+}
+
+mat_serde_impl! {Serde Matrix22; 2; [x1, x2], [y1, y2]}
+mat_serde_impl! {Serde Matrix33; 3; [x1, x2, x3], [y1, y2, y3], [z1, z2, z3]}
+mat_serde_impl! {Serde Matrix44; 4; [x1, x2, x3, x4], [y1, y2, y3, y4], [z1, z2, z3, z4], [w1, w2, w3, w4]}
+
+impl<T: Scalar + Serialize> Serialize for MatrixN<T> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let (cols, a) = (self.cols(), self.as_slice());
+		serializer.collect_seq((0..self.rows()).map(|i| &a[i*cols..(i + 1)*cols]))
+	}
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for MatrixN<T> {
+	/// Deserializes a sequence of rows, failing if they are not all the same length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// # extern crate serde_json;
+	/// use lineq::matn::MatrixN;
+	/// let a : MatrixN<f64> = MatrixN::from_rows(2, 3, Box::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+	/// let json : String = serde_json::to_string(&a).unwrap();
+	/// assert_eq!(json, "[[1.0,2.0,3.0],[4.0,5.0,6.0]]");
+	/// assert_eq!(serde_json::from_str::<MatrixN<f64>>(&json).unwrap(), a);
+	/// assert!(serde_json::from_str::<MatrixN<f64>>("[[1.0,2.0],[3.0]]").is_err());
+	/// ```
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let rows : Vec<Vec<T>> = Vec::deserialize(deserializer)?;
+		let cols : usize = rows.first().map_or(0, |r| r.len());
+		if rows.iter().any(|r| r.len() != cols) { return Err(D::Error::custom("rows of unequal length")); }
+		Ok(MatrixN::from_rows(rows.len(), cols, rows.concat().into_boxed_slice()))
+	}
+}
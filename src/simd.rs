@@ -0,0 +1,489 @@
+//! Module containing the SIMD kernels that arrays of vectors are computed with.
+//!
+//! The elementwise operators on [Vec2box](crate::vec2arr::Vec2box),
+//! [Vec3box](crate::vec3arr::Vec3box), their windows and raw arrays, along with their dot
+//! products, the `mag`, `mag2`, `norm`, `crossarr` and `detarr` methods and their products with
+//! [Mat22](crate::mat::Mat22) and [Mat33](crate::mat::Mat33), all run through the functions
+//! here. They work on [Lanes](LaneScalar::Lanes) of scalars at a time, and every lane is
+//! computed with the same operations in the same order as the scalar path, never with fused
+//! multiply-adds, which is what the crate docs promise.
+//!
+//! The module is private to the crate, since the lanes change with the `simd` feature.
+//!
+//! The kernels that write to an `out` slice take it uninitialized, and write every element of it.
+
+use std::borrow::Borrow;
+use std::mem::MaybeUninit;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+#[cfg(feature = "simd")]
+use std::simd::{Simd, StdFloat, simd_swizzle};
+use std::slice;
+use crate::scalar::Scalar;
+use crate::vec2::Vector2;
+use crate::vec3::Vector3;
+
+/// The SIMD lanes of a scalar, which are `std::simd` vectors of 8 [f32] or 4 [f64] with the
+/// `simd` feature and the scalar itself without it.
+///
+/// This is a supertrait of [Scalar] so that every scalar has lanes, but it is only nameable
+/// inside the crate, which seals [Scalar] and keeps the lanes out of its public interface.
+pub trait LaneScalar : Copy {
+	type Lanes : Copy
+		+ Add<Output = Self::Lanes> + Sub<Output = Self::Lanes> + Mul<Output = Self::Lanes> + Div<Output = Self::Lanes>
+		+ AddAssign + SubAssign + MulAssign + DivAssign;
+
+	/// The number of scalars in [Lanes](LaneScalar::Lanes), which is at most 8.
+	const LANES : usize;
+
+	fn splat(self) -> Self::Lanes;
+
+	/// Loads the first [LANES](LaneScalar::LANES) scalars of a slice.
+	fn load(s : &[Self]) -> Self::Lanes;
+
+	/// Stores to the first [LANES](LaneScalar::LANES) scalars of a slice.
+	fn store(v : Self::Lanes, s : &mut [Self]);
+
+	fn sqrt_lanes(v : Self::Lanes) -> Self::Lanes;
+
+	/// Loads [LANES](LaneScalar::LANES) vectors of dimension `dim`, 2 or 3, from the start of a
+	/// slice, with each of their components in its own lanes.
+	fn load_vectors(s : &[Self], dim : usize) -> [Self::Lanes; 3];
+
+	/// Stores lanes of components as vectors of dimension `dim`, 2 or 3, to the start of a
+	/// slice, the inverse of [load_vectors](LaneScalar::load_vectors).
+	fn store_vectors(v : [Self::Lanes; 3], s : &mut [Self], dim : usize);
+}
+
+// Three vectors a, b and c of n lanes hold n vectors of dimension 3 one after another, and
+// component k of them is gathered in two swizzles, first of a and b then of that and c.
+#[cfg(feature = "simd")]
+const fn gather_ab<const N: usize>(k : usize) -> [usize; N] {
+	let mut idx : [usize; N] = [0; N];
+	let mut i : usize = 0;
+	while i < N {
+		idx[i] = if 3*i + k < 2*N { 3*i + k } else { 0 };
+		i += 1;
+	}
+	idx
+}
+
+#[cfg(feature = "simd")]
+const fn gather_c<const N: usize>(k : usize) -> [usize; N] {
+	let mut idx : [usize; N] = [0; N];
+	let mut i : usize = 0;
+	while i < N {
+		idx[i] = if 3*i + k < 2*N { i } else { 3*i + k - N };
+		i += 1;
+	}
+	idx
+}
+
+// The inverse, where lane j of output m comes from the lanes of components x, y and z,
+// first of x and y then of that and z.
+#[cfg(feature = "simd")]
+const fn scatter_xy<const N: usize>(m : usize) -> [usize; N] {
+	let mut idx : [usize; N] = [0; N];
+	let mut j : usize = 0;
+	while j < N {
+		let p : usize = m*N + j;
+		idx[j] = if p % 3 < 2 { (p % 3)*N + p/3 } else { 0 };
+		j += 1;
+	}
+	idx
+}
+
+#[cfg(feature = "simd")]
+const fn scatter_z<const N: usize>(m : usize) -> [usize; N] {
+	let mut idx : [usize; N] = [0; N];
+	let mut j : usize = 0;
+	while j < N {
+		let p : usize = m*N + j;
+		idx[j] = if p % 3 < 2 { j } else { N + p/3 };
+		j += 1;
+	}
+	idx
+}
+
+macro_rules! lane_scalar_impl {
+	($t:ident; $lanes:expr) => {
+		impl LaneScalar for $t {
+		#[cfg(feature = "simd")]
+		type Lanes = Simd<$t, $lanes>;
+		#[cfg(feature = "simd")]
+		const LANES : usize = $lanes;
+
+		#[cfg(not(feature = "simd"))]
+		type Lanes = $t;
+		#[cfg(not(feature = "simd"))]
+		const LANES : usize = 1;
+
+		#[cfg(feature = "simd")]
+		#[inline]
+		fn splat(self) -> Self::Lanes {
+			Simd::splat(self)
+		}
+
+		#[cfg(feature = "simd")]
+		#[inline]
+		fn load(s : &[$t]) -> Self::Lanes {
+			Simd::from_slice(s)
+		}
+
+		#[cfg(feature = "simd")]
+		#[inline]
+		fn store(v : Self::Lanes, s : &mut [$t]) {
+			v.copy_to_slice(s)
+		}
+
+		#[cfg(feature = "simd")]
+		#[inline]
+		fn sqrt_lanes(v : Self::Lanes) -> Self::Lanes {
+			v.sqrt()
+		}
+
+		#[cfg(feature = "simd")]
+		#[inline]
+		fn load_vectors(s : &[$t], dim : usize) -> [Self::Lanes; 3] {
+			let a : Self::Lanes = Simd::from_slice(s);
+			let b : Self::Lanes = Simd::from_slice(&s[$lanes..]);
+			if dim == 2 {
+				let (x, y) = a.deinterleave(b);
+				return [x, y, Simd::splat(1.0)];
+			}
+			let c : Self::Lanes = Simd::from_slice(&s[2*$lanes..]);
+			[simd_swizzle!(simd_swizzle!(a, b, gather_ab::<$lanes>(0)), c, gather_c::<$lanes>(0)),
+			 simd_swizzle!(simd_swizzle!(a, b, gather_ab::<$lanes>(1)), c, gather_c::<$lanes>(1)),
+			 simd_swizzle!(simd_swizzle!(a, b, gather_ab::<$lanes>(2)), c, gather_c::<$lanes>(2))]
+		}
+
+		#[cfg(feature = "simd")]
+		#[inline]
+		fn store_vectors(v : [Self::Lanes; 3], s : &mut [$t], dim : usize) {
+			let [x, y, z] = v;
+			if dim == 2 {
+				let (a, b) = x.interleave(y);
+				a.copy_to_slice(s);
+				b.copy_to_slice(&mut s[$lanes..]);
+				return;
+			}
+			simd_swizzle!(simd_swizzle!(x, y, scatter_xy::<$lanes>(0)), z, scatter_z::<$lanes>(0)).copy_to_slice(s);
+			simd_swizzle!(simd_swizzle!(x, y, scatter_xy::<$lanes>(1)), z, scatter_z::<$lanes>(1)).copy_to_slice(&mut s[$lanes..]);
+			simd_swizzle!(simd_swizzle!(x, y, scatter_xy::<$lanes>(2)), z, scatter_z::<$lanes>(2)).copy_to_slice(&mut s[2*$lanes..]);
+		}
+
+		#[cfg(not(feature = "simd"))]
+		#[inline]
+		fn splat(self) -> $t {
+			self
+		}
+
+		#[cfg(not(feature = "simd"))]
+		#[inline]
+		fn load(s : &[$t]) -> $t {
+			s[0]
+		}
+
+		#[cfg(not(feature = "simd"))]
+		#[inline]
+		fn store(v : $t, s : &mut [$t]) {
+			s[0] = v
+		}
+
+		#[cfg(not(feature = "simd"))]
+		#[inline]
+		fn sqrt_lanes(v : $t) -> $t {
+			v.sqrt()
+		}
+
+		#[cfg(not(feature = "simd"))]
+		#[inline]
+		fn load_vectors(s : &[$t], dim : usize) -> [$t; 3] {
+			[s[0], s[1], if dim == 2 { 1.0 } else { s[2] }]
+		}
+
+		#[cfg(not(feature = "simd"))]
+		#[inline]
+		fn store_vectors(v : [$t; 3], s : &mut [$t], dim : usize) {
+			s[..dim].copy_from_slice(&v[..dim]);
+		}
+		}
+	};
+}
+
+lane_scalar_impl! {f32; 8}
+lane_scalar_impl! {f64; 4}
+
+/// The lanes of the scalar type of a vector.
+pub type Lanes<V> = <<V as Components>::Scalar as LaneScalar>::Lanes;
+
+// The most scalars that Lanes can hold, which bounds the buffers used for the ends of arrays.
+const MAX_LANES : usize = 8;
+
+/// A vector made of [DIM](Components::DIM) scalars one after another, so that a slice of them
+/// can be viewed as a slice of scalars.
+///
+/// # Safety
+///
+/// The type has to be `#[repr(C)]` with exactly `DIM` fields, all of type `Scalar`.
+pub unsafe trait Components : Copy {
+	type Scalar : Scalar;
+	const DIM : usize;
+}
+
+unsafe impl<T: Scalar> Components for Vector2<T> {
+	type Scalar = T;
+	const DIM : usize = 2;
+}
+
+unsafe impl<T: Scalar> Components for Vector3<T> {
+	type Scalar = T;
+	const DIM : usize = 3;
+}
+
+/// Views vectors as their components in order, without copying.
+pub fn flat<V: Components>(a : &[V]) -> &[V::Scalar] {
+	unsafe { slice::from_raw_parts(a.as_ptr() as *const V::Scalar, a.len()*V::DIM) }
+}
+
+/// Views vectors as their components in order, without copying.
+pub fn flat_mut<V: Components>(a : &mut [V]) -> &mut [V::Scalar] {
+	unsafe { slice::from_raw_parts_mut(a.as_mut_ptr() as *mut V::Scalar, a.len()*V::DIM) }
+}
+
+/// Views components as vectors without copying, the inverse of [flat], panicking if the length
+/// is not a multiple of [DIM](Components::DIM).
+pub fn unflat<V: Components>(s : &[V::Scalar]) -> &[V] {
+	if !s.len().is_multiple_of(V::DIM) { panic!("slice length is not a multiple of {}", V::DIM); }
+	unsafe { slice::from_raw_parts(s.as_ptr() as *const V, s.len()/V::DIM) }
+}
+
+/// Views components as vectors without copying, the inverse of [flat_mut], panicking if the
+/// length is not a multiple of [DIM](Components::DIM).
+pub fn unflat_mut<V: Components>(s : &mut [V::Scalar]) -> &mut [V] {
+	if !s.len().is_multiple_of(V::DIM) { panic!("slice length is not a multiple of {}", V::DIM); }
+	unsafe { slice::from_raw_parts_mut(s.as_mut_ptr() as *mut V, s.len()/V::DIM) }
+}
+
+fn flat_uninit<V: Components>(a : &mut [MaybeUninit<V>]) -> &mut [MaybeUninit<V::Scalar>] {
+	unsafe { slice::from_raw_parts_mut(a.as_mut_ptr() as *mut MaybeUninit<V::Scalar>, a.len()*V::DIM) }
+}
+
+// Writes lanes to the start of out, dropping those past its end, through a buffer since out may be uninitialized.
+#[inline]
+fn store_uninit<T: Scalar>(v : T::Lanes, out : &mut [MaybeUninit<T>]) {
+	let mut buf : [T; MAX_LANES] = [T::ONE; MAX_LANES];
+	T::store(v, &mut buf[..T::LANES]);
+	for (o, b) in out.iter_mut().zip(buf[..T::LANES].iter()) {
+		o.write(*b);
+	}
+}
+
+// Writes f of the lanes of `ins` to every element of `out`, padding the last lanes with ones.
+fn lanewise<T: Scalar, const K: usize, F: Fn([T::Lanes; K]) -> T::Lanes>(out : &mut [MaybeUninit<T>], ins : [&[T]; K], f : F) {
+	let (n, len) = (T::LANES, out.len());
+	let body : usize = len - len % n;
+	for i in (0..body).step_by(n) {
+		store_uninit(f(ins.map(|s| T::load(&s[i..i + n]))), &mut out[i..i + n]);
+	}
+	if body < len {
+		let mut buf : [[T; MAX_LANES]; K] = [[T::ONE; MAX_LANES]; K];
+		for (b, s) in buf.iter_mut().zip(ins.iter()) {
+			b[..len - body].copy_from_slice(&s[body..len]);
+		}
+		store_uninit(f(buf.map(|b| T::load(&b[..n]))), &mut out[body..]);
+	}
+}
+
+// Replaces `out` with f of its lanes and the lanes of `ins`, padding the last lanes with ones.
+fn lanewise_assign<T: Scalar, const K: usize, F: Fn(T::Lanes, [T::Lanes; K]) -> T::Lanes>(out : &mut [T], ins : [&[T]; K], f : F) {
+	let (n, len) = (T::LANES, out.len());
+	let body : usize = len - len % n;
+	for i in (0..body).step_by(n) {
+		let v : T::Lanes = f(T::load(&out[i..i + n]), ins.map(|s| T::load(&s[i..i + n])));
+		T::store(v, &mut out[i..i + n]);
+	}
+	if body < len {
+		let mut buf : [[T; MAX_LANES]; K] = [[T::ONE; MAX_LANES]; K];
+		for (b, s) in buf.iter_mut().zip(ins.iter()) {
+			b[..len - body].copy_from_slice(&s[body..len]);
+		}
+		let mut tail : [T; MAX_LANES] = [T::ONE; MAX_LANES];
+		tail[..len - body].copy_from_slice(&out[body..]);
+		let v : T::Lanes = f(T::load(&tail[..n]), buf.map(|b| T::load(&b[..n])));
+		T::store(v, &mut tail[..n]);
+		out[body..].copy_from_slice(&tail[..len - body]);
+	}
+}
+
+// Loads each component of the vectors of dimension `dim` starting at vector i into its own
+// lanes, padding past the end with ones.
+#[inline]
+fn gather<T: Scalar>(a : &[T], dim : usize, i : usize) -> [T::Lanes; 3] {
+	let n : usize = T::LANES;
+	if (i + n)*dim <= a.len() {
+		return T::load_vectors(&a[i*dim..(i + n)*dim], dim);
+	}
+	let mut buf : [T; 3*MAX_LANES] = [T::ONE; 3*MAX_LANES];
+	buf[..a.len() - i*dim].copy_from_slice(&a[i*dim..]);
+	T::load_vectors(&buf[..n*dim], dim)
+}
+
+// Stores each lanes to its component of the vectors starting at vector i, dropping those past the end.
+#[inline]
+fn scatter<T: Scalar>(v : [T::Lanes; 3], out : &mut [MaybeUninit<T>], dim : usize, i : usize) {
+	let n : usize = T::LANES;
+	let mut buf : [T; 3*MAX_LANES] = [T::ONE; 3*MAX_LANES];
+	T::store_vectors(v, &mut buf[..n*dim], dim);
+	for (o, b) in out[i*dim..].iter_mut().zip(buf[..n*dim].iter()) {
+		o.write(*b);
+	}
+}
+
+// Views scalars as uninitialized, so that scatter can write back to the slice they were gathered
+// from, which it only ever does with initialized values.
+fn as_uninit<T>(a : &mut [T]) -> &mut [MaybeUninit<T>] {
+	unsafe { slice::from_raw_parts_mut(a.as_mut_ptr() as *mut MaybeUninit<T>, a.len()) }
+}
+
+// The product of the lanes of a vector with the rows of a matrix, summed in the same order as the scalar path.
+#[inline]
+fn mat_lanes<T: Scalar>(m : &[[T::Lanes; 3]; 3], v : &[T::Lanes; 3], dim : usize) -> [T::Lanes; 3] {
+	m.map(|r| {
+		let mut sum : T::Lanes = r[0]*v[0];
+		for k in 1..dim {
+			sum += r[k]*v[k];
+		}
+		sum
+	})
+}
+
+// The squared magnitudes of the lanes of a vector, summed in the same order as the scalar path.
+#[inline]
+fn mag2_lanes<T: Scalar>(v : &[T::Lanes]) -> T::Lanes {
+	let mut sum : T::Lanes = v[0]*v[0];
+	for c in v[1..].iter() {
+		sum += *c * *c;
+	}
+	sum
+}
+
+/// Sets each element of `out` to f of the elements of `a` and `b`, panicking if the lengths differ.
+pub fn zip<V: Components, F: Fn(Lanes<V>, Lanes<V>) -> Lanes<V>>(out : &mut [MaybeUninit<V>], a : &[V], b : &[V], f : F) {
+	if out.len() != a.len() || a.len() != b.len() { panic!("slices inequal length"); }
+	lanewise(flat_uninit(out), [flat(a), flat(b)], |[a, b]| f(a, b));
+}
+
+/// Sets each element of `out` to f of the elements of `a` and the scalar `s`, splatted to lanes,
+/// panicking if the lengths differ.
+pub fn zip_scalar<V: Components, S: Borrow<V::Scalar>, F: Fn(Lanes<V>, Lanes<V>) -> Lanes<V>>(out : &mut [MaybeUninit<V>], a : &[V], s : S, f : F) {
+	if out.len() != a.len() { panic!("slices inequal length"); }
+	let s : Lanes<V> = s.borrow().splat();
+	lanewise(flat_uninit(out), [flat(a)], |[a]| f(a, s));
+}
+
+/// Sets each element of `out` to f of the scalar `s`, splatted to lanes, and the elements of `a`,
+/// panicking if the lengths differ.
+pub fn scalar_zip<V: Components, S: Borrow<V::Scalar>, F: Fn(Lanes<V>, Lanes<V>) -> Lanes<V>>(out : &mut [MaybeUninit<V>], s : S, a : &[V], f : F) {
+	if out.len() != a.len() { panic!("slices inequal length"); }
+	let s : Lanes<V> = s.borrow().splat();
+	lanewise(flat_uninit(out), [flat(a)], |[a]| f(s, a));
+}
+
+/// Replaces each element of `a` with f of it and the element of `b`, panicking if the lengths differ.
+pub fn zip_assign<V: Components, F: Fn(Lanes<V>, Lanes<V>) -> Lanes<V>>(a : &mut [V], b : &[V], f : F) {
+	if a.len() != b.len() { panic!("slices inequal length"); }
+	lanewise_assign(flat_mut(a), [flat(b)], |a, [b]| f(a, b));
+}
+
+/// Replaces each element of `a` with f of it and the scalar `s`, splatted to lanes.
+pub fn zip_scalar_assign<V: Components, S: Borrow<V::Scalar>, F: Fn(Lanes<V>, Lanes<V>) -> Lanes<V>>(a : &mut [V], s : S, f : F) {
+	let s : Lanes<V> = s.borrow().splat();
+	lanewise_assign(flat_mut(a), [], |a, []| f(a, s));
+}
+
+/// Writes the dot product of each pair of vectors to `out`, panicking if the lengths differ.
+pub fn dot<V: Components>(out : &mut [MaybeUninit<V::Scalar>], a : &[V], b : &[V]) {
+	if out.len() != a.len() || a.len() != b.len() { panic!("slices inequal length"); }
+	let (n, a, b) = (V::Scalar::LANES, flat(a), flat(b));
+	for i in (0..out.len()).step_by(n) {
+		let (u, v) = (gather(a, V::DIM, i), gather(b, V::DIM, i));
+		let mut sum : Lanes<V> = u[0]*v[0];
+		for k in 1..V::DIM {
+			sum += u[k]*v[k];
+		}
+		store_uninit(sum, &mut out[i..]);
+	}
+}
+
+/// Writes the squared magnitude of each vector to `out`, panicking if the lengths differ.
+pub fn mag2<V: Components>(out : &mut [MaybeUninit<V::Scalar>], a : &[V]) {
+	if out.len() != a.len() { panic!("slices inequal length"); }
+	let (n, a) = (V::Scalar::LANES, flat(a));
+	for i in (0..out.len()).step_by(n) {
+		let v : [Lanes<V>; 3] = gather(a, V::DIM, i);
+		store_uninit(mag2_lanes::<V::Scalar>(&v[..V::DIM]), &mut out[i..]);
+	}
+}
+
+/// Writes the magnitude of each vector to `out`, panicking if the lengths differ.
+pub fn mag<V: Components>(out : &mut [MaybeUninit<V::Scalar>], a : &[V]) {
+	if out.len() != a.len() { panic!("slices inequal length"); }
+	let (n, a) = (V::Scalar::LANES, flat(a));
+	for i in (0..out.len()).step_by(n) {
+		let v : [Lanes<V>; 3] = gather(a, V::DIM, i);
+		store_uninit(V::Scalar::sqrt_lanes(mag2_lanes::<V::Scalar>(&v[..V::DIM])), &mut out[i..]);
+	}
+}
+
+/// Writes each vector divided by its magnitude to `out`, panicking if the lengths differ.
+pub fn norm<V: Components>(out : &mut [MaybeUninit<V>], a : &[V]) {
+	if out.len() != a.len() { panic!("slices inequal length"); }
+	let (out, a) = (flat_uninit(out), flat(a));
+	for i in (0..a.len()/V::DIM).step_by(V::Scalar::LANES) {
+		let v : [Lanes<V>; 3] = gather(a, V::DIM, i);
+		let mag : Lanes<V> = V::Scalar::sqrt_lanes(mag2_lanes::<V::Scalar>(&v[..V::DIM]));
+		scatter(v.map(|c| c/mag), out, V::DIM, i);
+	}
+}
+
+/// Writes the cross product of each pair of vectors to `out`, panicking if the lengths differ.
+pub fn cross<T: Scalar>(out : &mut [MaybeUninit<Vector3<T>>], a : &[Vector3<T>], b : &[Vector3<T>]) {
+	if out.len() != a.len() || a.len() != b.len() { panic!("slices inequal length"); }
+	let (out, a, b) = (flat_uninit(out), flat(a), flat(b));
+	for i in (0..a.len()/3).step_by(T::LANES) {
+		let ([ax, ay, az], [bx, by, bz]) = (gather(a, 3, i), gather(b, 3, i));
+		scatter([ay*bz - az*by, az*bx - ax*bz, ax*by - ay*bx], out, 3, i);
+	}
+}
+
+/// Writes the determinant of each pair of vectors to `out`, panicking if the lengths differ.
+pub fn det<T: Scalar>(out : &mut [MaybeUninit<T>], a : &[Vector2<T>], b : &[Vector2<T>]) {
+	if out.len() != a.len() || a.len() != b.len() { panic!("slices inequal length"); }
+	let (a, b) = (flat(a), flat(b));
+	for i in (0..out.len()).step_by(T::LANES) {
+		let ([ax, ay, _], [bx, by, _]) = (gather(a, 2, i), gather(b, 2, i));
+		store_uninit(ax*by - ay*bx, &mut out[i..]);
+	}
+}
+
+/// Writes the product of the matrix with rows `m` and each vector to `out`, panicking if the
+/// lengths differ. Only the first [DIM](Components::DIM) rows and columns of `m` are used.
+pub fn mat_mul<V: Components>(out : &mut [MaybeUninit<V>], m : [[V::Scalar; 3]; 3], a : &[V]) {
+	if out.len() != a.len() { panic!("slices inequal length"); }
+	let m : [[Lanes<V>; 3]; 3] = m.map(|r| r.map(|e| e.splat()));
+	let (out, a) = (flat_uninit(out), flat(a));
+	for i in (0..a.len()/V::DIM).step_by(V::Scalar::LANES) {
+		scatter(mat_lanes::<V::Scalar>(&m, &gather(a, V::DIM, i), V::DIM), out, V::DIM, i);
+	}
+}
+
+/// Replaces each vector with the product of the matrix with rows `m` and it, the same as
+/// [mat_mul] but in place.
+pub fn mat_mul_assign<V: Components>(a : &mut [V], m : [[V::Scalar; 3]; 3]) {
+	let m : [[Lanes<V>; 3]; 3] = m.map(|r| r.map(|e| e.splat()));
+	let a = flat_mut(a);
+	for i in (0..a.len()/V::DIM).step_by(V::Scalar::LANES) {
+		let v : [Lanes<V>; 3] = gather(a, V::DIM, i);
+		scatter(mat_lanes::<V::Scalar>(&m, &v, V::DIM), as_uninit(a), V::DIM, i);
+	}
+}
@@ -0,0 +1,217 @@
+//! Module containing the singular value decomposition.
+//!
+//! Decompositions are found by the one-sided Jacobi method, which orthogonalizes the columns of
+//! the matrix with plane rotations and is accurate even for tiny singular values. Besides the
+//! dense [SVD](crate::svd::SVD), [Mat22](crate::mat::Mat22) and [Mat33](crate::mat::Mat33) have
+//! `svd` and `svd_proper` methods, the second of which returns rotations for U and Vᵀ.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::matn::MatrixN;
+//! use lineq::svd::SVD;
+//! let a : MatrixN<f64> = MatrixN::from_rows(3, 2, Box::new([3.0, 0.0,
+//!                                                     0.0, 4.0,
+//!                                                     0.0, 0.0]));
+//! let svd : SVD<f64> = SVD::new_from_matn(&a);
+//! assert_eq!(svd.s().as_slice(), &[4.0, 3.0]);
+//! assert_eq!(svd.rank(), 2);
+//! let e : MatrixN<f64> = svd.pinv() - MatrixN::from_rows(2, 3, Box::new([1.0/3.0, 0.0, 0.0,
+//!                                                                   0.0, 0.25, 0.0]));
+//! assert!(e.as_slice().iter().all(|e| e.abs() < 1e-12));
+//! ```
+//!
+//! Singular values that are only rounding error count as zero, and U still has orthonormal
+//! columns for them:
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::matn::MatrixN;
+//! use lineq::svd::SVD;
+//! let (x, y) : ([f64; 3], [f64; 3]) = ([1.0, 2.0, 3.0], [0.1, 0.7, 0.3]);
+//! let a : MatrixN<f64> = MatrixN::ftomat(|i, j| x[i]*y[j], 3, 3);
+//! let svd : SVD<f64> = SVD::new_from_matn(&a);
+//! assert_eq!(svd.rank(), 1);
+//! let e : MatrixN<f64> = &svd.u().t() * &svd.u() - MatrixN::identity(3);
+//! assert!(e.as_slice().iter().all(|e| e.abs() < 1e-12));
+//! ```
+
+use crate::scalar::Scalar;
+use crate::matn::{MatrixN, VectorN};
+use crate::lu::LU;
+
+/// The decomposition `A = U Σ Vᵀ` of an m by n matrix, where with k the smaller of m and n, U is
+/// m by k and Vᵀ is k by n with orthonormal columns and rows, and Σ is diagonal with k
+/// non-negative entries in descending order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SVD<T = f32> {
+        u : MatrixN<T>,
+        s : VectorN<T>,
+        vt : MatrixN<T>,
+}
+
+// Orthogonalizes the columns of the m by n row-major matrix in `a` in place for m >= n, and
+// returns the rotations applied as the n by n row-major matrix V.
+fn jacobi<T: Scalar>(a : &mut [T], m : usize, n : usize) -> Box<[T]> {
+	let mut v : Box<[T]> = (0..n*n).map(|i| if i/n == i%n { T::ONE } else { T::ZERO }).collect();
+	for _ in 0..64 {
+		let mut rotated : bool = false;
+		for p in 0..n {
+			for q in p+1..n {
+				let (mut alpha, mut beta, mut gamma) = (T::ZERO, T::ZERO, T::ZERO);
+				for i in 0..m {
+					alpha += a[i*n + p]*a[i*n + p];
+					beta += a[i*n + q]*a[i*n + q];
+					gamma += a[i*n + p]*a[i*n + q];
+				}
+				if gamma.abs() <= T::EPSILON*(alpha*beta).sqrt() { continue; }
+				rotated = true;
+				let zeta : T = (beta - alpha)/(gamma + gamma);
+				let t : T = if zeta < T::ZERO { T::NEG_ONE } else { T::ONE }/(zeta.abs() + (zeta*zeta + T::ONE).sqrt());
+				let c : T = T::ONE/(t*t + T::ONE).sqrt();
+				let s : T = t*c;
+				for i in 0..m {
+					let (aip, aiq) = (a[i*n + p], a[i*n + q]);
+					a[i*n + p] = c*aip - s*aiq;
+					a[i*n + q] = s*aip + c*aiq;
+				}
+				for i in 0..n {
+					let (vip, viq) = (v[i*n + p], v[i*n + q]);
+					v[i*n + p] = c*vip - s*viq;
+					v[i*n + q] = s*vip + c*viq;
+				}
+			}
+		}
+		if !rotated { break; }
+	}
+	v
+}
+
+//SVD Methods
+
+impl<T: Scalar> SVD<T> {
+
+	/// Decomposes a [MatN](crate::matn::MatN) of any shape.
+	pub fn new_from_matn(a : &MatrixN<T>) -> SVD<T> {
+		if a.rows() < a.cols() {
+			let svd : SVD<T> = SVD::new_from_matn(&a.t());
+			return SVD { u: svd.vt.t(), s: svd.s, vt: svd.u.t() };
+		}
+		let (m, n) = (a.rows(), a.cols());
+		let mut w : Box<[T]> = a.as_slice().into();
+		let v : Box<[T]> = jacobi(&mut w, m, n);
+		let norms : Box<[T]> = (0..n).map(|j| (0..m).map(|i| w[i*n + j]*w[i*n + j]).fold(T::ZERO, |x, y| x + y).sqrt()).collect();
+		let mut order : Box<[usize]> = (0..n).collect();
+		order.sort_by(|i, j| norms[*j].partial_cmp(&norms[*i]).unwrap_or(std::cmp::Ordering::Equal));
+		let s : VectorN<T> = VectorN::ftoarr(|j| norms[order[j]], n);
+		let vt : MatrixN<T> = MatrixN::ftomat(|i, j| v[j*n + order[i]], n, n);
+		// the columns of w for negligible singular values are only rounding error, so instead
+		// of normalizing them they are completed from whichever unit vector is furthest from
+		// the span of the columns before them
+		let tol : T = tol(&s, m);
+		let mut u : MatrixN<T> = MatrixN::ftomat(|i, j| if s[j] <= tol { T::ZERO } else { w[i*n + order[j]]/s[j] }, m, n);
+		for j in 0..n {
+			if s[j] > tol { continue; }
+			let mut best : VectorN<T> = VectorN::new_zeroed(m);
+			for k in 0..m {
+				let mut e : VectorN<T> = VectorN::ftoarr(|i| if i == k { T::ONE } else { T::ZERO }, m);
+				for c in 0..j {
+					let col : VectorN<T> = u.col(c);
+					let d : T = e.dot(&col);
+					e -= col*d;
+				}
+				if e.mag2() > best.mag2() { best = e; }
+			}
+			let best : VectorN<T> = best.norm();
+			for i in 0..m {
+				u[(i, j)] = best[i];
+			}
+		}
+		SVD { u, s, vt }
+	}
+
+	/// Decomposes a square [MatN](crate::matn::MatN) so that U and Vᵀ are both rotations, with determinant 1,
+	/// by negating the last singular value if needed, and panics if it is not square.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::matn::MatrixN;
+	/// use lineq::svd::SVD;
+	/// use lineq::lu::LU;
+	/// let a : MatrixN<f64> = MatrixN::from_rows(2, 2, Box::new([0.0, 1.0, 1.0, 0.0]));
+	/// let svd : SVD<f64> = SVD::new_from_matn_proper(&a);
+	/// assert!((LU::new_from_matn(&svd.u()).det() - 1.0).abs() < 1e-12);
+	/// assert!((LU::new_from_matn(&svd.vt()).det() - 1.0).abs() < 1e-12);
+	/// assert_eq!(svd.s().as_slice(), &[1.0, -1.0]);
+	/// ```
+	pub fn new_from_matn_proper(a : &MatrixN<T>) -> SVD<T> {
+		if a.rows() != a.cols() { panic!("matrix is not square"); }
+		let mut svd : SVD<T> = SVD::new_from_matn(a);
+		let n : usize = a.rows();
+		if n == 0 { return svd; }
+		if LU::new_from_matn(&svd.u).det() < T::ZERO {
+			for i in 0..n {
+				svd.u[(i, n - 1)] = -svd.u[(i, n - 1)];
+			}
+			svd.s[n - 1] = -svd.s[n - 1];
+		}
+		if LU::new_from_matn(&svd.vt).det() < T::ZERO {
+			for j in 0..n {
+				svd.vt[(n - 1, j)] = -svd.vt[(n - 1, j)];
+			}
+			svd.s[n - 1] = -svd.s[n - 1];
+		}
+		svd
+	}
+
+	pub fn u(&self) -> MatrixN<T> {
+		self.u.clone()
+	}
+
+	/// The diagonal of Σ.
+	pub fn s(&self) -> VectorN<T> {
+		self.s.clone()
+	}
+
+	pub fn vt(&self) -> MatrixN<T> {
+		self.vt.clone()
+	}
+
+	/// The number of singular values that are not negligible compared to the largest.
+	pub fn rank(&self) -> usize {
+		let tol : T = self.tol();
+		self.s.iter().filter(|s| s.abs() > tol).count()
+	}
+
+	/// The Moore-Penrose pseudo-inverse `V Σ⁺ Uᵀ`, where Σ⁺ inverts the singular values
+	/// that are not negligible and zeros the rest.
+	pub fn pinv(&self) -> MatrixN<T> {
+		let tol : T = self.tol();
+		let (m, n, k) = (self.u.rows(), self.vt.cols(), self.s.len());
+		MatrixN::ftomat(|i, j| {
+			let mut sum : T = T::ZERO;
+			for l in 0..k {
+				if self.s[l].abs() > tol { sum += self.vt[(l, i)]*self.u[(j, l)]/self.s[l]; }
+			}
+			sum
+		}, n, m)
+	}
+
+	fn tol(&self) -> T {
+		tol(&self.s, self.u.rows().max(self.vt.cols()))
+	}
+}
+
+// Singular values at or below the largest times epsilon times the larger dimension of the
+// matrix, `dim`, are negligible.
+fn tol<T: Scalar>(s : &[T], dim : usize) -> T {
+	let mut tol : T = T::ZERO;
+	for _ in 0..dim {
+		tol += T::EPSILON;
+	}
+	if !s.is_empty() { tol *= s[0].abs(); }
+	tol
+}
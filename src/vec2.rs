@@ -1,42 +1,77 @@
+use crate::scalar::Scalar;
+use crate::error::LineqError;
+
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Vec2 {
-        pub x : f32,
-        pub y : f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Vector2<T> {
+        pub x : T,
+        pub y : T,
 }
 
+/// A [Vec2] of [f32]s.
+pub type Vec2 = Vector2<f32>;
+
 macro_rules! value_impl {
         ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty) => {
-                impl $imp<$rhs> for $lhs {
-                        type Output = Vec2;
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector2<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector2<T> {
+				Vector2 { x: self.x $op rhs.x, y: self.y $op rhs.y }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for &$lhs:ident) => {
+                impl $imp<$rhs> for &$lhs {
+                        type Output = Vector2<$lhs>;
                         #[inline]
-                        fn $func(self, rhs: $rhs) -> Vec2 {
-				Vec2 { x: self.x $op rhs.x, y: self.y $op rhs.y }
+                        fn $func(self, rhs: $rhs) -> Vector2<$lhs> {
+                                Vector2 { x: *self $op rhs.x, y: *self $op rhs.y }
                         }
                 }
         };
-        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty) => {
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ident) => {
                 impl $imp<$rhs> for $lhs {
-                        type Output = Vec2;
+                        type Output = Vector2<$lhs>;
                         #[inline]
-                        fn $func(self, rhs: $rhs) -> Vec2 {
-                                Vec2 { x: self $op rhs.x, y: self $op rhs.y }
+                        fn $func(self, rhs: $rhs) -> Vector2<$lhs> {
+                                Vector2 { x: self $op rhs.x, y: self $op rhs.y }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 &$rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<&$rhs> for $lhs {
+                        type Output = Vector2<T>;
+                        #[inline]
+                        fn $func(self, rhs: &$rhs) -> Vector2<T> {
+                                Vector2 { x: self.x $op *rhs, y: self.y $op *rhs }
                         }
                 }
         };
         ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty) => {
-                impl $imp<$rhs> for $lhs {
-                        type Output = Vec2;
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector2<T>;
                         #[inline]
-                        fn $func(self, rhs: $rhs) -> Vec2 {
-                                Vec2 { x: self.x $op rhs, y: self.y $op rhs }
+                        fn $func(self, rhs: $rhs) -> Vector2<T> {
+                                Vector2 { x: self.x $op rhs, y: self.y $op rhs }
                         }
                 }
         };
 }
 
 macro_rules! inplace_impl {
+        ($imp:ident;$func:ident;$op:tt; 0 &$rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<&$rhs> for $lhs {
+                        #[inline]
+                        fn $func(&mut self, rhs: &$rhs) {
+                                self.x $op *rhs;
+                                self.y $op *rhs;
+                        }
+                }
+        };
         ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty) => {
-                impl $imp<$rhs> for $lhs {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
                         #[inline]
                         fn $func(&mut self, rhs: $rhs) {
                                 self.x $op rhs;
@@ -45,7 +80,7 @@ macro_rules! inplace_impl {
                 }
         };
         ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty) => {
-                impl $imp<$rhs> for $lhs {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
                         #[inline]
                         fn $func(&mut self, rhs: $rhs) {
                                 self.x $op rhs.x;
@@ -57,10 +92,10 @@ macro_rules! inplace_impl {
 
 macro_rules! dot_impl {
         (Dot $rhs:ty; for $lhs:ty) => {
-                impl Mul<$rhs> for $lhs {
-                        type Output = f32;
+                impl<T: Scalar> Mul<$rhs> for $lhs {
+                        type Output = T;
                         #[inline]
-                        fn mul(self, rhs: $rhs) -> f32 {
+                        fn mul(self, rhs: $rhs) -> T {
 				self.x*rhs.x+self.y*rhs.y
                         }
                 }
@@ -70,31 +105,35 @@ macro_rules! dot_impl {
 //Add
 use std::ops::Add;
 
-value_impl! {Add;add;+; 1 f32; for Vec2}
-value_impl! {Add;add;+; 2 Vec2; for f32}
-value_impl! {Add;add;+; 3 Vec2; for Vec2}
-value_impl! {Add;add;+; 1 &f32; for Vec2}
-value_impl! {Add;add;+; 2 &Vec2; for f32}
-value_impl! {Add;add;+; 3 &Vec2; for Vec2}
-value_impl! {Add;add;+; 1 f32; for &Vec2}
-value_impl! {Add;add;+; 2 Vec2; for &f32}
-value_impl! {Add;add;+; 3 Vec2; for &Vec2}
-value_impl! {Add;add;+; 1 &f32; for &Vec2}
-value_impl! {Add;add;+; 2 &Vec2; for &f32}
-value_impl! {Add;add;+; 3 &Vec2; for &Vec2}
+value_impl! {Add;add;+; 1 T; for Vector2<T>}
+value_impl! {Add;add;+; 2 Vector2<f32>; for f32}
+value_impl! {Add;add;+; 2 Vector2<f64>; for f64}
+value_impl! {Add;add;+; 3 Vector2<T>; for Vector2<T>}
+value_impl! {Add;add;+; 1 &T; for Vector2<T>}
+value_impl! {Add;add;+; 2 &Vector2<f32>; for f32}
+value_impl! {Add;add;+; 2 &Vector2<f64>; for f64}
+value_impl! {Add;add;+; 3 &Vector2<T>; for Vector2<T>}
+value_impl! {Add;add;+; 1 T; for &Vector2<T>}
+value_impl! {Add;add;+; 2 Vector2<f32>; for &f32}
+value_impl! {Add;add;+; 2 Vector2<f64>; for &f64}
+value_impl! {Add;add;+; 3 Vector2<T>; for &Vector2<T>}
+value_impl! {Add;add;+; 1 &T; for &Vector2<T>}
+value_impl! {Add;add;+; 2 &Vector2<f32>; for &f32}
+value_impl! {Add;add;+; 2 &Vector2<f64>; for &f64}
+value_impl! {Add;add;+; 3 &Vector2<T>; for &Vector2<T>}
 
 //AddAssign
 use std::ops::AddAssign;
 
-inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec2}
-inplace_impl! {AddAssign;add_assign;+=; 1 Vec2; for Vec2}
-inplace_impl! {AddAssign;add_assign;+=; 0 &f32; for Vec2}
-inplace_impl! {AddAssign;add_assign;+=; 1 &Vec2; for Vec2}
+inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector2<T>}
+inplace_impl! {AddAssign;add_assign;+=; 1 Vector2<T>; for Vector2<T>}
+inplace_impl! {AddAssign;add_assign;+=; 0 &T; for Vector2<T>}
+inplace_impl! {AddAssign;add_assign;+=; 1 &Vector2<T>; for Vector2<T>}
 
 //Display
 use std::fmt;
 
-impl fmt::Display for Vec2 {
+impl<T: Scalar> fmt::Display for Vector2<T> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "<{}, {}>", self.x, self.y)
         }
@@ -103,100 +142,126 @@ impl fmt::Display for Vec2 {
 //Div
 use std::ops::Div;
 
-value_impl! {Div;div;/; 1 f32; for Vec2}
-value_impl! {Div;div;/; 1 &f32; for Vec2}
-value_impl! {Div;div;/; 1 f32; for &Vec2}
-value_impl! {Div;div;/; 1 &f32; for &Vec2}
+value_impl! {Div;div;/; 1 T; for Vector2<T>}
+value_impl! {Div;div;/; 1 &T; for Vector2<T>}
+value_impl! {Div;div;/; 1 T; for &Vector2<T>}
+value_impl! {Div;div;/; 1 &T; for &Vector2<T>}
 
 //DivAssign
 use std::ops::DivAssign;
 
-inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec2}
-inplace_impl! {DivAssign;div_assign;/=; 0 &f32; for Vec2}
+inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector2<T>}
+inplace_impl! {DivAssign;div_assign;/=; 0 &T; for Vector2<T>}
 
 //Mult
 use std::ops::Mul;
 
-value_impl! {Mul;mul;*; 1 f32; for Vec2}
-value_impl! {Mul;mul;*; 2 Vec2; for f32}
-dot_impl! {Dot Vec2; for Vec2}
-value_impl! {Mul;mul;*; 1 &f32; for Vec2}
-value_impl! {Mul;mul;*; 2 &Vec2; for f32}
-dot_impl! {Dot &Vec2; for Vec2}
-value_impl! {Mul;mul;*; 1 f32; for &Vec2}
-value_impl! {Mul;mul;*; 2 Vec2; for &f32}
-dot_impl! {Dot Vec2; for &Vec2}
-value_impl! {Mul;mul;*; 1 &f32; for &Vec2}
-value_impl! {Mul;mul;*; 2 &Vec2; for &f32}
-dot_impl! {Dot &Vec2; for &Vec2}
+value_impl! {Mul;mul;*; 1 T; for Vector2<T>}
+value_impl! {Mul;mul;*; 2 Vector2<f32>; for f32}
+value_impl! {Mul;mul;*; 2 Vector2<f64>; for f64}
+dot_impl! {Dot Vector2<T>; for Vector2<T>}
+value_impl! {Mul;mul;*; 1 &T; for Vector2<T>}
+value_impl! {Mul;mul;*; 2 &Vector2<f32>; for f32}
+value_impl! {Mul;mul;*; 2 &Vector2<f64>; for f64}
+dot_impl! {Dot &Vector2<T>; for Vector2<T>}
+value_impl! {Mul;mul;*; 1 T; for &Vector2<T>}
+value_impl! {Mul;mul;*; 2 Vector2<f32>; for &f32}
+value_impl! {Mul;mul;*; 2 Vector2<f64>; for &f64}
+dot_impl! {Dot Vector2<T>; for &Vector2<T>}
+value_impl! {Mul;mul;*; 1 &T; for &Vector2<T>}
+value_impl! {Mul;mul;*; 2 &Vector2<f32>; for &f32}
+value_impl! {Mul;mul;*; 2 &Vector2<f64>; for &f64}
+dot_impl! {Dot &Vector2<T>; for &Vector2<T>}
 
 //MultAssign
 use std::ops::MulAssign;
 
-inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec2}
-inplace_impl! {MulAssign;mul_assign;*=; 0 &f32; for Vec2}
+inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector2<T>}
+inplace_impl! {MulAssign;mul_assign;*=; 0 &T; for Vector2<T>}
 
 //Neg
 use std::ops::Neg;
 
-impl Neg for Vec2 {
-        type Output = Vec2;
-        fn neg(self) -> Vec2 {
-                Vec2 { x: -self.x, y: -self.y }
+impl<T: Scalar> Neg for Vector2<T> {
+        type Output = Vector2<T>;
+        fn neg(self) -> Vector2<T> {
+                Vector2 { x: -self.x, y: -self.y }
         }
 }
 
-impl Neg for &Vec2 {
-        type Output = Vec2;
-        fn neg(self) -> Vec2 {
-                Vec2 { x: -self.x, y: -self.y }
+impl<T: Scalar> Neg for &Vector2<T> {
+        type Output = Vector2<T>;
+        fn neg(self) -> Vector2<T> {
+                Vector2 { x: -self.x, y: -self.y }
         }
 }
 
 //Sub
 use std::ops::Sub;
 
-value_impl! {Sub;sub;-; 1 f32; for Vec2}
-value_impl! {Sub;sub;-; 3 Vec2; for Vec2}
-value_impl! {Sub;sub;-; 1 &f32; for Vec2}
-value_impl! {Sub;sub;-; 3 &Vec2; for Vec2}
-value_impl! {Sub;sub;-; 1 f32; for &Vec2}
-value_impl! {Sub;sub;-; 3 Vec2; for &Vec2}
-value_impl! {Sub;sub;-; 1 &f32; for &Vec2}
-value_impl! {Sub;sub;-; 3 &Vec2; for &Vec2}
+value_impl! {Sub;sub;-; 1 T; for Vector2<T>}
+value_impl! {Sub;sub;-; 3 Vector2<T>; for Vector2<T>}
+value_impl! {Sub;sub;-; 1 &T; for Vector2<T>}
+value_impl! {Sub;sub;-; 3 &Vector2<T>; for Vector2<T>}
+value_impl! {Sub;sub;-; 1 T; for &Vector2<T>}
+value_impl! {Sub;sub;-; 3 Vector2<T>; for &Vector2<T>}
+value_impl! {Sub;sub;-; 1 &T; for &Vector2<T>}
+value_impl! {Sub;sub;-; 3 &Vector2<T>; for &Vector2<T>}
 
 //SubAssign
 use std::ops::SubAssign;
 
-inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec2}
-inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2; for Vec2}
-inplace_impl! {SubAssign;sub_assign;-=; 0 &f32; for Vec2}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &Vec2; for Vec2}
+inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector2<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2<T>; for Vector2<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 0 &T; for Vector2<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &Vector2<T>; for Vector2<T>}
+
+//Sum
+use std::iter::Sum;
+
+impl<T: Scalar> Sum for Vector2<T> {
+        fn sum<I: Iterator<Item = Vector2<T>>>(iter: I) -> Vector2<T> {
+                iter.fold(Vector2::ZERO, |a, b| a + b)
+        }
+}
+
+impl<'a, T: Scalar> Sum<&'a Vector2<T>> for Vector2<T> {
+        fn sum<I: Iterator<Item = &'a Vector2<T>>>(iter: I) -> Vector2<T> {
+                iter.fold(Vector2::ZERO, |a, b| a + *b)
+        }
+}
 
 //Vec2 Methods
 
-impl Vec2 {
-	pub const ZERO : Vec2 = Vec2 { x: 0.0, y: 0.0 };
-	pub const ONE : Vec2 = Vec2 { x: 1.0, y: 1.0 };
-	pub const LEFT : Vec2 = Vec2 { x: -1.0, y: 0.0 };
-	pub const RIGHT : Vec2 = Vec2 { x: 1.0, y: 0.0 };
-	pub const UP : Vec2 = Vec2 { x: 0.0, y: 1.0 };
-	pub const DOWN : Vec2 = Vec2 { x: 0.0, y: -1.0 };
+impl<T: Scalar> Vector2<T> {
+	pub const ZERO : Vector2<T> = Vector2 { x: T::ZERO, y: T::ZERO };
+	pub const ONE : Vector2<T> = Vector2 { x: T::ONE, y: T::ONE };
+	pub const LEFT : Vector2<T> = Vector2 { x: T::NEG_ONE, y: T::ZERO };
+	pub const RIGHT : Vector2<T> = Vector2 { x: T::ONE, y: T::ZERO };
+	pub const UP : Vector2<T> = Vector2 { x: T::ZERO, y: T::ONE };
+	pub const DOWN : Vector2<T> = Vector2 { x: T::ZERO, y: T::NEG_ONE };
 
-	pub fn det(&self, rhs : Vec2) -> f32 {
+	pub fn det(&self, rhs : Vector2<T>) -> T {
                 self.x*rhs.y-self.y*rhs.x
 	}
 
-        pub fn mag(&self) -> f32 {
+        pub fn mag(&self) -> T {
                 (self.x*self.x + self.y*self.y).sqrt()
         }
 
-        pub fn mag2(&self) -> f32 {
+        pub fn mag2(&self) -> T {
                 self.x*self.x + self.y*self.y
         }
 
-        pub fn norm(&self) -> Vec2 {
-                let mag : f32 = (self.x*self.x + self.y*self.y).sqrt();
-                Vec2 { x: self.x/mag, y: self.y/mag }
+        pub fn norm(&self) -> Vector2<T> {
+                let mag : T = (self.x*self.x + self.y*self.y).sqrt();
+                Vector2 { x: self.x/mag, y: self.y/mag }
         }
+
+	/// Normalizes the vector, failing instead of dividing by zero when its magnitude is zero.
+	pub fn try_norm(&self) -> Result<Vector2<T>, LineqError> {
+		let mag : T = self.mag();
+		if mag == T::ZERO { return Err(LineqError::ZeroVector); }
+		Ok(Vector2 { x: self.x/mag, y: self.y/mag })
+	}
 }
@@ -1,8 +1,14 @@
-use crate::vec2::Vec2;
+use crate::scalar::Scalar;
+use crate::simd;
+use crate::error::{LineqError, check_len};
+use crate::vec2::{Vector2, Vec2};
 use std::mem::MaybeUninit;
 use std::ptr::slice_from_raw_parts_mut;
 use std::alloc::Layout;
 use std::alloc::alloc;
+use std::alloc::dealloc;
+use std::alloc::handle_alloc_error;
+use std::ptr::NonNull;
 use ::deref_impl;
 use ::deref_mut_impl;
 use ::value_impl;
@@ -13,22 +19,108 @@ use ::pv_inplace_impl;
 use ::pv_dot_impl;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Vec2arr<const N: usize>(pub [Vec2; N]);
+#[repr(transparent)]
+pub struct Vector2arr<const N: usize, T>(pub [Vector2<T>; N]);
+
+/// A [Vec2arr] of [f32]s.
+pub type Vec2arr<const N: usize> = Vector2arr<N, f32>;
+
+/// Creates a [Vec2arr] from an array of vectors.
+#[allow(non_snake_case)]
+pub const fn Vec2arr<const N: usize>(arr : [Vec2; N]) -> Vec2arr<N> {
+        Vector2arr(arr)
+}
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct Vec2box(pub Box<[Vec2]>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector2box<T>(pub Box<[Vector2<T>]>);
+
+/// A [Vec2box] of [f32]s.
+pub type Vec2box = Vector2box<f32>;
+
+/// Creates a [Vec2box] from a boxed slice of vectors.
+#[allow(non_snake_case)]
+pub const fn Vec2box(arr : Box<[Vec2]>) -> Vec2box {
+        Vector2box(arr)
+}
 
 #[derive(Debug, PartialEq)]
-pub struct Vec2win<'a>(pub &'a mut [Vec2]);
+pub struct Vector2win<'a, T>(pub &'a mut [Vector2<T>]);
+
+/// A [Vec2win] of [f32]s.
+pub type Vec2win<'a> = Vector2win<'a, f32>;
+
+/// Creates a [Vec2win] over a mutable slice of vectors.
+#[allow(non_snake_case)]
+pub const fn Vec2win(arr : &mut [Vec2]) -> Vec2win<'_> {
+        Vector2win(arr)
+}
+
+/// A growable array of vectors, for building up an array before using it like a [Vec2box].
+///
+/// In operators it counts as less structured than a [Vec2box] and more than a [Vec2win], so adding
+/// a `Vec2vec` to a [Vec2win] or another `Vec2vec` gives a `Vec2vec`, which is allocated the same way as
+/// a [Vec2box] would be. It converts to a [Vec2box] with [into_box](Vec2vec::into_box), and from one
+/// with [From], without copying the vectors.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec2::Vec2;
+/// use lineq::vec2arr::{Vec2arr, Vec2box, Vec2vec, Vec2win, Vec2Array};
+/// let mut a : Vec2vec = (0..3).map(|i| Vec2::ONE*i as f32).collect();
+/// let mut b : Vec2box = Vec2box::ftoarr(|_| Vec2::ONE, 3);
+/// let c : Vec2vec = &a + Vec2win(&mut b);
+/// let d : Vec2box = &a + &b;
+/// let e : Vec2arr<3> = &a + Vec2arr([Vec2::ONE; 3]);
+/// assert_eq!(&c[..], &d[..]);
+/// assert_eq!(&c[..], &e[..]);
+/// a -= &b;
+/// assert_eq!(&*a.dot(&b), &[-2.0, 0.0, 2.0]);
+/// let n : Vec2vec = Vec2Array::norm(&c);
+/// assert_eq!(n.len(), 3);
+/// let mut v : Vec2vec = Vec2vec::new();
+/// assert_eq!(v.to_string(), "[]");
+/// v.push(Vec2::ONE);
+/// assert_eq!(v.to_string(), "[<1, 1>]");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector2vec<T>(pub Vec<Vector2<T>>);
 
+/// A [Vec2vec] of [f32]s.
+pub type Vec2vec = Vector2vec<f32>;
+
+/// Creates a [Vec2vec] from a [Vec] of vectors.
+#[allow(non_snake_case)]
+pub const fn Vec2vec(arr : Vec<Vec2>) -> Vec2vec {
+        Vector2vec(arr)
+}
+
+/// An array of vectors behind a raw pointer, allocated with [alloc] and freed when dropped.
+///
+/// A `Vec2raw` always owns its memory, the same as a [Box], so it is [Send] and [Sync]. Use
+/// [into_raw](Vec2raw::into_raw) to give the pointer away without freeing it, and
+/// [from_raw](Vec2raw::from_raw) to take it back. Memory owned by something else should be
+/// borrowed as a [Vec2win] instead.
 #[derive(Debug, PartialEq)]
-pub struct Vec2raw(pub *mut [Vec2]);
+pub struct Vector2raw<T>(*mut [Vector2<T>]);
+
+/// A [Vec2raw] of [f32]s.
+pub type Vec2raw = Vector2raw<f32>;
+
+// A Vec2raw owns its allocation and its scalars are plain data, so it can move between
+// and be shared by threads like a Box<[Vec2<T>]>.
+unsafe impl<T: Scalar + Send> Send for Vector2raw<T> {}
+unsafe impl<T: Scalar + Sync> Sync for Vector2raw<T> {}
 
 macro_rules! disp_impl {
         (Disp $t:ty$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
-                impl$(<$lt>)?$(<const $gen: $gent>)? fmt::Display for $t {
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> fmt::Display for $t {
 			#[inline]
                         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                                if self.len() == 0 { return write!(f, "[]"); }
                                 write!(f, "[")?;
                                 for i in 0..self.len()-1 {
                                         write!(f, "<{}, {}>, ", self[i].x, self[i].y)?
@@ -39,811 +131,703 @@ macro_rules! disp_impl {
         };
 }
 
-//Deref
-use std::ops::Deref;
+macro_rules! try_impl {
+        (Try $t:ty$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> $t {
+                        /// Adds `rhs` to the vectors in place, failing instead of panicking if the lengths differ.
+                        pub fn try_add_assign(&mut self, rhs : &[Vector2<T>]) -> Result<(), LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                for (a, b) in self.iter_mut().zip(rhs.iter()) {
+                                        *a += *b;
+                                }
+                                Ok(())
+                        }
 
-deref_impl! {Deref val Vec2arr<N>; to [Vec2; N]; const N: usize}
-deref_impl! {Deref val Vec2box; to [Vec2]}
-deref_impl! {Deref ptr Vec2win<'_>; to [Vec2]}
-impl Deref for Vec2raw {
-	type Target = [Vec2];
-	fn deref(&self) -> &Self::Target {
-		unsafe { &*self.0 }
-	}
-}
+                        /// Subtracts `rhs` from the vectors in place, failing instead of panicking if the lengths differ.
+                        pub fn try_sub_assign(&mut self, rhs : &[Vector2<T>]) -> Result<(), LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                for (a, b) in self.iter_mut().zip(rhs.iter()) {
+                                        *a -= *b;
+                                }
+                                Ok(())
+                        }
 
-//DerefMut
-use std::ops::DerefMut;
+                        /// Adds two arrays of vectors into a new Vec2box, failing instead of panicking if the lengths differ.
+                        ///
+                        /// # Examples
+                        ///
+                        /// ```rust
+                        /// # extern crate lineq;
+                        /// use lineq::vec2::Vec2;
+                        /// use lineq::vec2arr::{Vec2arr, Vec2box, Vec2vec, Vec2win};
+                        /// use lineq::error::LineqError;
+                        /// let mut a : Vec2box = Vec2box::new_zeroed(3);
+                        /// let b : Vec2arr<2> = Vec2arr([Vec2::ONE; 2]);
+                        /// assert_eq!(a.try_add(&*b), Err(LineqError::LengthMismatch { expected: 3, found: 2 }));
+                        /// assert_eq!(b.try_sub(&*b).unwrap(), Vec2box::new_zeroed(2));
+                        /// let c : Vec2vec = Vec2vec::from(vec![Vec2::ONE; 3]);
+                        /// assert_eq!(&*c.try_dot(&*a).unwrap(), &[0.0; 3]);
+                        /// assert!(Vec2win(&mut a).try_dot(&*b).is_err());
+                        /// ```
+                        pub fn try_add(&self, rhs : &[Vector2<T>]) -> Result<Vector2box<T>, LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                Ok(Vector2box::ftoarr(|i| self[i] + rhs[i], self.len()))
+                        }
 
-deref_mut_impl! {DerefMut val Vec2arr<N>; to [Vec2; N]; const N: usize}
-deref_mut_impl! {DerefMut val Vec2box; to [Vec2]}
-deref_mut_impl! {DerefMut ptr Vec2win<'_>; to [Vec2]}
-impl DerefMut for Vec2raw {
-	fn deref_mut(&mut self) -> &mut [Vec2] {
-		unsafe { &mut *self.0 }
-	}
-}
+                        /// Subtracts two arrays of vectors into a new Vec2box, failing instead of panicking if the lengths differ.
+                        pub fn try_sub(&self, rhs : &[Vector2<T>]) -> Result<Vector2box<T>, LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                Ok(Vector2box::ftoarr(|i| self[i] - rhs[i], self.len()))
+                        }
 
-//Add
-use std::ops::Add;
-	
-/*impl<T: Copy, const N: usize> Add<T> for Vec2arr<N>
-where 
-    Vec2: Add<T, Output = Vec2>,
-{
-    type Output = Vec2arr<N>;
-    #[inline]
-    fn add(self, rhs: T) -> Vec2arr<N> {
-        let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..N {
-            tmp[i] = self[i] + rhs;
-        }
-        unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
-    }
-}
-
-impl<T: Copy, const N: usize> Add<T> for &Vec2arr<N>
-where 
-    Vec2: Add<T, Output = Vec2>,
-{
-    type Output = Vec2arr<N>;
-    #[inline]
-    fn add(self, rhs: T) -> Vec2arr<N> {
-        let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..N {
-            tmp[i] = self[i] + rhs;
-        }
-        unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
-    }
-}
-
-impl<T: Copy> Add<T> for Vec2box
-where 
-    Vec2: Add<T, Output = Vec2>,
-{
-    type Output = Vec2box;
-    #[inline]
-    fn add(self, rhs: T) -> Vec2box {
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] + rhs );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-}
-
-impl<T: Copy> Add<T> for &Vec2box
-where 
-    Vec2: Add<T, Output = Vec2>,
-{
-    type Output = Vec2box;
-    #[inline]
-    fn add(self, rhs: T) -> Vec2box {
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] + rhs );
-            }
-            tmp.assume_init()
+                        /// Takes the dot product of each pair of vectors, failing instead of panicking if the lengths differ.
+                        pub fn try_dot(&self, rhs : &[Vector2<T>]) -> Result<Box<[T]>, LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                Ok(self.iter().zip(rhs.iter()).map(|(a, b)| *a**b).collect())
+                        }
+                }
         };
-        Vec2box(tmp)
-    }
-}
-*/
-/*
-impl<T, const N: usize> Add<T> for f32
-where 
-    T: Deref<Target = [Vec2; N]>,
-    f32: Add<Vec2, Output = Vec2>,
-{
-    type Output = T;
-    #[inline]
-    fn add(self, rhs: T) -> T {
-        let mut tmp: T = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..N {
-            tmp[i] = self + rhs[i];
-        }
-        unsafe { std::mem::transmute::<_, T>(tmp) }
-    }
 }
-*/
-/*
-pv_value_impl! {Add;add;+; 2 Vec2arr<N>; for f32; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 2 Vec2box; for f32; out: Vec2box}
-*/
-// see https://github.com/rust-lang/rfcs/pull/1672 for fix
 
-//-----------
-pub trait Assoc {
-    type ID;
+// Operators that give a Vec2vec, computed into a box which then becomes the buffer of the Vec2vec.
+macro_rules! vec_value_impl {
+        ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
+                vec_value_impl! {@3 $imp;$func;$op; $rhs; for $lhs$(; <$lt>)?}
+                vec_value_impl! {@3 $imp;$func;$op; &$rhs; for $lhs$(; <$lt>)?}
+                vec_value_impl! {@3 $imp;$func;$op; $rhs; for &$lhs$(; <$lt>)?}
+                vec_value_impl! {@3 $imp;$func;$op; &$rhs; for &$lhs$(; <$lt>)?}
+        };
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:ty) => {
+                vec_value_impl! {@2 $imp;$func;$op; $rhs; for $lhs; out: $out}
+                vec_value_impl! {@2 $imp;$func;$op; &$rhs; for $lhs; out: $out}
+                vec_value_impl! {@2 $imp;$func;$op; $rhs; for &$lhs; out: $out}
+                vec_value_impl! {@2 $imp;$func;$op; &$rhs; for &$lhs; out: $out}
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty) => {
+                vec_value_impl! {@1 $imp;$func;$op; $rhs; for $lhs}
+                vec_value_impl! {@1 $imp;$func;$op; &$rhs; for $lhs}
+                vec_value_impl! {@1 $imp;$func;$op; $rhs; for &$lhs}
+                vec_value_impl! {@1 $imp;$func;$op; &$rhs; for &$lhs}
+        };
+        (@3 $imp:ident;$func:ident;$op:tt; $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector2vec<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector2vec<T> {
+                                if self.len() != rhs.len() { panic!("slices inequal length"); }
+                                let mut tmp = Vector2box::new_uninit_box(self.len());
+                                simd::zip(&mut tmp, &self[..], &rhs[..], |a, b| a $op b);
+                                Vector2vec(unsafe { tmp.assume_init() }.into_vec())
+                        }
+                }
+        };
+        (@2 $imp:ident;$func:ident;$op:tt; $rhs:ty; for $lhs:ty; out: $out:ty) => {
+                impl $imp<$rhs> for $lhs {
+                        type Output = $out;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> $out {
+                                let mut tmp = Vector2box::new_uninit_box(rhs.len());
+                                simd::scalar_zip(&mut tmp, self, &rhs[..], |a, b| a $op b);
+                                Vector2vec(unsafe { tmp.assume_init() }.into_vec())
+                        }
+                }
+        };
+        (@1 $imp:ident;$func:ident;$op:tt; $rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector2vec<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector2vec<T> {
+                                let mut tmp = Vector2box::new_uninit_box(self.len());
+                                simd::zip_scalar(&mut tmp, &self[..], rhs, |a, b| a $op b);
+                                Vector2vec(unsafe { tmp.assume_init() }.into_vec())
+                        }
+                }
+        };
 }
 
-pub trait VArr<const N: usize>: Assoc<ID = i8> {} // Array type
-pub trait VBox: Assoc<ID = i16> {} // Box type
-pub trait VInd: Assoc<ID = i32> {} // Indexable type
-pub trait VNot: Assoc<ID = i64> {} // None of the above
-
-// some basic impls for types to test this out
-
-impl<const N: usize> VArr<N> for Vec2arr<N> {}
-impl<const N: usize> Assoc for Vec2arr<N> {
-    type ID = i8;
-}
+// Borrowing any of the arrays iterates over references to its vectors, like borrowing a slice.
+macro_rules! iter_impl {
+        (Iter $t:ty$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
+                impl<'b, $($lt,)?$(const $gen: $gent,)?T: Scalar> IntoIterator for &'b $t {
+                        type Item = &'b Vector2<T>;
+                        type IntoIter = std::slice::Iter<'b, Vector2<T>>;
+                        fn into_iter(self) -> std::slice::Iter<'b, Vector2<T>> {
+                                self.iter()
+                        }
+                }
 
-impl VBox for Vec2box {}
-impl Assoc for Vec2box {
-    type ID = i16;
+                impl<'b, $($lt,)?$(const $gen: $gent,)?T: Scalar> IntoIterator for &'b mut $t {
+                        type Item = &'b mut Vector2<T>;
+                        type IntoIter = std::slice::IterMut<'b, Vector2<T>>;
+                        fn into_iter(self) -> std::slice::IterMut<'b, Vector2<T>> {
+                                self.iter_mut()
+                        }
+                }
+        };
 }
 
-impl<'a> VInd for Vec2win<'a> {}
-impl<'a> Assoc for Vec2win<'a> {
-    type ID = i32;
-}
+//Deref
+use std::ops::Deref;
 
-impl VInd for Vec2raw {}
-impl Assoc for Vec2raw {
-    type ID = i32;
+deref_impl! {Deref val Vector2arr<N, T>; to [Vector2<T>; N]; const N: usize; T: Scalar}
+deref_impl! {Deref val Vector2box<T>; to [Vector2<T>]; T: Scalar}
+deref_impl! {Deref ptr Vector2win<'_, T>; to [Vector2<T>]; T: Scalar}
+impl<T: Scalar> Deref for Vector2raw<T> {
+	type Target = [Vector2<T>];
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*self.0 }
+	}
 }
+deref_impl! {Deref val Vector2vec<T>; to [Vector2<T>]; T: Scalar}
 
-impl VNot for f32 {}
-impl Assoc for f32 {
-    type ID = i64;
-}
+//DerefMut
+use std::ops::DerefMut;
 
-impl VNot for Vec2 {}
-impl Assoc for Vec2 {
-    type ID = i64;
+deref_mut_impl! {DerefMut val Vector2arr<N, T>; to [Vector2<T>; N]; const N: usize; T: Scalar}
+deref_mut_impl! {DerefMut val Vector2box<T>; to [Vector2<T>]; T: Scalar}
+deref_mut_impl! {DerefMut ptr Vector2win<'_, T>; to [Vector2<T>]; T: Scalar}
+impl<T: Scalar> DerefMut for Vector2raw<T> {
+	fn deref_mut(&mut self) -> &mut [Vector2<T>] {
+		unsafe { &mut *self.0 }
+	}
 }
+deref_mut_impl! {DerefMut val Vector2vec<T>; to [Vector2<T>]; T: Scalar}
 
-// Since impls with distinct parameters are considered disjoint
-// we can write multiple blanket impls for YakHelper given different paremeters
-trait BoxHelper<ID, Rhs> { //used when lhs is a box type
-    type AddType;
-    fn add_imp(self, rhs: Rhs) -> Self::AddType;
-    type DivType;
-    fn div_imp(self, rhs: Rhs) -> Self::DivType;
-    type MulType;
-    fn mul_imp(self, rhs: Rhs) -> Self::MulType;
-    type SubType;
-    fn sub_imp(self, rhs: Rhs) -> Self::SubType;
-    fn add_assign_imp(&mut self, rhs: Rhs);
-    fn div_assign_imp(&mut self, rhs: Rhs);
-    fn mul_assign_imp(&mut self, rhs: Rhs);
-    fn sub_assign_imp(&mut self, rhs: Rhs);
-}
+//Add
+use std::ops::Add;
 
-// blanket impl 1
-impl<T: VArr, Rhs> BoxHelper<i8, Rhs> for T { //Rhs is array type
-    type AddType = Vec2arr<N>;
-    fn add_imp(self, rhs: Rhs) -> Vec2arr<N> {
-        if self.len() != rhs.len() { panic!("slice and array inequal length"); }
-        let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..N {
-            tmp[i] = self[i] + rhs[i];
-        }
-        unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
-    }
-    type DivType = Vec2arr<N>;
-    fn div_imp(self, rhs: Rhs) -> Vec2arr<N> {
-        if self.len() != rhs.len() { panic!("slice and array inequal length"); }
-        let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..N {
-            tmp[i] = self[i] / rhs[i];
-        }
-        unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
-    }
-    type MulType = Vec2arr<N>;
-    fn mul_imp(self, rhs: Rhs) -> Vec2arr<N> {
-        if self.len() != rhs.len() { panic!("slice and array inequal length"); }
-        let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..N {
-            tmp[i] = self[i] * rhs[i];
-        }
-        unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
-    }
-    type SubType = Vec2arr<N>;
-    fn sub_imp(self, rhs: Rhs) -> Vec2arr<N> {
-        if self.len() != rhs.len() { panic!("slice and array inequal length"); }
-        let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..N {
-            tmp[i] = self[i] - rhs[i];
-        }
-        unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
-    }
-    fn add_assign_imp(&mut self, rhs: Rhs) {
-        println!("adding inplace")
-    }
-    fn div_assign_imp(&mut self, rhs: Rhs) {
-        println!("dividing inplace")
-    }
-    fn mul_assign_imp(&mut self, rhs: Rhs) {
-        println!("multiplying inplace")
-    }
-    fn sub_assign_imp(&mut self, rhs: Rhs) {
-        println!("subtracting inplace")
-    }
-}
-    
-// blanket impl 2
-impl<T: VBox, Rhs> BoxHelper<i16, Rhs> for T { //Rhs is box type
-    type AddType = Vec2box;
-    fn add_imp(self, rhs: Rhs) -> Vec2box {
-        if self.len() != rhs.len() { panic!("slices inequal length"); }
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] + rhs[i] );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type DivType = Vec2box;
-    fn div_imp(self, rhs: Rhs) -> Vec2box {
-        if self.len() != rhs.len() { panic!("slices inequal length"); }
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] / rhs[i] );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type MulType = Vec2box;
-    fn mul_imp(self, rhs: Rhs) -> Vec2box {
-        if self.len() != rhs.len() { panic!("slices inequal length"); }
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] * rhs[i] );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type SubType = Vec2box;
-    fn sub_imp(self, rhs: Rhs) -> Vec2box {
-        if self.len() != rhs.len() { panic!("slices inequal length"); }
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] - rhs[i] );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    fn add_assign_imp(&mut self, rhs: Rhs) {
-        println!("adding inplace")
-    }
-    fn div_assign_imp(&mut self, rhs: Rhs) {
-        println!("dividing inplace")
-    }
-    fn mul_assign_imp(&mut self, rhs: Rhs) {
-        println!("multiplying inplace")
-    }
-    fn sub_assign_imp(&mut self, rhs: Rhs) {
-        println!("subtracting inplace")
-    }
-}
-
-// blanket impl 3
-impl<T: VInd, Rhs> BoxHelper<i32, Rhs> for T { //Rhs is indexable type
-    type AddType = Vec2box;
-    fn add_imp(self, rhs: Rhs) -> Vec2box {
-        if self.len() != rhs.len() { panic!("slices inequal length"); }
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] + rhs[i] );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type DivType = Vec2box;
-    fn div_imp(self, rhs: Rhs) -> Vec2box {
-        if self.len() != rhs.len() { panic!("slices inequal length"); }
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] / rhs[i] );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type MulType = Vec2box;
-    fn mul_imp(self, rhs: Rhs) -> Vec2box {
-        if self.len() != rhs.len() { panic!("slices inequal length"); }
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] * rhs[i] );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type SubType = Vec2box;
-    fn sub_imp(self, rhs: Rhs) -> Vec2box {
-        if self.len() != rhs.len() { panic!("slices inequal length"); }
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] - rhs[i] );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    fn add_assign_imp(&mut self, rhs: Rhs) {
-        println!("adding inplace")
-    }
-    fn div_assign_imp(&mut self, rhs: Rhs) {
-        println!("dividing inplace")
-    }
-    fn mul_assign_imp(&mut self, rhs: Rhs) {
-        println!("multiplying inplace")
-    }
-    fn sub_assign_imp(&mut self, rhs: Rhs) {
-        println!("subtracting inplace")
-    }
-}
-
-// blanket impl 4
-impl<T: VNot, Rhs> BoxHelper<i64, Rhs> for T { //Rhs is none of the above type
-    type AddType = Vec2box;
-    fn add_imp(self, rhs: Rhs) -> Vec2box {
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] + rhs );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type DivType = Vec2box;
-    fn div_imp(self, rhs: Rhs) -> Vec2box {
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] / rhs );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type MulType = Vec2box;
-    fn mul_imp(self, rhs: Rhs) -> Vec2box {
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] * rhs );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    type SubType = Vec2box;
-    fn sub_imp(self, rhs: Rhs) -> Vec2box {
-        let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-        let tmp = unsafe {
-            for i in 0..self.len() {
-                tmp[i].as_mut_ptr().write( self[i] - rhs );
-            }
-            tmp.assume_init()
-        };
-        Vec2box(tmp)
-    }
-    fn add_assign_imp(&mut self, rhs: Rhs) {
-        println!("adding inplace")
-    }
-    fn div_assign_imp(&mut self, rhs: Rhs) {
-        println!("dividing inplace")
-    }
-    fn mul_assign_imp(&mut self, rhs: Rhs) {
-        println!("multiplying inplace")
-    }
-    fn sub_assign_imp(&mut self, rhs: Rhs) {
-        println!("subtracting inplace")
-    }
-}
-
-// the other trait that we'll try to implement a blanket impl for
-pub trait BoxValue<Rhs = Self> {
-    fn add(self, rhs: Rhs);
-    fn div(self, rhs: Rhs);
-    fn mul(self, rhs: Rhs);
-    fn sub(self, rhs: Rhs);
-    fn add_assign(&mut self, rhs: Rhs);
-    fn div_assign(&mut self, rhs: Rhs);
-    fn mul_assign(&mut self, rhs: Rhs);
-    fn sub_assign(&mut self, rhs: Rhs);
-}
-
-// This delegates to a private helper trait which we can specialize on in stable rust
-impl<T: Assoc + BoxHelper<T::ID, T>> BoxValue<T> for T {
-    fn add(self, rhs: T) {
-        BoxHelper::add_imp(self, rhs)
-    }
-    fn div(self, rhs: T) {
-        BoxHelper::div_imp(self, rhs)
-    }
-    fn mul(self, rhs: T) {
-        BoxHelper::mul_imp(self, rhs)
-    }
-    fn sub(self, rhs: T) {
-        BoxHelper::sub_imp(self, rhs)
-    }  
-    fn add_assign(&mut self, rhs: T) {
-        BoxHelper::add_assign_imp(self, rhs)
-    }
-    fn div_assign(&mut self, rhs: T) {
-        BoxHelper::div_assign_imp(self, rhs)
-    }
-    fn mul_assign(&mut self, rhs: T) {
-        BoxHelper::mul_assign_imp(self, rhs)
-    }
-    fn sub_assign(&mut self, rhs: T) {
-        BoxHelper::sub_assign_imp(self, rhs)
-    }
-}
-//-----------
-/*
-impl<T, const N: usize> Add<T> for Vec2arr<N>
-where
-    T: Deref<Target = [Vec2; N]>
-{
-    type Output = Vec2arr<N>;
-    #[inline]
-    fn add(self, rhs: T) -> Vec2arr<N> {
-        if self.len() != rhs.len() { panic!("slice and array inequal length"); }
-        let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-        for i in 0..N {
-            tmp[i] = self[i] + rhs[i];
-        }
-        unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
-    }
-}
-*/
-/*pv_value_impl! {Add;add;+; 3 Vec2arr<N>; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec2box; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec2win<'a>; for Vec2arr<N>; out: Vec2arr<N>; const N: usize; <'a>}
-pv_value_impl! {Add;add;+; 3 Vec2raw; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec2arr<N>; for Vec2box; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec2box; for Vec2box; out: Vec2box}
-pv_value_impl! {Add;add;+; 3 Vec2win<'a>; for Vec2box; out: Vec2box; <'a>}
-pv_value_impl! {Add;add;+; 3 Vec2raw; for Vec2box; out: Vec2box}
-pv_value_impl! {Add;add;+; 3 Vec2arr<N>; for Vec2win<'a>; out: Vec2arr<N>; const N: usize; <'a>}
-pv_value_impl! {Add;add;+; 3 Vec2box; for Vec2win<'a>; out: Vec2box; <'a>}
-pv_value_impl! {Add;add;+; 3 Vec2arr<N>; for Vec2raw; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec2box; for Vec2raw; out: Vec2box}*/
+pv_value_impl! {Add;add;+; 1 T; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 1 T; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Add;add;+; 2 Vector2arr<N, f32>; for f32; out: Vector2arr<N, f32>; const N: usize}
+pv_value_impl! {Add;add;+; 2 Vector2arr<N, f64>; for f64; out: Vector2arr<N, f64>; const N: usize}
+pv_value_impl! {Add;add;+; 2 Vector2box<f32>; for f32; out: Vector2box<f32>}
+pv_value_impl! {Add;add;+; 2 Vector2box<f64>; for f64; out: Vector2box<f64>}
+pv_value_impl! {Add;add;+; 3 Vector2arr<N, T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2box<T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2win<'a, T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2raw<T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2arr<N, T>; for Vector2box<T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2box<T>; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2win<'a, T>; for Vector2box<T>; out: Vector2box<T>; <'a>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2raw<T>; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2arr<N, T>; for Vector2win<'a, T>; out: Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2box<T>; for Vector2win<'a, T>; out: Vector2box<T>; <'a>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2arr<N, T>; for Vector2raw<T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2box<T>; for Vector2raw<T>; out: Vector2box<T>; T: Scalar}
+vec_value_impl! {Add;add;+; 1 T; for Vector2vec<T>}
+vec_value_impl! {Add;add;+; 2 Vector2vec<f32>; for f32; out: Vector2vec<f32>}
+vec_value_impl! {Add;add;+; 2 Vector2vec<f64>; for f64; out: Vector2vec<f64>}
+pv_value_impl! {Add;add;+; 3 Vector2vec<T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2arr<N, T>; for Vector2vec<T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2vec<T>; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector2box<T>; for Vector2vec<T>; out: Vector2box<T>; T: Scalar}
+vec_value_impl! {Add;add;+; 3 Vector2vec<T>; for Vector2vec<T>}
+vec_value_impl! {Add;add;+; 3 Vector2win<'a, T>; for Vector2vec<T>; <'a>}
+vec_value_impl! {Add;add;+; 3 Vector2raw<T>; for Vector2vec<T>}
+vec_value_impl! {Add;add;+; 3 Vector2vec<T>; for Vector2win<'a, T>; <'a>}
+vec_value_impl! {Add;add;+; 3 Vector2vec<T>; for Vector2raw<T>}
 
 //AddAssign
 use std::ops::AddAssign;
 
-pv_inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec2arr<N>; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec2box}
-pv_inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec2raw}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2arr<N>; for Vec2arr<N>; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2box; for Vec2arr<N>; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2win<'a>; for Vec2arr<N>; const N: usize; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2raw; for Vec2arr<N>; const N: usize}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vec2]; for Vec2arr<N>; const N: usize; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vec2]; for Vec2arr<N>; const N: usize; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2arr<N>; for Vec2box; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2box; for Vec2box}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2win<'a>; for Vec2box; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2raw; for Vec2box}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vec2]; for Vec2box; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vec2]; for Vec2box; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2arr<N>; for Vec2win<'a>; const N: usize; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2box; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2win<'a>; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2raw; for Vec2win<'a>; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vec2]; for Vec2win<'a>; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vec2]; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2arr<N>; for Vec2raw; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2box; for Vec2raw}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2win<'a>; for Vec2raw; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec2raw; for Vec2raw}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vec2]; for Vec2raw; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vec2]; for Vec2raw; <'a>}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector2box<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector2raw<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2arr<N, T>; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2box<T>; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2win<'a, T>; for Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2raw<T>; for Vector2arr<N, T>; const N: usize; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector2<T>]; for Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector2<T>]; for Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2arr<N, T>; for Vector2box<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2box<T>; for Vector2box<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2win<'a, T>; for Vector2box<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2raw<T>; for Vector2box<T>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector2<T>]; for Vector2box<T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector2<T>]; for Vector2box<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2arr<N, T>; for Vector2win<'a, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2box<T>; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2win<'a, T>; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2raw<T>; for Vector2win<'a, T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector2<T>]; for Vector2win<'a, T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector2<T>]; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2arr<N, T>; for Vector2raw<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2box<T>; for Vector2raw<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2win<'a, T>; for Vector2raw<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2raw<T>; for Vector2raw<T>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector2<T>]; for Vector2raw<T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector2<T>]; for Vector2raw<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector2vec<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2vec<T>; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2vec<T>; for Vector2box<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2vec<T>; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2vec<T>; for Vector2raw<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2arr<N, T>; for Vector2vec<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2box<T>; for Vector2vec<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2win<'a, T>; for Vector2vec<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2raw<T>; for Vector2vec<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector2vec<T>; for Vector2vec<T>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector2<T>]; for Vector2vec<T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector2<T>]; for Vector2vec<T>; <'a>; T: Scalar}
 
 //Display
 use std::fmt;
 
-disp_impl! {Disp Vec2arr<N>; const N: usize}
-disp_impl! {Disp Vec2box}
-disp_impl! {Disp Vec2win<'a>; <'a>}
-disp_impl! {Disp Vec2raw}
+disp_impl! {Disp Vector2arr<N, T>; const N: usize}
+disp_impl! {Disp Vector2box<T>}
+disp_impl! {Disp Vector2win<'a, T>; <'a>}
+disp_impl! {Disp Vector2raw<T>}
+disp_impl! {Disp Vector2vec<T>}
+
+//Drop
+
+impl<T> Drop for Vector2raw<T> {
+	fn drop(&mut self) {
+		let len : usize = self.0.len();
+		if len != 0 {
+			unsafe { dealloc(self.0 as *mut u8, Layout::array::<Vector2<T>>(len).unwrap()) };
+		}
+	}
+}
 
 //Div
 use std::ops::Div;
 
-pv_value_impl! {Div;div;/; 1 f32; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Div;div;/; 1 f32; for Vec2box; out: Vec2box}
+pv_value_impl! {Div;div;/; 1 T; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Div;div;/; 1 T; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+vec_value_impl! {Div;div;/; 1 T; for Vector2vec<T>}
 
 //DivAssign
 use std::ops::DivAssign;
 
-pv_inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec2arr<N>; const N: usize}
-pv_inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec2box}
-pv_inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec2raw}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector2box<T>; T: Scalar}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector2raw<T>; T: Scalar}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector2vec<T>; T: Scalar}
+
+//IntoIterator
+
+iter_impl! {Iter Vector2arr<N, T>; const N: usize}
+iter_impl! {Iter Vector2box<T>}
+iter_impl! {Iter Vector2vec<T>}
+iter_impl! {Iter Vector2win<'a, T>; <'a>}
+iter_impl! {Iter Vector2raw<T>}
+
+impl<const N: usize, T: Scalar> IntoIterator for Vector2arr<N, T> {
+        type Item = Vector2<T>;
+        type IntoIter = std::array::IntoIter<Vector2<T>, N>;
+        fn into_iter(self) -> std::array::IntoIter<Vector2<T>, N> {
+                IntoIterator::into_iter(self.0)
+        }
+}
+
+impl<T: Scalar> IntoIterator for Vector2box<T> {
+        type Item = Vector2<T>;
+        type IntoIter = std::vec::IntoIter<Vector2<T>>;
+        fn into_iter(self) -> std::vec::IntoIter<Vector2<T>> {
+                self.0.into_vec().into_iter()
+        }
+}
+
+impl<T: Scalar> IntoIterator for Vector2vec<T> {
+        type Item = Vector2<T>;
+        type IntoIter = std::vec::IntoIter<Vector2<T>>;
+        fn into_iter(self) -> std::vec::IntoIter<Vector2<T>> {
+                self.0.into_iter()
+        }
+}
+
+// A Vec2win only borrows its vectors, so it iterates over them mutably instead of moving them out.
+impl<'a, T: Scalar> IntoIterator for Vector2win<'a, T> {
+        type Item = &'a mut Vector2<T>;
+        type IntoIter = std::slice::IterMut<'a, Vector2<T>>;
+        fn into_iter(self) -> std::slice::IterMut<'a, Vector2<T>> {
+                self.0.iter_mut()
+        }
+}
+
+// A Vec2raw is allocated with the layout of a Box<[Vec2<T>]>, so a box can take over freeing it.
+impl<T: Scalar> IntoIterator for Vector2raw<T> {
+        type Item = Vector2<T>;
+        type IntoIter = std::vec::IntoIter<Vector2<T>>;
+        fn into_iter(self) -> std::vec::IntoIter<Vector2<T>> {
+                unsafe { Box::from_raw(self.into_raw()) }.into_vec().into_iter()
+        }
+}
 
 //Mult
 use std::ops::Mul;
 
-pv_value_impl! {Mul;mul;*; 1 f32; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Mul;mul;*; 1 f32; for Vec2box; out: Vec2box}
-pv_value_impl! {Mul;mul;*; 2 Vec2arr<N>; for f32; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Mul;mul;*; 2 Vec2box; for f32; out: Vec2box}
-pv_dot_impl! {Dot Vec2arr<N>; for Vec2arr<N>; const N: usize}
-pv_dot_impl! {Dot Vec2box; for Vec2arr<N>; const N: usize}
-pv_dot_impl! {Dot Vec2win<'a>; for Vec2arr<N>; const N: usize; <'a>}
-pv_dot_impl! {Dot Vec2raw; for Vec2arr<N>; const N: usize}
-pv_dot_impl! {Dot Vec2arr<N>; for Vec2box; const N: usize}
-pv_dot_impl! {Dot Vec2box; for Vec2box}
-pv_dot_impl! {Dot Vec2win<'a>; for Vec2box; <'a>}
-pv_dot_impl! {Dot Vec2raw; for Vec2box}
-pv_dot_impl! {Dot Vec2arr<N>; for Vec2win<'a>; const N: usize; <'a>}
-pv_dot_impl! {Dot Vec2box; for Vec2win<'a>; <'a>}
-pv_dot_impl! {Dot Vec2arr<N>; for Vec2raw; const N: usize}
-pv_dot_impl! {Dot Vec2box; for Vec2raw}
+pv_value_impl! {Mul;mul;*; 1 T; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Mul;mul;*; 1 T; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Mul;mul;*; 2 Vector2arr<N, f32>; for f32; out: Vector2arr<N, f32>; const N: usize}
+pv_value_impl! {Mul;mul;*; 2 Vector2arr<N, f64>; for f64; out: Vector2arr<N, f64>; const N: usize}
+pv_value_impl! {Mul;mul;*; 2 Vector2box<f32>; for f32; out: Vector2box<f32>}
+pv_value_impl! {Mul;mul;*; 2 Vector2box<f64>; for f64; out: Vector2box<f64>}
+pv_dot_impl! {Dot Vector2arr<N, T>; for Vector2arr<N, T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector2box<T>; for Vector2arr<N, T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector2win<'a, T>; for Vector2arr<N, T>; out: T; const N: usize; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector2raw<T>; for Vector2arr<N, T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector2arr<N, T>; for Vector2box<T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector2box<T>; for Vector2box<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector2win<'a, T>; for Vector2box<T>; out: T; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector2raw<T>; for Vector2box<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector2arr<N, T>; for Vector2win<'a, T>; out: T; const N: usize; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector2box<T>; for Vector2win<'a, T>; out: T; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector2arr<N, T>; for Vector2raw<T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector2box<T>; for Vector2raw<T>; out: T; T: Scalar}
+vec_value_impl! {Mul;mul;*; 1 T; for Vector2vec<T>}
+vec_value_impl! {Mul;mul;*; 2 Vector2vec<f32>; for f32; out: Vector2vec<f32>}
+vec_value_impl! {Mul;mul;*; 2 Vector2vec<f64>; for f64; out: Vector2vec<f64>}
+pv_dot_impl! {Dot Vector2vec<T>; for Vector2arr<N, T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector2arr<N, T>; for Vector2vec<T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector2vec<T>; for Vector2box<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector2box<T>; for Vector2vec<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector2vec<T>; for Vector2vec<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector2win<'a, T>; for Vector2vec<T>; out: T; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector2raw<T>; for Vector2vec<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector2vec<T>; for Vector2win<'a, T>; out: T; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector2vec<T>; for Vector2raw<T>; out: T; T: Scalar}
 
 //MultAssign
 use std::ops::MulAssign;
 
-pv_inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec2arr<N>; const N: usize}
-pv_inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec2box}
-pv_inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec2raw}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector2box<T>; T: Scalar}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector2raw<T>; T: Scalar}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector2vec<T>; T: Scalar}
 
 //Neg
 use std::ops::Neg;
 
-impl<const N: usize> Neg for Vec2arr<N> {
-        type Output = Vec2arr<N>;
-        fn neg(self) -> Vec2arr<N> {
-		let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-                        tmp[i] = Vec2 { x: -self[i].x, y: -self[i].y };
-                }
-		unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
+impl<const N: usize, T: Scalar> Neg for Vector2arr<N, T> {
+        type Output = Vector2arr<N, T>;
+        fn neg(self) -> Vector2arr<N, T> {
+		Vector2arr::ftoarr(|i| Vector2 { x: -self[i].x, y: -self[i].y })
         }
 }
 
-impl Neg for Vec2box {
-        type Output = Vec2box;
-        fn neg(self) -> Vec2box {
-                let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
+impl<T: Scalar> Neg for Vector2box<T> {
+        type Output = Vector2box<T>;
+        fn neg(self) -> Vector2box<T> {
+                let mut tmp = Box::<[Vector2<T>]>::new_uninit_slice(self.len());
                 let tmp = unsafe {
                         for i in 0..self.len() {
-                                tmp[i].as_mut_ptr().write( Vec2 { x: -self[i].x, y: -self[i].y } );
+                                tmp[i].as_mut_ptr().write( Vector2 { x: -self[i].x, y: -self[i].y } );
                         }
                         tmp.assume_init()
                 };
-                Vec2box(tmp)
+                Vector2box(tmp)
+        }
+}
+
+impl<T: Scalar> Neg for Vector2vec<T> {
+        type Output = Vector2vec<T>;
+        fn neg(mut self) -> Vector2vec<T> {
+                for v in self.iter_mut() {
+                        *v = -*v;
+                }
+                self
         }
 }
 
 //Sub
 use std::ops::Sub;
 
-pv_value_impl! {Sub;sub;-; 1 f32; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 1 f32; for Vec2box; out: Vec2box}
-pv_value_impl! {Sub;sub;-; 3 Vec2arr<N>; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec2box; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec2win<'a>; for Vec2arr<N>; out: Vec2arr<N>; const N: usize; <'a>}
-pv_value_impl! {Sub;sub;-; 3 Vec2raw; for Vec2arr<N>; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec2arr<N>; for Vec2box; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec2box; for Vec2box; out: Vec2box}
-pv_value_impl! {Sub;sub;-; 3 Vec2win<'a>; for Vec2box; out: Vec2box; <'a>}
-pv_value_impl! {Sub;sub;-; 3 Vec2raw; for Vec2box; out: Vec2box}
-pv_value_impl! {Sub;sub;-; 3 Vec2arr<N>; for Vec2win<'a>; out: Vec2arr<N>; const N: usize; <'a>}
-pv_value_impl! {Sub;sub;-; 3 Vec2box; for Vec2win<'a>; out: Vec2box; <'a>}
-pv_value_impl! {Sub;sub;-; 3 Vec2arr<N>; for Vec2raw; out: Vec2arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec2box; for Vec2raw; out: Vec2box}
+pv_value_impl! {Sub;sub;-; 1 T; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 1 T; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2arr<N, T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2box<T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2win<'a, T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2raw<T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2arr<N, T>; for Vector2box<T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2box<T>; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2win<'a, T>; for Vector2box<T>; out: Vector2box<T>; <'a>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2raw<T>; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2arr<N, T>; for Vector2win<'a, T>; out: Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2box<T>; for Vector2win<'a, T>; out: Vector2box<T>; <'a>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2arr<N, T>; for Vector2raw<T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2box<T>; for Vector2raw<T>; out: Vector2box<T>; T: Scalar}
+vec_value_impl! {Sub;sub;-; 1 T; for Vector2vec<T>}
+pv_value_impl! {Sub;sub;-; 3 Vector2vec<T>; for Vector2arr<N, T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2arr<N, T>; for Vector2vec<T>; out: Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2vec<T>; for Vector2box<T>; out: Vector2box<T>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector2box<T>; for Vector2vec<T>; out: Vector2box<T>; T: Scalar}
+vec_value_impl! {Sub;sub;-; 3 Vector2vec<T>; for Vector2vec<T>}
+vec_value_impl! {Sub;sub;-; 3 Vector2win<'a, T>; for Vector2vec<T>; <'a>}
+vec_value_impl! {Sub;sub;-; 3 Vector2raw<T>; for Vector2vec<T>}
+vec_value_impl! {Sub;sub;-; 3 Vector2vec<T>; for Vector2win<'a, T>; <'a>}
+vec_value_impl! {Sub;sub;-; 3 Vector2vec<T>; for Vector2raw<T>}
 
 //SubAssign
 use std::ops::SubAssign;
 
-pv_inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec2arr<N>; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec2box}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec2raw}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2arr<N>; for Vec2arr<N>; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2box; for Vec2arr<N>; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2win<'a>; for Vec2arr<N>; const N: usize; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2raw; for Vec2arr<N>; const N: usize}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vec2]; for Vec2arr<N>; const N: usize; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vec2]; for Vec2arr<N>; const N: usize; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2arr<N>; for Vec2box; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2box; for Vec2box}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2win<'a>; for Vec2box; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2raw; for Vec2box}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vec2]; for Vec2box; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vec2]; for Vec2box; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2arr<N>; for Vec2win<'a>; const N: usize; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2box; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2win<'a>; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2raw; for Vec2win<'a>; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vec2]; for Vec2win<'a>; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vec2]; for Vec2win<'a>; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2arr<N>; for Vec2raw; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2box; for Vec2raw}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2win<'a>; for Vec2raw; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec2raw; for Vec2raw}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vec2]; for Vec2raw; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vec2]; for Vec2raw; <'a>}
-
-//Vec2 Methods
-
-impl<const N: usize> Vec2arr<N> {
-
-        pub fn mag(&self) -> [f32; N] {
-		let mut tmp: [f32; N] = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-                        tmp[i] = (self[i].x*self[i].x + self[i].y*self[i].y).sqrt();
-                }
-                unsafe { std::mem::transmute::<_, [f32; N]>(tmp) }
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector2box<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector2raw<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2arr<N, T>; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2box<T>; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2win<'a, T>; for Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2raw<T>; for Vector2arr<N, T>; const N: usize; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector2<T>]; for Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector2<T>]; for Vector2arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2arr<N, T>; for Vector2box<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2box<T>; for Vector2box<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2win<'a, T>; for Vector2box<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2raw<T>; for Vector2box<T>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector2<T>]; for Vector2box<T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector2<T>]; for Vector2box<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2arr<N, T>; for Vector2win<'a, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2box<T>; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2win<'a, T>; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2raw<T>; for Vector2win<'a, T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector2<T>]; for Vector2win<'a, T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector2<T>]; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2arr<N, T>; for Vector2raw<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2box<T>; for Vector2raw<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2win<'a, T>; for Vector2raw<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2raw<T>; for Vector2raw<T>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector2<T>]; for Vector2raw<T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector2<T>]; for Vector2raw<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector2vec<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2vec<T>; for Vector2arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2vec<T>; for Vector2box<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2vec<T>; for Vector2win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2vec<T>; for Vector2raw<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2arr<N, T>; for Vector2vec<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2box<T>; for Vector2vec<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2win<'a, T>; for Vector2vec<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2raw<T>; for Vector2vec<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector2vec<T>; for Vector2vec<T>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector2<T>]; for Vector2vec<T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector2<T>]; for Vector2vec<T>; <'a>; T: Scalar}
+
+//Try
+
+try_impl! {Try Vector2arr<N, T>; const N: usize}
+try_impl! {Try Vector2box<T>}
+try_impl! {Try Vector2win<'a, T>; <'a>}
+try_impl! {Try Vector2raw<T>}
+try_impl! {Try Vector2vec<T>}
+
+//Vec2arr Methods
+
+impl<const N: usize, T: Scalar> Vector2arr<N, T> {
+
+	pub fn detarr(&self, rhs : Vector2arr<N, T>) -> [T; N] {
+		std::array::from_fn(|i| self[i].det(rhs[i]))
+	}
+
+	pub fn det(&self, rhs : Vector2<T>) -> [T; N] {
+		std::array::from_fn(|i| self[i].det(rhs))
+	}
+
+        pub fn mag(&self) -> [T; N] {
+		std::array::from_fn(|i| (self[i].x*self[i].x + self[i].y*self[i].y).sqrt())
         }
 
-        pub fn mag2(&self) -> [f32; N] {
-                let mut tmp: [f32; N] = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-                        tmp[i] = self[i].x*self[i].x + self[i].y*self[i].y;
-                }
-                unsafe { std::mem::transmute::<_, [f32; N]>(tmp) }
+        pub fn mag2(&self) -> [T; N] {
+		std::array::from_fn(|i| self[i].x*self[i].x + self[i].y*self[i].y)
         }
 
-        pub fn norm(&self) -> Vec2arr<N> {
-		let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-			let mag : f32 = (self[i].x*self[i].x + self[i].y*self[i].y).sqrt();
-                        tmp[i] = Vec2 { x: self[i].x/mag, y: self[i].y/mag };
-                }
-                unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
+        pub fn norm(&self) -> Vector2arr<N, T> {
+		Vector2arr::ftoarr(|i| {
+			let mag : T = (self[i].x*self[i].x + self[i].y*self[i].y).sqrt();
+			Vector2 { x: self[i].x/mag, y: self[i].y/mag }
+		})
         }
 
-	pub fn transform<F : Fn(Vec2) -> Vec2>(&mut self, f: F) {
+	pub fn transform<F : Fn(Vector2<T>) -> Vector2<T>>(&mut self, f: F) {
 		for i in 0..N {
                         self[i] = f(self[i]);
                 }
 	}
 
-	pub fn ftoarr<F : Fn(usize) -> Vec2>(f: F) -> Vec2arr<N> {
-		let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-		for i in 0..N {
-                        tmp[i] = f(i);
-                }
-		unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
+	pub fn ftoarr<F : Fn(usize) -> Vector2<T>>(f: F) -> Vector2arr<N, T> {
+		Vector2arr(std::array::from_fn(f))
 	}
 }
 
-impl Vec2box {
+// Vec2box Methods
 
-	pub fn new_uninit_box(len: usize) -> Box<[MaybeUninit<Vec2>]> {
-		Box::<[Vec2]>::new_uninit_slice(len)
-	}
+impl<T: Scalar> Vector2box<T> {
 
-	pub fn new_zeroed(len: usize) -> Vec2box {
-                unsafe { Vec2box(Box::<[Vec2]>::new_zeroed_slice(len).assume_init()) }
+	pub fn new_zeroed(len: usize) -> Vector2box<T> {
+                unsafe { Vector2box(Box::<[Vector2<T>]>::new_zeroed_slice(len).assume_init()) }
         }
 
-	pub fn from_raw_parts(ptr: *mut Vec2, len: usize) -> Vec2box {
-                unsafe { Vec2box(Box::from_raw(slice_from_raw_parts_mut(ptr, len))) }
+	/// Takes ownership of `len` vectors starting at `ptr`, which are freed when the `Vec2box`
+	/// is dropped.
+	///
+	/// # Safety
+	///
+	/// The pointer must have come from a `Box<[Vec2<T>]>` of exactly `len` vectors, for example
+	/// through `Box::into_raw`, and must not be used again except through the returned `Vec2box`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vec2;
+	/// use lineq::vec2arr::Vec2box;
+	/// let a : Vec2box = Vec2box::new_zeroed(4);
+	/// let ptr = Box::into_raw(a.0) as *mut Vec2;
+	/// let b : Vec2box = unsafe { Vec2box::from_raw_parts(ptr, 4) };
+	/// assert_eq!(b.len(), 4);
+	/// assert_eq!(b[3], Vec2::ZERO);
+	/// ```
+	pub unsafe fn from_raw_parts(ptr: *mut Vector2<T>, len: usize) -> Vector2box<T> {
+                Vector2box(Box::from_raw(slice_from_raw_parts_mut(ptr, len)))
         }
 
-        pub fn new_from_arr<const N: usize>(arr: Vec2arr<N>) -> Vec2box {
-                let mut new = Vec2box::new_uninit_box(N);
+        pub fn new_from_arr<const N: usize>(arr: Vector2arr<N, T>) -> Vector2box<T> {
+                let mut new = Vector2box::new_uninit_box(N);
                 let new = unsafe {
 			for i in 0..N {
                         	new[i].as_mut_ptr().write(arr[i]);
                 	}
 			new.assume_init()
 		};
-                Vec2box(new)
+                Vector2box(new)
         }
 
-        pub fn new_from_box(arr: Vec2box) -> Vec2box {
-                let mut new = Vec2box::new_uninit_box(arr.len());
+        pub fn new_from_box(arr: Vector2box<T>) -> Vector2box<T> {
+                let mut new = Vector2box::new_uninit_box(arr.len());
                 let new = unsafe {
                         for i in 0..arr.len() {
                                 new[i].as_mut_ptr().write(arr[i]);
                         }
                         new.assume_init()
                 };
-                Vec2box(new)
+                Vector2box(new)
         }
 
-        pub fn new_from_win<'a>(arr : Vec2win<'a>) -> Vec2box {
-                let mut new = Vec2box::new_uninit_box(arr.len());
+        pub fn new_from_win<'a>(arr : Vector2win<'a, T>) -> Vector2box<T> {
+                let mut new = Vector2box::new_uninit_box(arr.len());
                 let new = unsafe {
                         for i in 0..arr.len() {
                                 new[i].as_mut_ptr().write(arr[i]);
                         }
                         new.assume_init()
                 };
-                Vec2box(new)
+                Vector2box(new)
         }
 
-	pub fn new_from_raw<'a>(arr : Vec2raw) -> Vec2box {
-                let mut new = Vec2box::new_uninit_box(arr.len());
+	pub fn new_from_raw(arr : Vector2raw<T>) -> Vector2box<T> {
+                let mut new = Vector2box::new_uninit_box(arr.len());
                 let new = unsafe {
                         for i in 0..arr.len() {
                                 new[i].as_mut_ptr().write(arr[i]);
                         }
                         new.assume_init()
                 };
-                Vec2box(new)
+                Vector2box(new)
         }
 
-	pub fn mag(&self) -> Box<[f32]> {
-		let mut tmp = Box::<[f32]>::new_uninit_slice(self.len());
-                let tmp = unsafe {
-                        for i in 0..self.len() {
-                        	tmp[i].as_mut_ptr().write((self[i].x*self[i].x + self[i].y*self[i].y).sqrt());
-			}
-			tmp.assume_init()
-                };
-                tmp
-        }
+	/// The determinant of each pair of vectors, which is the z component of their cross product
+	/// as 3D vectors.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vec2;
+	/// use lineq::vec2arr::{Vec2arr, Vec2box};
+	/// let a : Vec2box = Vec2box::ftoarr(|i| Vec2 { x: i as f32, y: 1.0 }, 11);
+	/// let b : Vec2box = Vec2box::ftoarr(|i| Vec2 { x: 2.0, y: 0.5 * i as f32 }, 11);
+	/// let c : Vec2arr<2> = Vec2arr([Vec2::RIGHT, Vec2::UP]);
+	/// let det : Box<[f32]> = a.detarr(b.clone());
+	/// for i in 0..11 {
+	///     assert_eq!(det[i], a[i].det(b[i]));
+	/// }
+	/// assert_eq!(a.det(Vec2::UP)[3], 3.0);
+	/// assert_eq!(c.detarr(Vec2arr([Vec2::UP, Vec2::RIGHT])), [1.0, -1.0]);
+	/// ```
+	pub fn detarr(&self, rhs : Vector2box<T>) -> Box<[T]> {
+		let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+		simd::det(&mut tmp, self, &rhs);
+		unsafe { tmp.assume_init() }
+	}
 
-        pub fn mag2(&self) -> Box<[f32]> {
-		let mut tmp = Box::<[f32]>::new_uninit_slice(self.len());
-                let tmp = unsafe {
-                        for i in 0..self.len() {
-                        	tmp[i].as_mut_ptr().write(self[i].x*self[i].x + self[i].y*self[i].y);
-			}
-			tmp.assume_init()
-                };
-                tmp
-        }
+	pub fn det(&self, rhs : Vector2<T>) -> Box<[T]> {
+		self.iter().map(|v| v.det(rhs)).collect()
+	}
 
-        pub fn norm(&self) -> Vec2box {
-		let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
-                let tmp = unsafe {
-                        for i in 0..self.len() {
-                        	let mag : f32 = (self[i].x*self[i].x + self[i].y*self[i].y).sqrt();
-                        	tmp[i].as_mut_ptr().write( Vec2 { x: self[i].x/mag, y: self[i].y/mag } );
-			}
-			tmp.assume_init()
-                };
-                Vec2box(tmp)
-        }
+	pub fn mag(&self) -> Box<[T]> {
+		let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+		simd::mag(&mut tmp, self);
+		unsafe { tmp.assume_init() }
+	}
 
-	pub fn transform<F: Fn(Vec2) -> Vec2>(&mut self, f: F) {
+	pub fn mag2(&self) -> Box<[T]> {
+		let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+		simd::mag2(&mut tmp, self);
+		unsafe { tmp.assume_init() }
+	}
+
+	pub fn norm(&self) -> Vector2box<T> {
+		let mut tmp = Vector2box::new_uninit_box(self.len());
+		simd::norm(&mut tmp, self);
+		Vector2box(unsafe { tmp.assume_init() })
+	}
+
+	pub fn transform<F: Fn(Vector2<T>) -> Vector2<T>>(&mut self, f: F) {
                 for i in 0..self.len() {
                         self[i] = f(self[i]);
                 }
         }
 
-	pub fn ftoarr<F: Fn(usize) -> Vec2>(f: F, n: usize) -> Vec2box {
-		let mut tmp = Box::<[Vec2]>::new_uninit_slice(n);
+	pub fn ftoarr<F: Fn(usize) -> Vector2<T>>(f: F, n: usize) -> Vector2box<T> {
+		let mut tmp = Box::<[Vector2<T>]>::new_uninit_slice(n);
                 let tmp = unsafe {
                         for i in 0..n {
                         	tmp[i].as_mut_ptr().write(f(i));
 			}
 			tmp.assume_init()
                 };
-                Vec2box(tmp)
+                Vector2box(tmp)
         }
+
+	pub fn new_uninit_box(len: usize) -> Box<[MaybeUninit<Vector2<T>>]> {
+		Box::<[Vector2<T>]>::new_uninit_slice(len)
+	}
+}
+
+use std::iter::FromIterator;
+
+impl<T: Scalar> FromIterator<Vector2<T>> for Vector2box<T> {
+	/// Collects vectors into a box, allocating once if the iterator knows its length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vec2;
+	/// use lineq::vec2arr::{Vec2arr, Vec2box, Vec2raw};
+	/// let mut a : Vec2box = (0..3).map(|i| Vec2 { x: i as f32, y: 0.0 }).collect();
+	/// for v in &mut a {
+	///     *v *= 2.0;
+	/// }
+	/// let b : Vec2box = a.iter().filter(|v| v.x > 1.0).copied().collect();
+	/// assert_eq!(b.len(), 2);
+	/// assert_eq!(b.into_iter().sum::<Vec2>(), Vec2 { x: 3.0, y: 0.0 }*2.0);
+	/// let c : Vec2raw = Vec2raw::new_from_arr(Vec2arr([Vec2::ONE; 2]));
+	/// assert_eq!(c.into_iter().collect::<Vec2box>(), Vec2box::ftoarr(|_| Vec2::ONE, 2));
+	/// ```
+	fn from_iter<I: IntoIterator<Item = Vector2<T>>>(iter: I) -> Vector2box<T> {
+		Vector2box(iter.into_iter().collect())
+	}
 }
 
 // Vec2win Methods
 
-impl<'a> Vec2win<'a> {
+impl<'a, T: Scalar> Vector2win<'a, T> {
 
-	pub fn transform<F: Fn(Vec2) -> Vec2>(&mut self, f: F) {
+	pub fn transform<F: Fn(Vector2<T>) -> Vector2<T>>(&mut self, f: F) {
                 for i in 0..self.len() {
                         self[i] = f(self[i]);
                 }
@@ -851,108 +835,666 @@ impl<'a> Vec2win<'a> {
 
 }
 
-//Vec2raw Methods
+// Vec2vec Methods
+
+impl<T: Scalar> Vector2vec<T> {
 
-impl Vec2raw {
+	pub fn new() -> Vector2vec<T> {
+		Vector2vec(Vec::new())
+	}
+
+	pub fn with_capacity(capacity: usize) -> Vector2vec<T> {
+		Vector2vec(Vec::with_capacity(capacity))
+	}
+
+	pub fn capacity(&self) -> usize {
+		self.0.capacity()
+	}
+
+	/// Adds a vector to the end of the array.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vec2;
+	/// use lineq::vec2arr::{Vec2box, Vec2vec};
+	/// let mut a : Vec2vec = Vec2vec::new();
+	/// for i in 0..4 {
+	///     a.push(Vec2 { x: i as f32, y: 0.0 });
+	/// }
+	/// a.extend(Vec2box::new_zeroed(2).iter());
+	/// a.truncate(5);
+	/// a += 1.0;
+	/// assert_eq!(a.len(), 5);
+	/// assert_eq!(a[4], Vec2::ONE);
+	/// let b : Vec2vec = (0..5).map(|i| Vec2 { x: i as f32, y: 0.0 }).collect();
+	/// assert_eq!((&a - &b)[..4], [Vec2::ONE; 4]);
+	/// ```
+	pub fn push(&mut self, v: Vector2<T>) {
+		self.0.push(v)
+	}
+
+	pub fn pop(&mut self) -> Option<Vector2<T>> {
+		self.0.pop()
+	}
+
+	/// Shortens the array to `len` vectors, doing nothing if it is already that short.
+	pub fn truncate(&mut self, len: usize) {
+		self.0.truncate(len)
+	}
+
+	/// Makes room for at least `additional` more vectors before the next allocation.
+	pub fn reserve(&mut self, additional: usize) {
+		self.0.reserve(additional)
+	}
+
+	pub fn clear(&mut self) {
+		self.0.clear()
+	}
+
+	/// Turns the array into a [Vec2box] without copying, unless it has spare capacity to give back.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vec2;
+	/// use lineq::vec2arr::{Vec2box, Vec2vec, Vec2win};
+	/// let mut a : Vec2vec = Vec2vec::with_capacity(3);
+	/// a.extend([Vec2::ONE; 3]);
+	/// let mut win : Vec2win = a.as_win();
+	/// win *= 2.0;
+	/// let b : Vec2box = a.into_box();
+	/// assert_eq!(b, Vec2box::ftoarr(|_| Vec2::ONE*2.0, 3));
+	/// assert_eq!(Vec2vec::from(b).capacity(), 3);
+	/// ```
+	pub fn into_box(self) -> Vector2box<T> {
+		Vector2box(self.0.into_boxed_slice())
+	}
+
+	/// Borrows the vectors as a [Vec2win].
+	pub fn as_win(&mut self) -> Vector2win<'_, T> {
+		Vector2win(&mut self.0[..])
+	}
+
+	pub fn transform<F: Fn(Vector2<T>) -> Vector2<T>>(&mut self, f: F) {
+		for v in self.iter_mut() {
+			*v = f(*v);
+		}
+	}
+}
+
+impl<T: Scalar> Default for Vector2vec<T> {
+	fn default() -> Vector2vec<T> {
+		Vector2vec::new()
+	}
+}
+
+impl<T: Scalar> From<Vector2box<T>> for Vector2vec<T> {
+	fn from(arr: Vector2box<T>) -> Vector2vec<T> {
+		Vector2vec(arr.0.into_vec())
+	}
+}
+
+impl<T: Scalar> From<Vector2vec<T>> for Vector2box<T> {
+	fn from(arr: Vector2vec<T>) -> Vector2box<T> {
+		arr.into_box()
+	}
+}
+
+impl<T: Scalar> From<Vec<Vector2<T>>> for Vector2vec<T> {
+	fn from(v: Vec<Vector2<T>>) -> Vector2vec<T> {
+		Vector2vec(v)
+	}
+}
+
+impl<T: Scalar> FromIterator<Vector2<T>> for Vector2vec<T> {
+	fn from_iter<I: IntoIterator<Item = Vector2<T>>>(iter: I) -> Vector2vec<T> {
+		Vector2vec(iter.into_iter().collect())
+	}
+}
+
+impl<T: Scalar> Extend<Vector2<T>> for Vector2vec<T> {
+	fn extend<I: IntoIterator<Item = Vector2<T>>>(&mut self, iter: I) {
+		self.0.extend(iter)
+	}
+}
+
+impl<'a, T: Scalar> Extend<&'a Vector2<T>> for Vector2vec<T> {
+	fn extend<I: IntoIterator<Item = &'a Vector2<T>>>(&mut self, iter: I) {
+		self.0.extend(iter)
+	}
+}
+
+// Scalar Views
+
+impl<const N: usize, T: Scalar> Vector2arr<N, T> {
+
+	/// The components of the vectors in order, as x, y, x, y ...
+	pub fn as_scalar_slice(&self) -> &[T] {
+		simd::flat(&self.0)
+	}
+
+	pub fn as_scalar_slice_mut(&mut self) -> &mut [T] {
+		simd::flat_mut(&mut self.0)
+	}
+
+	/// Copies vectors out of their components, panicking if the slice is not 2N long.
+	pub fn from_scalar_slice(s : &[T]) -> Vector2arr<N, T> {
+		if s.len() != 2*N { panic!("slice and array inequal length"); }
+		let v : &[Vector2<T>] = simd::unflat(s);
+		Vector2arr::ftoarr(|i| v[i])
+	}
+}
+
+impl<T: Scalar> Vector2box<T> {
+
+	/// The components of the vectors in order, as x, y, x, y ...
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vector2;
+	/// use lineq::vec2arr::{Vector2box, Vector2win};
+	/// let a : Vector2box<f64> = Vector2box::ftoarr(|i| Vector2 { x: i as f64, y: 1.0 }, 2);
+	/// assert_eq!(a.as_scalar_slice(), &[0.0, 1.0, 1.0, 1.0]);
+	/// assert_eq!(Vector2box::from_scalar_slice(a.as_scalar_slice()), a);
+	/// let mut floats : [f64; 4] = [1.0, 0.0, 0.0, 1.0];
+	/// let mut win : Vector2win<f64> = Vector2win::from_scalar_slice(&mut floats);
+	/// win *= 2.0;
+	/// assert_eq!(floats, [2.0, 0.0, 0.0, 2.0]);
+	/// ```
+	pub fn as_scalar_slice(&self) -> &[T] {
+		simd::flat(&self.0)
+	}
+
+	pub fn as_scalar_slice_mut(&mut self) -> &mut [T] {
+		simd::flat_mut(&mut self.0)
+	}
+
+	/// Copies vectors out of their components, panicking if the length is not a multiple of 2.
+	pub fn from_scalar_slice(s : &[T]) -> Vector2box<T> {
+		Vector2box(simd::unflat(s).into())
+	}
+}
+
+impl<'a, T: Scalar> Vector2win<'a, T> {
+
+	/// The components of the vectors in order, as x, y, x, y ...
+	pub fn as_scalar_slice(&self) -> &[T] {
+		simd::flat(self.0)
+	}
+
+	pub fn as_scalar_slice_mut(&mut self) -> &mut [T] {
+		simd::flat_mut(self.0)
+	}
+
+	/// Borrows components as vectors without copying, panicking if the length is not a multiple of 2.
+	pub fn from_scalar_slice(s : &'a mut [T]) -> Vector2win<'a, T> {
+		Vector2win(simd::unflat_mut(s))
+	}
+}
+
+// f32 Views
+
+impl<const N: usize> Vector2arr<N, f32> {
+
+	/// The same as [as_scalar_slice](Vec2arr::as_scalar_slice).
+	pub fn as_f32_slice(&self) -> &[f32] {
+		self.as_scalar_slice()
+	}
+
+	/// The same as [as_scalar_slice_mut](Vec2arr::as_scalar_slice_mut).
+	pub fn as_f32_slice_mut(&mut self) -> &mut [f32] {
+		self.as_scalar_slice_mut()
+	}
+
+	/// The same as [from_scalar_slice](Vec2arr::from_scalar_slice).
+	pub fn from_f32_slice(s : &[f32]) -> Vector2arr<N, f32> {
+		Vector2arr::from_scalar_slice(s)
+	}
+}
+
+impl Vector2box<f32> {
+
+	/// The same as [as_scalar_slice](Vec2box::as_scalar_slice).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vec2;
+	/// use lineq::vec2arr::Vec2box;
+	/// let a : Vec2box = Vec2box::ftoarr(|i| Vec2 { x: i as f32, y: 1.0 }, 2);
+	/// assert_eq!(a.as_f32_slice(), &[0.0, 1.0, 1.0, 1.0]);
+	/// assert_eq!(Vec2box::from_f32_slice(a.as_f32_slice()), a);
+	/// ```
+	pub fn as_f32_slice(&self) -> &[f32] {
+		self.as_scalar_slice()
+	}
+
+	/// The same as [as_scalar_slice_mut](Vec2box::as_scalar_slice_mut).
+	pub fn as_f32_slice_mut(&mut self) -> &mut [f32] {
+		self.as_scalar_slice_mut()
+	}
+
+	/// The same as [from_scalar_slice](Vec2box::from_scalar_slice).
+	pub fn from_f32_slice(s : &[f32]) -> Vector2box<f32> {
+		Vector2box::from_scalar_slice(s)
+	}
+}
+
+impl<'a> Vector2win<'a, f32> {
+
+	/// The same as [as_scalar_slice](Vec2win::as_scalar_slice).
+	pub fn as_f32_slice(&self) -> &[f32] {
+		self.as_scalar_slice()
+	}
+
+	/// The same as [as_scalar_slice_mut](Vec2win::as_scalar_slice_mut).
+	pub fn as_f32_slice_mut(&mut self) -> &mut [f32] {
+		self.as_scalar_slice_mut()
+	}
+
+	/// The same as [from_scalar_slice](Vec2win::from_scalar_slice).
+	pub fn from_f32_slice(s : &'a mut [f32]) -> Vector2win<'a, f32> {
+		Vector2win::from_scalar_slice(s)
+	}
+}
+
+// Vec2raw Methods
+
+impl<T: Scalar> Vector2raw<T> {
 
 	fn layout(len: usize) -> Layout {
-		Layout::from_size_align(Layout::new::<Vec2>().size()*len,Layout::new::<Vec2>().align()).unwrap()
+		Layout::array::<Vector2<T>>(len).unwrap()
 	}
 
-	pub unsafe fn new_uninit_ptr(len: usize) -> *mut [MaybeUninit<Vec2>] {
-		let ptr = alloc(Vec2raw::layout(len)) as *mut MaybeUninit<Vec2>;
+	/// Allocates space for `len` vectors with the same layout a `Vec2raw` frees with.
+	/// An empty slice gets a dangling pointer rather than an allocation.
+	///
+	/// # Safety
+	///
+	/// The vectors are uninitialized, and the memory is leaked unless it is passed to
+	/// [from_raw](Vec2raw::from_raw) once initialized.
+	pub unsafe fn new_uninit_ptr(len: usize) -> *mut [MaybeUninit<Vector2<T>>] {
+		if len == 0 { return slice_from_raw_parts_mut(NonNull::dangling().as_ptr(), 0); }
+		let ptr = alloc(Vector2raw::<T>::layout(len)) as *mut MaybeUninit<Vector2<T>>;
+		if ptr.is_null() { handle_alloc_error(Vector2raw::<T>::layout(len)); }
 		slice_from_raw_parts_mut(ptr, len)
 	}
 
-	pub fn from_raw_parts(ptr: *mut Vec2, len: usize) -> Vec2raw {
-		Vec2raw(slice_from_raw_parts_mut(ptr, len))
+	/// Takes ownership of `len` vectors starting at `ptr`, see [from_raw](Vec2raw::from_raw).
+	///
+	/// # Safety
+	///
+	/// The same as [from_raw](Vec2raw::from_raw).
+	pub unsafe fn from_raw_parts(ptr: *mut Vector2<T>, len: usize) -> Vector2raw<T> {
+		Vector2raw(slice_from_raw_parts_mut(ptr, len))
 	}
 
-	pub fn new_zeroed(len: usize) -> Vec2raw {
-		let ptr = unsafe { Vec2raw::new_uninit_ptr(len) };
+	/// Takes ownership of a pointer, which is freed when the `Vec2raw` is dropped.
+	///
+	/// # Safety
+	///
+	/// The pointer must have come from [into_raw](Vec2raw::into_raw) or
+	/// [new_uninit_ptr](Vec2raw::new_uninit_ptr) with every vector initialized, and must not
+	/// be used again except through the returned `Vec2raw`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2::Vec2;
+	/// use lineq::vec2arr::Vec2raw;
+	/// let a : Vec2raw = Vec2raw::new_zeroed(4);
+	/// let ptr = a.into_raw();
+	/// // the vectors are still allocated and can be handed to other code here
+	/// let b : Vec2raw = unsafe { Vec2raw::from_raw(ptr) };
+	/// assert_eq!(b.len(), 4);
+	/// assert_eq!(b[3], Vec2::ZERO);
+	/// ```
+	pub unsafe fn from_raw(ptr: *mut [Vector2<T>]) -> Vector2raw<T> {
+		Vector2raw(ptr)
+	}
+
+	/// Gives up ownership of the vectors without freeing them, returning the pointer to them.
+	pub fn into_raw(self) -> *mut [Vector2<T>] {
+		let ptr = self.0;
+		std::mem::forget(self);
+		ptr
+	}
+
+	/// Gives up ownership of the vectors without freeing them, returning a reference
+	/// that lives for the rest of the program.
+	pub fn leak(self) -> &'static mut [Vector2<T>] {
+		unsafe { &mut *self.into_raw() }
+	}
+
+	pub fn new_zeroed(len: usize) -> Vector2raw<T> {
+		let ptr = unsafe { Vector2raw::<T>::new_uninit_ptr(len) };
 		for i in 0..len {
-			unsafe { (*ptr.as_mut_ptr().add(i)).write(Vec2::ZERO) };
+			unsafe { (*ptr.as_mut_ptr().add(i)).write(Vector2::ZERO) };
 		}
-		Vec2raw(ptr as *mut [Vec2])
+		Vector2raw(ptr as *mut [Vector2<T>])
 	}
 
-	pub fn new_from_arr<const N:usize>(arr: Vec2arr<N>) -> Vec2raw {
-		let ptr = unsafe { Vec2raw::new_uninit_ptr(N) };
+	pub fn new_from_arr<const N:usize>(arr: Vector2arr<N, T>) -> Vector2raw<T> {
+		let ptr = unsafe { Vector2raw::<T>::new_uninit_ptr(N) };
                 for i in 0..N {
 			unsafe { (*ptr.as_mut_ptr().add(i)).write(arr[i]) };
                 }
-                Vec2raw(ptr as *mut [Vec2])
+                Vector2raw(ptr as *mut [Vector2<T>])
 	}
 
-	pub fn new_from_box(arr: Vec2box) -> Vec2raw {
-		let ptr = unsafe { Vec2raw::new_uninit_ptr(arr.len()) };
+	pub fn new_from_box(arr: Vector2box<T>) -> Vector2raw<T> {
+		let ptr = unsafe { Vector2raw::<T>::new_uninit_ptr(arr.len()) };
                 for i in 0..arr.len() {
                         unsafe { (*ptr.as_mut_ptr().add(i)).write(arr[i]) };
                 }
-                Vec2raw(ptr as *mut [Vec2])
+                Vector2raw(ptr as *mut [Vector2<T>])
 	}
 
-	pub fn new_from_win<'a>(arr: Vec2win<'a>) -> Vec2raw {
-		let ptr = unsafe { Vec2raw::new_uninit_ptr(arr.len()) };
+	pub fn new_from_win<'a>(arr : Vector2win<'a, T>) -> Vector2raw<T> {
+		let ptr = unsafe { Vector2raw::<T>::new_uninit_ptr(arr.len()) };
                 for i in 0..arr.len() {
                         unsafe { (*ptr.as_mut_ptr().add(i)).write(arr[i]) };
                 }
-                Vec2raw(ptr as *mut [Vec2])
+                Vector2raw(ptr as *mut [Vector2<T>])
 	}
 
-	pub fn new_from_raw<'a>(arr : Vec2raw) -> Vec2raw {
-		let ptr = unsafe { Vec2raw::new_uninit_ptr(arr.len()) };
+	pub fn new_from_raw(arr : Vector2raw<T>) -> Vector2raw<T> {
+		let ptr = unsafe { Vector2raw::<T>::new_uninit_ptr(arr.len()) };
                 for i in 0..arr.len() {
                         unsafe { (*ptr.as_mut_ptr().add(i)).write(arr[i]) };
                 }
-                Vec2raw(ptr as *mut [Vec2])
+                Vector2raw(ptr as *mut [Vector2<T>])
 	}
 
-	pub fn from_arr<const N: usize>(&mut self, arr: Vec2arr<N>) {
+	pub fn from_arr<const N: usize>(&mut self, arr: Vector2arr<N, T>) {
 		if self.len() != arr.len() { panic!("slice and array inequal length"); }
 		for i in 0..self.len() {
 			self[i] = arr[i];
 		}
 	}
 
-	pub fn from_box(&mut self, arr: Vec2box) {
+	pub fn from_box(&mut self, arr: Vector2box<T>) {
 		if self.len() != arr.len() { panic!("slices inequal length"); }
 		for i in 0..self.len() {
                         self[i] = arr[i];
                 }
 	}
 
-	pub fn from_win<'a>(&mut self, arr : Vec2win<'a>) {
+	pub fn from_win<'a>(&mut self, arr : Vector2win<'a, T>) {
 		if self.len() != arr.len() { panic!("slices inequal length"); }
 		for i in 0..self.len() {
                         self[i] = arr[i];
                 }
 	}
 
-	pub fn to_arr<const N: usize>(&self) -> Vec2arr<N> {
+	pub fn to_arr<const N: usize>(&self) -> Vector2arr<N, T> {
 		if self.len() != N { panic!("slice and array inequal length"); }
-		let mut tmp: Vec2arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-		for i in 0..N {
-                        tmp[i] = self[i];
-                }
-                unsafe { std::mem::transmute::<_, Vec2arr<N>>(tmp) }
+		Vector2arr::ftoarr(|i| self[i])
 	}
 
-	pub fn to_box(&self) -> Vec2box {
-		let mut tmp = Box::<[Vec2]>::new_uninit_slice(self.len());
+	pub fn to_box(&self) -> Vector2box<T> {
+		let mut tmp = Box::<[Vector2<T>]>::new_uninit_slice(self.len());
 		let tmp = unsafe {
                         for i in 0..self.len() {
                                 tmp[i].as_mut_ptr().write(self[i]);
                         }
                         tmp.assume_init()
                 };
-		Vec2box(tmp)
+		Vector2box(tmp)
+	}
+
+	/// Copies from a [Vec2arr], failing instead of panicking if the lengths differ.
+	pub fn try_from_arr<const N: usize>(&mut self, arr: Vector2arr<N, T>) -> Result<(), LineqError> {
+		check_len(self.len(), N)?;
+		self.from_arr(arr);
+		Ok(())
 	}
 
-	pub fn transform<F: Fn(Vec2) -> Vec2>(&mut self, f: F) {
+	/// Copies from a [Vec2box], failing instead of panicking if the lengths differ.
+	pub fn try_from_box(&mut self, arr: Vector2box<T>) -> Result<(), LineqError> {
+		check_len(self.len(), arr.len())?;
+		self.from_box(arr);
+		Ok(())
+	}
+
+	/// Copies from a [Vec2win], failing instead of panicking if the lengths differ.
+	pub fn try_from_win<'a>(&mut self, arr : Vector2win<'a, T>) -> Result<(), LineqError> {
+		check_len(self.len(), arr.len())?;
+		self.from_win(arr);
+		Ok(())
+	}
+
+	/// Copies into a [Vec2arr], failing instead of panicking if the lengths differ.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec2arr::Vec2raw;
+	/// use lineq::error::LineqError;
+	/// let a : Vec2raw = Vec2raw::new_zeroed(3);
+	/// assert!(a.try_to_arr::<3>().is_ok());
+	/// assert_eq!(a.try_to_arr::<4>(), Err(LineqError::LengthMismatch { expected: 4, found: 3 }));
+	/// ```
+	pub fn try_to_arr<const N: usize>(&self) -> Result<Vector2arr<N, T>, LineqError> {
+		check_len(N, self.len())?;
+		Ok(self.to_arr())
+	}
+
+	pub fn transform<F: Fn(Vector2<T>) -> Vector2<T>>(&mut self, f: F) {
                 for i in 0..self.len() {
                         self[i] = f(self[i]);
                 }
         }
 
 }
+
+// Vec2Array
+use std::convert::TryFrom;
+
+/// The methods shared by [Vec2arr], [Vec2box], [Vec2vec], [Vec2win] and [Vec2raw], for code that
+/// works with any of them.
+///
+/// Results come back the same way as from the operators, so a [Vec2arr] returns arrays of the
+/// same length and a [Vec2vec] returns another [Vec2vec], while the rest allocate boxes. Arrays
+/// given as `rhs` are taken as slices, and have to be the same length as `self`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec2::Vec2;
+/// use lineq::vec2arr::{Vec2arr, Vec2box, Vec2win, Vec2raw, Vec2Array};
+/// fn longest<A: Vec2Array>(a : &A) -> Vec2 {
+///     let mag = a.mag();
+///     let i : usize = (0..a.len()).fold(0, |j, i| if mag.as_ref()[i] > mag.as_ref()[j] { i } else { j });
+///     a.as_slice()[i]
+/// }
+/// let mut a : Vec2arr<3> = Vec2arr([Vec2::UP, Vec2::ONE, Vec2::LEFT]);
+/// let mut b : Vec2box = a.to_box();
+/// let c : Vec2raw = a.to_raw();
+/// assert_eq!(longest(&a), Vec2::ONE);
+/// assert_eq!(longest(&b), Vec2::ONE);
+/// assert_eq!(longest(&Vec2win(&mut b[1..])), Vec2::ONE);
+/// assert_eq!(longest(&c), Vec2::ONE);
+/// Vec2Array::transform(&mut a, |v| v*2.0);
+/// assert_eq!(a.zip_map(&c, |u, v| u - v*2.0), Vec2arr([Vec2::ZERO; 3]));
+/// assert_eq!(a.dot(&c), [2.0, 4.0, 2.0]);
+/// assert_eq!(&*Vec2Array::detarr(&b, &*a), &[0.0, 0.0, 0.0]);
+/// assert_eq!(c.to_arr::<3>(), Vec2arr([Vec2::UP, Vec2::ONE, Vec2::LEFT]));
+/// ```
+pub trait Vec2Array<T: Scalar = f32> {
+	/// The array that vectors are returned in, a [Vec2arr] for [Vec2arr], a [Vec2vec]
+	/// for [Vec2vec] and a [Vec2box] for the rest.
+	type Vecs : Vec2Array<T>;
+	/// The array that scalars are returned in, `[T; N]` for [Vec2arr] and `Box<[T]>` for the rest.
+	type Scalars : AsRef<[T]> + AsMut<[T]>;
+
+	fn as_slice(&self) -> &[Vector2<T>];
+
+	fn as_mut_slice(&mut self) -> &mut [Vector2<T>];
+
+	fn mag(&self) -> Self::Scalars;
+
+	fn mag2(&self) -> Self::Scalars;
+
+	fn norm(&self) -> Self::Vecs;
+
+	fn det(&self, rhs : Vector2<T>) -> Self::Scalars;
+
+	fn detarr(&self, rhs : &[Vector2<T>]) -> Self::Scalars;
+
+	fn dot(&self, rhs : &[Vector2<T>]) -> Self::Scalars;
+
+	/// Applies `f` to each vector and the one at the same index in `rhs`.
+	fn zip_map<F: Fn(Vector2<T>, Vector2<T>) -> Vector2<T>>(&self, rhs : &[Vector2<T>], f : F) -> Self::Vecs;
+
+	fn len(&self) -> usize {
+		self.as_slice().len()
+	}
+
+	fn is_empty(&self) -> bool {
+		self.as_slice().is_empty()
+	}
+
+	fn transform<F: Fn(Vector2<T>) -> Vector2<T>>(&mut self, f: F) {
+		for v in self.as_mut_slice().iter_mut() {
+			*v = f(*v);
+		}
+	}
+
+	fn to_arr<const N: usize>(&self) -> Vector2arr<N, T> {
+		match <[Vector2<T>; N]>::try_from(self.as_slice()) {
+			Ok(arr) => Vector2arr(arr),
+			Err(_) => panic!("slice and array inequal length"),
+		}
+	}
+
+	/// Copies into a [Vec2arr], failing instead of panicking if the lengths differ.
+	fn try_to_arr<const N: usize>(&self) -> Result<Vector2arr<N, T>, LineqError> {
+		check_len(N, self.len())?;
+		Ok(self.to_arr())
+	}
+
+	fn to_box(&self) -> Vector2box<T> {
+		Vector2box(self.as_slice().into())
+	}
+
+	fn to_raw(&self) -> Vector2raw<T> {
+		let mut raw : Vector2raw<T> = Vector2raw::new_zeroed(self.len());
+		raw.copy_from_slice(self.as_slice());
+		raw
+	}
+}
+
+impl<const N: usize, T: Scalar> Vec2Array<T> for Vector2arr<N, T> {
+	type Vecs = Vector2arr<N, T>;
+	type Scalars = [T; N];
+
+	fn as_slice(&self) -> &[Vector2<T>] {
+		&self[..]
+	}
+
+	fn as_mut_slice(&mut self) -> &mut [Vector2<T>] {
+		&mut self[..]
+	}
+
+	fn mag(&self) -> [T; N] {
+		Vector2arr::mag(self)
+	}
+
+	fn mag2(&self) -> [T; N] {
+		Vector2arr::mag2(self)
+	}
+
+	fn norm(&self) -> Vector2arr<N, T> {
+		Vector2arr::norm(self)
+	}
+
+	fn det(&self, rhs : Vector2<T>) -> [T; N] {
+		Vector2arr::det(self, rhs)
+	}
+
+	fn detarr(&self, rhs : &[Vector2<T>]) -> [T; N] {
+		if rhs.len() != N { panic!("slice and array inequal length"); }
+		std::array::from_fn(|i| self[i].det(rhs[i]))
+	}
+
+	fn dot(&self, rhs : &[Vector2<T>]) -> [T; N] {
+		if rhs.len() != N { panic!("slice and array inequal length"); }
+		std::array::from_fn(|i| self[i]*rhs[i])
+	}
+
+	fn zip_map<F: Fn(Vector2<T>, Vector2<T>) -> Vector2<T>>(&self, rhs : &[Vector2<T>], f : F) -> Vector2arr<N, T> {
+		if rhs.len() != N { panic!("slice and array inequal length"); }
+		Vector2arr::ftoarr(|i| f(self[i], rhs[i]))
+	}
+}
+
+macro_rules! array_impl {
+        (Array $t:ty; out: $out:ident$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?T: Scalar> Vec2Array<T> for $t {
+                        type Vecs = $out<T>;
+                        type Scalars = Box<[T]>;
+
+                        fn as_slice(&self) -> &[Vector2<T>] {
+                                &self[..]
+                        }
+
+                        fn as_mut_slice(&mut self) -> &mut [Vector2<T>] {
+                                &mut self[..]
+                        }
+
+                        fn mag(&self) -> Box<[T]> {
+                                let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+                                simd::mag(&mut tmp, self);
+                                unsafe { tmp.assume_init() }
+                        }
+
+                        fn mag2(&self) -> Box<[T]> {
+                                let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+                                simd::mag2(&mut tmp, self);
+                                unsafe { tmp.assume_init() }
+                        }
+
+                        fn norm(&self) -> $out<T> {
+                                let mut tmp = Vector2box::new_uninit_box(self.len());
+                                simd::norm(&mut tmp, self);
+                                Vector2box(unsafe { tmp.assume_init() }).into()
+                        }
+
+                        fn det(&self, rhs : Vector2<T>) -> Box<[T]> {
+                                self.iter().map(|v| v.det(rhs)).collect()
+                        }
+
+                        fn detarr(&self, rhs : &[Vector2<T>]) -> Box<[T]> {
+                                let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+                                simd::det(&mut tmp, self, rhs);
+                                unsafe { tmp.assume_init() }
+                        }
+
+                        fn dot(&self, rhs : &[Vector2<T>]) -> Box<[T]> {
+                                let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+                                simd::dot(&mut tmp, self, rhs);
+                                unsafe { tmp.assume_init() }
+                        }
+
+                        fn zip_map<F: Fn(Vector2<T>, Vector2<T>) -> Vector2<T>>(&self, rhs : &[Vector2<T>], f : F) -> $out<T> {
+                                if self.len() != rhs.len() { panic!("slices inequal length"); }
+                                Vector2box::ftoarr(|i| f(self[i], rhs[i]), self.len()).into()
+                        }
+                }
+        };
+}
+
+array_impl! {Array Vector2box<T>; out: Vector2box}
+array_impl! {Array Vector2vec<T>; out: Vector2vec}
+array_impl! {Array Vector2win<'a, T>; out: Vector2box; <'a>}
+array_impl! {Array Vector2raw<T>; out: Vector2box}
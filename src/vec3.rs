@@ -1,43 +1,79 @@
+use crate::scalar::Scalar;
+use crate::error::LineqError;
+
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Vec3 {
-        pub x : f32,
-        pub y : f32,
-        pub z : f32,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Vector3<T> {
+        pub x : T,
+        pub y : T,
+        pub z : T,
 }
 
+/// A [Vec3] of [f32]s.
+pub type Vec3 = Vector3<f32>;
+
 macro_rules! value_impl {
         ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty) => {
-                impl $imp<$rhs> for $lhs {
-                        type Output = Vec3;
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector3<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector3<T> {
+				Vector3 { x: self.x $op rhs.x, y: self.y $op rhs.y, z: self.z $op rhs.z }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for &$lhs:ident) => {
+                impl $imp<$rhs> for &$lhs {
+                        type Output = Vector3<$lhs>;
                         #[inline]
-                        fn $func(self, rhs: $rhs) -> Vec3 {
-				Vec3 { x: self.x $op rhs.x, y: self.y $op rhs.y, z: self.z $op rhs.z }
+                        fn $func(self, rhs: $rhs) -> Vector3<$lhs> {
+                                Vector3 { x: *self $op rhs.x, y: *self $op rhs.y, z: *self $op rhs.z }
                         }
                 }
         };
-        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty) => {
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ident) => {
                 impl $imp<$rhs> for $lhs {
-                        type Output = Vec3;
+                        type Output = Vector3<$lhs>;
                         #[inline]
-                        fn $func(self, rhs: $rhs) -> Vec3 {
-                                Vec3 { x: self $op rhs.x, y: self $op rhs.y, z: self $op rhs.z }
+                        fn $func(self, rhs: $rhs) -> Vector3<$lhs> {
+                                Vector3 { x: self $op rhs.x, y: self $op rhs.y, z: self $op rhs.z }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 &$rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<&$rhs> for $lhs {
+                        type Output = Vector3<T>;
+                        #[inline]
+                        fn $func(self, rhs: &$rhs) -> Vector3<T> {
+                                Vector3 { x: self.x $op *rhs, y: self.y $op *rhs, z: self.z $op *rhs }
                         }
                 }
         };
         ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty) => {
-                impl $imp<$rhs> for $lhs {
-                        type Output = Vec3;
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector3<T>;
                         #[inline]
-                        fn $func(self, rhs: $rhs) -> Vec3 {
-                                Vec3 { x: self.x $op rhs, y: self.y $op rhs, z: self.z $op rhs }
+                        fn $func(self, rhs: $rhs) -> Vector3<T> {
+                                Vector3 { x: self.x $op rhs, y: self.y $op rhs, z: self.z $op rhs }
                         }
                 }
         };
 }
 
 macro_rules! inplace_impl {
+        ($imp:ident;$func:ident;$op:tt; 0 &$rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<&$rhs> for $lhs {
+                        #[inline]
+                        fn $func(&mut self, rhs: &$rhs) {
+                                self.x $op *rhs;
+                                self.y $op *rhs;
+				self.z $op *rhs;
+                        }
+                }
+        };
         ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty) => {
-                impl $imp<$rhs> for $lhs {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
                         #[inline]
                         fn $func(&mut self, rhs: $rhs) {
                                 self.x $op rhs;
@@ -47,7 +83,7 @@ macro_rules! inplace_impl {
                 }
         };
         ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty) => {
-                impl $imp<$rhs> for $lhs {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
                         #[inline]
                         fn $func(&mut self, rhs: $rhs) {
                                 self.x $op rhs.x;
@@ -60,10 +96,10 @@ macro_rules! inplace_impl {
 
 macro_rules! dot_impl {
         (Dot $rhs:ty; for $lhs:ty) => {
-                impl Mul<$rhs> for $lhs {
-                        type Output = f32;
+                impl<T: Scalar> Mul<$rhs> for $lhs {
+                        type Output = T;
                         #[inline]
-                        fn mul(self, rhs: $rhs) -> f32 {
+                        fn mul(self, rhs: $rhs) -> T {
 				self.x*rhs.x+self.y*rhs.y+self.z*rhs.z
                         }
                 }
@@ -73,31 +109,35 @@ macro_rules! dot_impl {
 //Add
 use std::ops::Add;
 
-value_impl! {Add;add;+; 1 f32; for Vec3}
-value_impl! {Add;add;+; 2 Vec3; for f32}
-value_impl! {Add;add;+; 3 Vec3; for Vec3}
-value_impl! {Add;add;+; 1 &f32; for Vec3}
-value_impl! {Add;add;+; 2 &Vec3; for f32}
-value_impl! {Add;add;+; 3 &Vec3; for Vec3}
-value_impl! {Add;add;+; 1 f32; for &Vec3}
-value_impl! {Add;add;+; 2 Vec3; for &f32}
-value_impl! {Add;add;+; 3 Vec3; for &Vec3}
-value_impl! {Add;add;+; 1 &f32; for &Vec3}
-value_impl! {Add;add;+; 2 &Vec3; for &f32}
-value_impl! {Add;add;+; 3 &Vec3; for &Vec3}
+value_impl! {Add;add;+; 1 T; for Vector3<T>}
+value_impl! {Add;add;+; 2 Vector3<f32>; for f32}
+value_impl! {Add;add;+; 2 Vector3<f64>; for f64}
+value_impl! {Add;add;+; 3 Vector3<T>; for Vector3<T>}
+value_impl! {Add;add;+; 1 &T; for Vector3<T>}
+value_impl! {Add;add;+; 2 &Vector3<f32>; for f32}
+value_impl! {Add;add;+; 2 &Vector3<f64>; for f64}
+value_impl! {Add;add;+; 3 &Vector3<T>; for Vector3<T>}
+value_impl! {Add;add;+; 1 T; for &Vector3<T>}
+value_impl! {Add;add;+; 2 Vector3<f32>; for &f32}
+value_impl! {Add;add;+; 2 Vector3<f64>; for &f64}
+value_impl! {Add;add;+; 3 Vector3<T>; for &Vector3<T>}
+value_impl! {Add;add;+; 1 &T; for &Vector3<T>}
+value_impl! {Add;add;+; 2 &Vector3<f32>; for &f32}
+value_impl! {Add;add;+; 2 &Vector3<f64>; for &f64}
+value_impl! {Add;add;+; 3 &Vector3<T>; for &Vector3<T>}
 
 //AddAssign
 use std::ops::AddAssign;
 
-inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec3}
-inplace_impl! {AddAssign;add_assign;+=; 1 Vec3; for Vec3}
-inplace_impl! {AddAssign;add_assign;+=; 0 &f32; for Vec3}
-inplace_impl! {AddAssign;add_assign;+=; 1 &Vec3; for Vec3}
+inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector3<T>}
+inplace_impl! {AddAssign;add_assign;+=; 1 Vector3<T>; for Vector3<T>}
+inplace_impl! {AddAssign;add_assign;+=; 0 &T; for Vector3<T>}
+inplace_impl! {AddAssign;add_assign;+=; 1 &Vector3<T>; for Vector3<T>}
 
 //Display
 use std::fmt;
 
-impl fmt::Display for Vec3 {
+impl<T: Scalar> fmt::Display for Vector3<T> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "<{}, {}, {}>", self.x, self.y, self.z)
         }
@@ -106,106 +146,132 @@ impl fmt::Display for Vec3 {
 //Div
 use std::ops::Div;
 
-value_impl! {Div;div;/; 1 f32; for Vec3}
-value_impl! {Div;div;/; 1 &f32; for Vec3}
-value_impl! {Div;div;/; 1 f32; for &Vec3}
-value_impl! {Div;div;/; 1 &f32; for &Vec3}
+value_impl! {Div;div;/; 1 T; for Vector3<T>}
+value_impl! {Div;div;/; 1 &T; for Vector3<T>}
+value_impl! {Div;div;/; 1 T; for &Vector3<T>}
+value_impl! {Div;div;/; 1 &T; for &Vector3<T>}
 
 //DivAssign
 use std::ops::DivAssign;
 
-inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec3}
-inplace_impl! {DivAssign;div_assign;/=; 0 &f32; for Vec3}
+inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector3<T>}
+inplace_impl! {DivAssign;div_assign;/=; 0 &T; for Vector3<T>}
 
 //Mult
 use std::ops::Mul;
 
-value_impl! {Mul;mul;*; 1 f32; for Vec3}
-value_impl! {Mul;mul;*; 2 Vec3; for f32}
-dot_impl! {Dot Vec3; for Vec3}
-value_impl! {Mul;mul;*; 1 &f32; for Vec3}
-value_impl! {Mul;mul;*; 2 &Vec3; for f32}
-dot_impl! {Dot &Vec3; for Vec3}
-value_impl! {Mul;mul;*; 1 f32; for &Vec3}
-value_impl! {Mul;mul;*; 2 Vec3; for &f32}
-dot_impl! {Dot Vec3; for &Vec3}
-value_impl! {Mul;mul;*; 1 &f32; for &Vec3}
-value_impl! {Mul;mul;*; 2 &Vec3; for &f32}
-dot_impl! {Dot &Vec3; for &Vec3}
+value_impl! {Mul;mul;*; 1 T; for Vector3<T>}
+value_impl! {Mul;mul;*; 2 Vector3<f32>; for f32}
+value_impl! {Mul;mul;*; 2 Vector3<f64>; for f64}
+dot_impl! {Dot Vector3<T>; for Vector3<T>}
+value_impl! {Mul;mul;*; 1 &T; for Vector3<T>}
+value_impl! {Mul;mul;*; 2 &Vector3<f32>; for f32}
+value_impl! {Mul;mul;*; 2 &Vector3<f64>; for f64}
+dot_impl! {Dot &Vector3<T>; for Vector3<T>}
+value_impl! {Mul;mul;*; 1 T; for &Vector3<T>}
+value_impl! {Mul;mul;*; 2 Vector3<f32>; for &f32}
+value_impl! {Mul;mul;*; 2 Vector3<f64>; for &f64}
+dot_impl! {Dot Vector3<T>; for &Vector3<T>}
+value_impl! {Mul;mul;*; 1 &T; for &Vector3<T>}
+value_impl! {Mul;mul;*; 2 &Vector3<f32>; for &f32}
+value_impl! {Mul;mul;*; 2 &Vector3<f64>; for &f64}
+dot_impl! {Dot &Vector3<T>; for &Vector3<T>}
 
 //MultAssign
 use std::ops::MulAssign;
 
-inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec3}
-inplace_impl! {MulAssign;mul_assign;*=; 0 &f32; for Vec3}
+inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector3<T>}
+inplace_impl! {MulAssign;mul_assign;*=; 0 &T; for Vector3<T>}
 
 //Neg
 use std::ops::Neg;
 
-impl Neg for Vec3 {
-        type Output = Vec3;
-        fn neg(self) -> Vec3 {
-                Vec3 { x: -self.x, y: -self.y, z: -self.z }
+impl<T: Scalar> Neg for Vector3<T> {
+        type Output = Vector3<T>;
+        fn neg(self) -> Vector3<T> {
+                Vector3 { x: -self.x, y: -self.y, z: -self.z }
         }
 }
 
-impl Neg for &Vec3 {
-        type Output = Vec3;
-        fn neg(self) -> Vec3 {
-                Vec3 { x: -self.x, y: -self.y, z: -self.z }
+impl<T: Scalar> Neg for &Vector3<T> {
+        type Output = Vector3<T>;
+        fn neg(self) -> Vector3<T> {
+                Vector3 { x: -self.x, y: -self.y, z: -self.z }
         }
 }
 
 //Sub
 use std::ops::Sub;
 
-value_impl! {Sub;sub;-; 1 f32; for Vec3}
-value_impl! {Sub;sub;-; 3 Vec3; for Vec3}
-value_impl! {Sub;sub;-; 1 &f32; for Vec3}
-value_impl! {Sub;sub;-; 3 &Vec3; for Vec3}
-value_impl! {Sub;sub;-; 1 f32; for &Vec3}
-value_impl! {Sub;sub;-; 3 Vec3; for &Vec3}
-value_impl! {Sub;sub;-; 1 &f32; for &Vec3}
-value_impl! {Sub;sub;-; 3 &Vec3; for &Vec3}
+value_impl! {Sub;sub;-; 1 T; for Vector3<T>}
+value_impl! {Sub;sub;-; 3 Vector3<T>; for Vector3<T>}
+value_impl! {Sub;sub;-; 1 &T; for Vector3<T>}
+value_impl! {Sub;sub;-; 3 &Vector3<T>; for Vector3<T>}
+value_impl! {Sub;sub;-; 1 T; for &Vector3<T>}
+value_impl! {Sub;sub;-; 3 Vector3<T>; for &Vector3<T>}
+value_impl! {Sub;sub;-; 1 &T; for &Vector3<T>}
+value_impl! {Sub;sub;-; 3 &Vector3<T>; for &Vector3<T>}
 
 //SubAssign
 use std::ops::SubAssign;
 
-inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec3}
-inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3; for Vec3}
-inplace_impl! {SubAssign;sub_assign;-=; 0 &f32; for Vec3}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &Vec3; for Vec3}
+inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector3<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3<T>; for Vector3<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 0 &T; for Vector3<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &Vector3<T>; for Vector3<T>}
+
+//Sum
+use std::iter::Sum;
+
+impl<T: Scalar> Sum for Vector3<T> {
+        fn sum<I: Iterator<Item = Vector3<T>>>(iter: I) -> Vector3<T> {
+                iter.fold(Vector3::ZERO, |a, b| a + b)
+        }
+}
+
+impl<'a, T: Scalar> Sum<&'a Vector3<T>> for Vector3<T> {
+        fn sum<I: Iterator<Item = &'a Vector3<T>>>(iter: I) -> Vector3<T> {
+                iter.fold(Vector3::ZERO, |a, b| a + *b)
+        }
+}
 
 //Vec3 Methods
 
-impl Vec3 {
-	pub const ZERO : Vec3 = Vec3 { x: 0.0, y: 0.0, z: 0.0 };
-        pub const ONE : Vec3 = Vec3 { x: 1.0, y: 1.0, z: 1.0 };
-        pub const LEFT : Vec3 = Vec3 { x: -1.0, y: 0.0, z: 0.0 };
-        pub const RIGHT : Vec3 = Vec3 { x: 1.0, y: 0.0, z: 0.0 };
-        pub const UP : Vec3 = Vec3 { x: 0.0, y: 1.0, z: 0.0 };
-        pub const DOWN : Vec3 = Vec3 { x: 0.0, y: -1.0, z: 0.0 };
-	pub const IN : Vec3 = Vec3 { x: 0.0, y: 0.0, z: 1.0 };
-	pub const OUT : Vec3 = Vec3 { x: 0.0, y: 0.0, z:-1.0 };
-
-	pub fn cross(&self, rhs : Vec3) -> Vec3 {
-                Vec3 { 
+impl<T: Scalar> Vector3<T> {
+	pub const ZERO : Vector3<T> = Vector3 { x: T::ZERO, y: T::ZERO, z: T::ZERO };
+        pub const ONE : Vector3<T> = Vector3 { x: T::ONE, y: T::ONE, z: T::ONE };
+        pub const LEFT : Vector3<T> = Vector3 { x: T::NEG_ONE, y: T::ZERO, z: T::ZERO };
+        pub const RIGHT : Vector3<T> = Vector3 { x: T::ONE, y: T::ZERO, z: T::ZERO };
+        pub const UP : Vector3<T> = Vector3 { x: T::ZERO, y: T::ONE, z: T::ZERO };
+        pub const DOWN : Vector3<T> = Vector3 { x: T::ZERO, y: T::NEG_ONE, z: T::ZERO };
+	pub const IN : Vector3<T> = Vector3 { x: T::ZERO, y: T::ZERO, z: T::ONE };
+	pub const OUT : Vector3<T> = Vector3 { x: T::ZERO, y: T::ZERO, z: T::NEG_ONE };
+
+	pub fn cross(&self, rhs : Vector3<T>) -> Vector3<T> {
+                Vector3 { 
                         x: self.y * rhs.z - self.z * rhs.y,
                         y: self.z * rhs.x - self.x * rhs.z,
                         z: self.x * rhs.y - self.y * rhs.x
 		}
 	}
 
-        pub fn mag(&self) -> f32 {
+        pub fn mag(&self) -> T {
                 (self.x*self.x + self.y*self.y + self.z*self.z).sqrt()
         }
 
-        pub fn mag2(&self) -> f32 {
+        pub fn mag2(&self) -> T {
                 self.x*self.x + self.y*self.y + self.z*self.z
         }
 
-        pub fn norm(&self) -> Vec3 {
-                let mag : f32 = self.mag();
-                Vec3 { x: self.x/mag, y: self.y/mag, z: self.z/mag }
+        pub fn norm(&self) -> Vector3<T> {
+                let mag : T = self.mag();
+                Vector3 { x: self.x/mag, y: self.y/mag, z: self.z/mag }
         }
+
+	/// Normalizes the vector, failing instead of dividing by zero when its magnitude is zero.
+	pub fn try_norm(&self) -> Result<Vector3<T>, LineqError> {
+		let mag : T = self.mag();
+		if mag == T::ZERO { return Err(LineqError::ZeroVector); }
+		Ok(Vector3 { x: self.x/mag, y: self.y/mag, z: self.z/mag })
+	}
 }
@@ -1,8 +1,14 @@
-use crate::vec3::Vec3;
+use crate::scalar::Scalar;
+use crate::simd;
+use crate::error::{LineqError, check_len};
+use crate::vec3::{Vector3, Vec3};
 use std::mem::MaybeUninit;
 use std::ptr::slice_from_raw_parts_mut;
 use std::alloc::Layout;
 use std::alloc::alloc;
+use std::alloc::dealloc;
+use std::alloc::handle_alloc_error;
+use std::ptr::NonNull;
 use ::deref_impl;
 use ::deref_mut_impl;
 use ::value_impl;
@@ -13,22 +19,108 @@ use ::pv_inplace_impl;
 use ::pv_dot_impl;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Vec3arr<const N: usize>(pub [Vec3; N]);
+#[repr(transparent)]
+pub struct Vector3arr<const N: usize, T>(pub [Vector3<T>; N]);
+
+/// A [Vec3arr] of [f32]s.
+pub type Vec3arr<const N: usize> = Vector3arr<N, f32>;
+
+/// Creates a [Vec3arr] from an array of vectors.
+#[allow(non_snake_case)]
+pub const fn Vec3arr<const N: usize>(arr : [Vec3; N]) -> Vec3arr<N> {
+        Vector3arr(arr)
+}
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct Vec3box(pub Box<[Vec3]>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3box<T>(pub Box<[Vector3<T>]>);
+
+/// A [Vec3box] of [f32]s.
+pub type Vec3box = Vector3box<f32>;
+
+/// Creates a [Vec3box] from a boxed slice of vectors.
+#[allow(non_snake_case)]
+pub const fn Vec3box(arr : Box<[Vec3]>) -> Vec3box {
+        Vector3box(arr)
+}
 
 #[derive(Debug, PartialEq)]
-pub struct Vec3win<'a>(pub &'a mut [Vec3]);
+pub struct Vector3win<'a, T>(pub &'a mut [Vector3<T>]);
+
+/// A [Vec3win] of [f32]s.
+pub type Vec3win<'a> = Vector3win<'a, f32>;
 
+/// Creates a [Vec3win] over a mutable slice of vectors.
+#[allow(non_snake_case)]
+pub const fn Vec3win(arr : &mut [Vec3]) -> Vec3win<'_> {
+        Vector3win(arr)
+}
+
+/// A growable array of vectors, for building up an array before using it like a [Vec3box].
+///
+/// In operators it counts as less structured than a [Vec3box] and more than a [Vec3win], so adding
+/// a `Vec3vec` to a [Vec3win] or another `Vec3vec` gives a `Vec3vec`, which is allocated the same way as
+/// a [Vec3box] would be. It converts to a [Vec3box] with [into_box](Vec3vec::into_box), and from one
+/// with [From], without copying the vectors.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec3::Vec3;
+/// use lineq::vec3arr::{Vec3arr, Vec3box, Vec3vec, Vec3win, Vec3Array};
+/// let mut a : Vec3vec = (0..3).map(|i| Vec3::ONE*i as f32).collect();
+/// let mut b : Vec3box = Vec3box::ftoarr(|_| Vec3::ONE, 3);
+/// let c : Vec3vec = &a + Vec3win(&mut b);
+/// let d : Vec3box = &a + &b;
+/// let e : Vec3arr<3> = &a + Vec3arr([Vec3::ONE; 3]);
+/// assert_eq!(&c[..], &d[..]);
+/// assert_eq!(&c[..], &e[..]);
+/// a -= &b;
+/// assert_eq!(&*a.dot(&b), &[-3.0, 0.0, 3.0]);
+/// let n : Vec3vec = Vec3Array::norm(&c);
+/// assert_eq!(n.len(), 3);
+/// let mut v : Vec3vec = Vec3vec::new();
+/// assert_eq!(v.to_string(), "[]");
+/// v.push(Vec3::ONE);
+/// assert_eq!(v.to_string(), "[<1, 1, 1>]");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3vec<T>(pub Vec<Vector3<T>>);
+
+/// A [Vec3vec] of [f32]s.
+pub type Vec3vec = Vector3vec<f32>;
+
+/// Creates a [Vec3vec] from a [Vec] of vectors.
+#[allow(non_snake_case)]
+pub const fn Vec3vec(arr : Vec<Vec3>) -> Vec3vec {
+        Vector3vec(arr)
+}
+
+/// An array of vectors behind a raw pointer, allocated with [alloc] and freed when dropped.
+///
+/// A `Vec3raw` always owns its memory, the same as a [Box], so it is [Send] and [Sync]. Use
+/// [into_raw](Vec3raw::into_raw) to give the pointer away without freeing it, and
+/// [from_raw](Vec3raw::from_raw) to take it back. Memory owned by something else should be
+/// borrowed as a [Vec3win] instead.
 #[derive(Debug, PartialEq)]
-pub struct Vec3raw(pub *mut [Vec3]);
+pub struct Vector3raw<T>(*mut [Vector3<T>]);
+
+/// A [Vec3raw] of [f32]s.
+pub type Vec3raw = Vector3raw<f32>;
+
+// A Vec3raw owns its allocation and its scalars are plain data, so it can move between
+// and be shared by threads like a Box<[Vec3<T>]>.
+unsafe impl<T: Scalar + Send> Send for Vector3raw<T> {}
+unsafe impl<T: Scalar + Sync> Sync for Vector3raw<T> {}
 
 macro_rules! disp_impl {
         (Disp $t:ty$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
-                impl$(<$lt>)?$(<const $gen: $gent>)? fmt::Display for $t {
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> fmt::Display for $t {
 			#[inline]
                         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                                if self.len() == 0 { return write!(f, "[]"); }
                                 write!(f, "[")?;
                                 for i in 0..self.len()-1 {
                                         write!(f, "<{}, {}, {}>, ", self[i].x, self[i].y, self[i].z)?
@@ -39,355 +131,622 @@ macro_rules! disp_impl {
         };
 }
 
+macro_rules! try_impl {
+        (Try $t:ty$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> $t {
+                        /// Adds `rhs` to the vectors in place, failing instead of panicking if the lengths differ.
+                        pub fn try_add_assign(&mut self, rhs : &[Vector3<T>]) -> Result<(), LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                for (a, b) in self.iter_mut().zip(rhs.iter()) {
+                                        *a += *b;
+                                }
+                                Ok(())
+                        }
+
+                        /// Subtracts `rhs` from the vectors in place, failing instead of panicking if the lengths differ.
+                        pub fn try_sub_assign(&mut self, rhs : &[Vector3<T>]) -> Result<(), LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                for (a, b) in self.iter_mut().zip(rhs.iter()) {
+                                        *a -= *b;
+                                }
+                                Ok(())
+                        }
+
+                        /// Adds two arrays of vectors into a new Vec3box, failing instead of panicking if the lengths differ.
+                        ///
+                        /// # Examples
+                        ///
+                        /// ```rust
+                        /// # extern crate lineq;
+                        /// use lineq::vec3::Vec3;
+                        /// use lineq::vec3arr::{Vec3arr, Vec3box, Vec3vec, Vec3win};
+                        /// use lineq::error::LineqError;
+                        /// let mut a : Vec3box = Vec3box::new_zeroed(3);
+                        /// let b : Vec3arr<2> = Vec3arr([Vec3::ONE; 2]);
+                        /// assert_eq!(a.try_add(&*b), Err(LineqError::LengthMismatch { expected: 3, found: 2 }));
+                        /// assert_eq!(b.try_sub(&*b).unwrap(), Vec3box::new_zeroed(2));
+                        /// let c : Vec3vec = Vec3vec::from(vec![Vec3::ONE; 3]);
+                        /// assert_eq!(&*c.try_dot(&*a).unwrap(), &[0.0; 3]);
+                        /// assert!(Vec3win(&mut a).try_dot(&*b).is_err());
+                        /// ```
+                        pub fn try_add(&self, rhs : &[Vector3<T>]) -> Result<Vector3box<T>, LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                Ok(Vector3box::ftoarr(|i| self[i] + rhs[i], self.len()))
+                        }
+
+                        /// Subtracts two arrays of vectors into a new Vec3box, failing instead of panicking if the lengths differ.
+                        pub fn try_sub(&self, rhs : &[Vector3<T>]) -> Result<Vector3box<T>, LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                Ok(Vector3box::ftoarr(|i| self[i] - rhs[i], self.len()))
+                        }
+
+                        /// Takes the dot product of each pair of vectors, failing instead of panicking if the lengths differ.
+                        pub fn try_dot(&self, rhs : &[Vector3<T>]) -> Result<Box<[T]>, LineqError> {
+                                check_len(self.len(), rhs.len())?;
+                                Ok(self.iter().zip(rhs.iter()).map(|(a, b)| *a**b).collect())
+                        }
+                }
+        };
+}
+
+// Operators that give a Vec3vec, computed into a box which then becomes the buffer of the Vec3vec.
+macro_rules! vec_value_impl {
+        ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
+                vec_value_impl! {@3 $imp;$func;$op; $rhs; for $lhs$(; <$lt>)?}
+                vec_value_impl! {@3 $imp;$func;$op; &$rhs; for $lhs$(; <$lt>)?}
+                vec_value_impl! {@3 $imp;$func;$op; $rhs; for &$lhs$(; <$lt>)?}
+                vec_value_impl! {@3 $imp;$func;$op; &$rhs; for &$lhs$(; <$lt>)?}
+        };
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ty; out: $out:ty) => {
+                vec_value_impl! {@2 $imp;$func;$op; $rhs; for $lhs; out: $out}
+                vec_value_impl! {@2 $imp;$func;$op; &$rhs; for $lhs; out: $out}
+                vec_value_impl! {@2 $imp;$func;$op; $rhs; for &$lhs; out: $out}
+                vec_value_impl! {@2 $imp;$func;$op; &$rhs; for &$lhs; out: $out}
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty) => {
+                vec_value_impl! {@1 $imp;$func;$op; $rhs; for $lhs}
+                vec_value_impl! {@1 $imp;$func;$op; &$rhs; for $lhs}
+                vec_value_impl! {@1 $imp;$func;$op; $rhs; for &$lhs}
+                vec_value_impl! {@1 $imp;$func;$op; &$rhs; for &$lhs}
+        };
+        (@3 $imp:ident;$func:ident;$op:tt; $rhs:ty; for $lhs:ty$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector3vec<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector3vec<T> {
+                                if self.len() != rhs.len() { panic!("slices inequal length"); }
+                                let mut tmp = Vector3box::new_uninit_box(self.len());
+                                simd::zip(&mut tmp, &self[..], &rhs[..], |a, b| a $op b);
+                                Vector3vec(unsafe { tmp.assume_init() }.into_vec())
+                        }
+                }
+        };
+        (@2 $imp:ident;$func:ident;$op:tt; $rhs:ty; for $lhs:ty; out: $out:ty) => {
+                impl $imp<$rhs> for $lhs {
+                        type Output = $out;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> $out {
+                                let mut tmp = Vector3box::new_uninit_box(rhs.len());
+                                simd::scalar_zip(&mut tmp, self, &rhs[..], |a, b| a $op b);
+                                Vector3vec(unsafe { tmp.assume_init() }.into_vec())
+                        }
+                }
+        };
+        (@1 $imp:ident;$func:ident;$op:tt; $rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector3vec<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector3vec<T> {
+                                let mut tmp = Vector3box::new_uninit_box(self.len());
+                                simd::zip_scalar(&mut tmp, &self[..], rhs, |a, b| a $op b);
+                                Vector3vec(unsafe { tmp.assume_init() }.into_vec())
+                        }
+                }
+        };
+}
+
+// Borrowing any of the arrays iterates over references to its vectors, like borrowing a slice.
+macro_rules! iter_impl {
+        (Iter $t:ty$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
+                impl<'b, $($lt,)?$(const $gen: $gent,)?T: Scalar> IntoIterator for &'b $t {
+                        type Item = &'b Vector3<T>;
+                        type IntoIter = std::slice::Iter<'b, Vector3<T>>;
+                        fn into_iter(self) -> std::slice::Iter<'b, Vector3<T>> {
+                                self.iter()
+                        }
+                }
+
+                impl<'b, $($lt,)?$(const $gen: $gent,)?T: Scalar> IntoIterator for &'b mut $t {
+                        type Item = &'b mut Vector3<T>;
+                        type IntoIter = std::slice::IterMut<'b, Vector3<T>>;
+                        fn into_iter(self) -> std::slice::IterMut<'b, Vector3<T>> {
+                                self.iter_mut()
+                        }
+                }
+        };
+}
+
 //Deref
 use std::ops::Deref;
 
-deref_impl! {Deref val Vec3arr<N>; to [Vec3; N]; const N: usize}
-deref_impl! {Deref val Vec3box; to [Vec3]}
-deref_impl! {Deref ptr Vec3win<'_>; to [Vec3]}
-impl Deref for Vec3raw {
-	type Target = [Vec3];
+deref_impl! {Deref val Vector3arr<N, T>; to [Vector3<T>; N]; const N: usize; T: Scalar}
+deref_impl! {Deref val Vector3box<T>; to [Vector3<T>]; T: Scalar}
+deref_impl! {Deref ptr Vector3win<'_, T>; to [Vector3<T>]; T: Scalar}
+impl<T: Scalar> Deref for Vector3raw<T> {
+	type Target = [Vector3<T>];
 	fn deref(&self) -> &Self::Target {
 		unsafe { &*self.0 }
 	}
 }
+deref_impl! {Deref val Vector3vec<T>; to [Vector3<T>]; T: Scalar}
 
 //DerefMut
 use std::ops::DerefMut;
 
-deref_mut_impl! {DerefMut val Vec3arr<N>; to [Vec3; N]; const N: usize}
-deref_mut_impl! {DerefMut val Vec3box; to [Vec3]}
-deref_mut_impl! {DerefMut ptr Vec3win<'_>; to [Vec3]}
-impl DerefMut for Vec3raw {
-	fn deref_mut(&mut self) -> &mut [Vec3] {
+deref_mut_impl! {DerefMut val Vector3arr<N, T>; to [Vector3<T>; N]; const N: usize; T: Scalar}
+deref_mut_impl! {DerefMut val Vector3box<T>; to [Vector3<T>]; T: Scalar}
+deref_mut_impl! {DerefMut ptr Vector3win<'_, T>; to [Vector3<T>]; T: Scalar}
+impl<T: Scalar> DerefMut for Vector3raw<T> {
+	fn deref_mut(&mut self) -> &mut [Vector3<T>] {
 		unsafe { &mut *self.0 }
 	}
 }
+deref_mut_impl! {DerefMut val Vector3vec<T>; to [Vector3<T>]; T: Scalar}
 
 //Add
 use std::ops::Add;
 
-pv_value_impl! {Add;add;+; 1 f32; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 1 f32; for Vec3box; out: Vec3box}
-pv_value_impl! {Add;add;+; 2 Vec3arr<N>; for f32; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 2 Vec3box; for f32; out: Vec3box}
-pv_value_impl! {Add;add;+; 3 Vec3arr<N>; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec3box; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec3win<'a>; for Vec3arr<N>; out: Vec3arr<N>; const N: usize; <'a>}
-pv_value_impl! {Add;add;+; 3 Vec3raw; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec3arr<N>; for Vec3box; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec3box; for Vec3box; out: Vec3box}
-pv_value_impl! {Add;add;+; 3 Vec3win<'a>; for Vec3box; out: Vec3box; <'a>}
-pv_value_impl! {Add;add;+; 3 Vec3raw; for Vec3box; out: Vec3box}
-pv_value_impl! {Add;add;+; 3 Vec3arr<N>; for Vec3win<'a>; out: Vec3arr<N>; const N: usize; <'a>}
-pv_value_impl! {Add;add;+; 3 Vec3box; for Vec3win<'a>; out: Vec3box; <'a>}
-pv_value_impl! {Add;add;+; 3 Vec3arr<N>; for Vec3raw; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Add;add;+; 3 Vec3box; for Vec3raw; out: Vec3box}
+pv_value_impl! {Add;add;+; 1 T; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 1 T; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Add;add;+; 2 Vector3arr<N, f32>; for f32; out: Vector3arr<N, f32>; const N: usize}
+pv_value_impl! {Add;add;+; 2 Vector3arr<N, f64>; for f64; out: Vector3arr<N, f64>; const N: usize}
+pv_value_impl! {Add;add;+; 2 Vector3box<f32>; for f32; out: Vector3box<f32>}
+pv_value_impl! {Add;add;+; 2 Vector3box<f64>; for f64; out: Vector3box<f64>}
+pv_value_impl! {Add;add;+; 3 Vector3arr<N, T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3box<T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3win<'a, T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3raw<T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3arr<N, T>; for Vector3box<T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3box<T>; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3win<'a, T>; for Vector3box<T>; out: Vector3box<T>; <'a>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3raw<T>; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3arr<N, T>; for Vector3win<'a, T>; out: Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3box<T>; for Vector3win<'a, T>; out: Vector3box<T>; <'a>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3arr<N, T>; for Vector3raw<T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3box<T>; for Vector3raw<T>; out: Vector3box<T>; T: Scalar}
+vec_value_impl! {Add;add;+; 1 T; for Vector3vec<T>}
+vec_value_impl! {Add;add;+; 2 Vector3vec<f32>; for f32; out: Vector3vec<f32>}
+vec_value_impl! {Add;add;+; 2 Vector3vec<f64>; for f64; out: Vector3vec<f64>}
+pv_value_impl! {Add;add;+; 3 Vector3vec<T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3arr<N, T>; for Vector3vec<T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3vec<T>; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Add;add;+; 3 Vector3box<T>; for Vector3vec<T>; out: Vector3box<T>; T: Scalar}
+vec_value_impl! {Add;add;+; 3 Vector3vec<T>; for Vector3vec<T>}
+vec_value_impl! {Add;add;+; 3 Vector3win<'a, T>; for Vector3vec<T>; <'a>}
+vec_value_impl! {Add;add;+; 3 Vector3raw<T>; for Vector3vec<T>}
+vec_value_impl! {Add;add;+; 3 Vector3vec<T>; for Vector3win<'a, T>; <'a>}
+vec_value_impl! {Add;add;+; 3 Vector3vec<T>; for Vector3raw<T>}
 
 //AddAssign
 use std::ops::AddAssign;
 
-pv_inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec3arr<N>; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec3box}
-pv_inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 0 f32; for Vec3raw}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3arr<N>; for Vec3arr<N>; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3box; for Vec3arr<N>; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3win<'a>; for Vec3arr<N>; const N: usize; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3raw; for Vec3arr<N>; const N: usize}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vec3]; for Vec3arr<N>; const N: usize; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vec3]; for Vec3arr<N>; const N: usize; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3arr<N>; for Vec3box; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3box; for Vec3box}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3win<'a>; for Vec3box; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3raw; for Vec3box}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vec3]; for Vec3box; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vec3]; for Vec3box; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3arr<N>; for Vec3win<'a>; const N: usize; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3box; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3win<'a>; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3raw; for Vec3win<'a>; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vec3]; for Vec3win<'a>; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vec3]; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3arr<N>; for Vec3raw; const N: usize}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3box; for Vec3raw}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3win<'a>; for Vec3raw; <'a>}
-pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vec3raw; for Vec3raw}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vec3]; for Vec3raw; <'a>}
-inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vec3]; for Vec3raw; <'a>}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector3box<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector3raw<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3arr<N, T>; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3box<T>; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3win<'a, T>; for Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3raw<T>; for Vector3arr<N, T>; const N: usize; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector3<T>]; for Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector3<T>]; for Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3arr<N, T>; for Vector3box<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3box<T>; for Vector3box<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3win<'a, T>; for Vector3box<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3raw<T>; for Vector3box<T>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector3<T>]; for Vector3box<T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector3<T>]; for Vector3box<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3arr<N, T>; for Vector3win<'a, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3box<T>; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3win<'a, T>; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3raw<T>; for Vector3win<'a, T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector3<T>]; for Vector3win<'a, T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector3<T>]; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3arr<N, T>; for Vector3raw<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3box<T>; for Vector3raw<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3win<'a, T>; for Vector3raw<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3raw<T>; for Vector3raw<T>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector3<T>]; for Vector3raw<T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector3<T>]; for Vector3raw<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector3vec<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3vec<T>; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3vec<T>; for Vector3box<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3vec<T>; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3vec<T>; for Vector3raw<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3arr<N, T>; for Vector3vec<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3box<T>; for Vector3vec<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3win<'a, T>; for Vector3vec<T>; <'a>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3raw<T>; for Vector3vec<T>; T: Scalar}
+pv_inplace_impl! {AddAssign;add_assign;+=; 1 Vector3vec<T>; for Vector3vec<T>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a mut[Vector3<T>]; for Vector3vec<T>; <'a>; T: Scalar}
+inplace_impl! {AddAssign;add_assign;+=; 1 &'a [Vector3<T>]; for Vector3vec<T>; <'a>; T: Scalar}
 
 //Display
 use std::fmt;
 
-disp_impl! {Disp Vec3arr<N>; const N: usize}
-disp_impl! {Disp Vec3box}
-disp_impl! {Disp Vec3win<'a>; <'a>}
-disp_impl! {Disp Vec3raw}
+disp_impl! {Disp Vector3arr<N, T>; const N: usize}
+disp_impl! {Disp Vector3box<T>}
+disp_impl! {Disp Vector3win<'a, T>; <'a>}
+disp_impl! {Disp Vector3raw<T>}
+disp_impl! {Disp Vector3vec<T>}
+
+//Drop
+
+impl<T> Drop for Vector3raw<T> {
+	fn drop(&mut self) {
+		let len : usize = self.0.len();
+		if len != 0 {
+			unsafe { dealloc(self.0 as *mut u8, Layout::array::<Vector3<T>>(len).unwrap()) };
+		}
+	}
+}
 
 //Div
 use std::ops::Div;
 
-pv_value_impl! {Div;div;/; 1 f32; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Div;div;/; 1 f32; for Vec3box; out: Vec3box}
+pv_value_impl! {Div;div;/; 1 T; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Div;div;/; 1 T; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+vec_value_impl! {Div;div;/; 1 T; for Vector3vec<T>}
 
 //DivAssign
 use std::ops::DivAssign;
 
-pv_inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec3arr<N>; const N: usize}
-pv_inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec3box}
-pv_inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {DivAssign;div_assign;/=; 0 f32; for Vec3raw}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector3box<T>; T: Scalar}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector3raw<T>; T: Scalar}
+pv_inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector3vec<T>; T: Scalar}
+
+//IntoIterator
+
+iter_impl! {Iter Vector3arr<N, T>; const N: usize}
+iter_impl! {Iter Vector3box<T>}
+iter_impl! {Iter Vector3vec<T>}
+iter_impl! {Iter Vector3win<'a, T>; <'a>}
+iter_impl! {Iter Vector3raw<T>}
+
+impl<const N: usize, T: Scalar> IntoIterator for Vector3arr<N, T> {
+        type Item = Vector3<T>;
+        type IntoIter = std::array::IntoIter<Vector3<T>, N>;
+        fn into_iter(self) -> std::array::IntoIter<Vector3<T>, N> {
+                IntoIterator::into_iter(self.0)
+        }
+}
+
+impl<T: Scalar> IntoIterator for Vector3box<T> {
+        type Item = Vector3<T>;
+        type IntoIter = std::vec::IntoIter<Vector3<T>>;
+        fn into_iter(self) -> std::vec::IntoIter<Vector3<T>> {
+                self.0.into_vec().into_iter()
+        }
+}
+
+impl<T: Scalar> IntoIterator for Vector3vec<T> {
+        type Item = Vector3<T>;
+        type IntoIter = std::vec::IntoIter<Vector3<T>>;
+        fn into_iter(self) -> std::vec::IntoIter<Vector3<T>> {
+                self.0.into_iter()
+        }
+}
+
+// A Vec3win only borrows its vectors, so it iterates over them mutably instead of moving them out.
+impl<'a, T: Scalar> IntoIterator for Vector3win<'a, T> {
+        type Item = &'a mut Vector3<T>;
+        type IntoIter = std::slice::IterMut<'a, Vector3<T>>;
+        fn into_iter(self) -> std::slice::IterMut<'a, Vector3<T>> {
+                self.0.iter_mut()
+        }
+}
+
+// A Vec3raw is allocated with the layout of a Box<[Vec3<T>]>, so a box can take over freeing it.
+impl<T: Scalar> IntoIterator for Vector3raw<T> {
+        type Item = Vector3<T>;
+        type IntoIter = std::vec::IntoIter<Vector3<T>>;
+        fn into_iter(self) -> std::vec::IntoIter<Vector3<T>> {
+                unsafe { Box::from_raw(self.into_raw()) }.into_vec().into_iter()
+        }
+}
 
 //Mult
 use std::ops::Mul;
 
-pv_value_impl! {Mul;mul;*; 1 f32; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Mul;mul;*; 1 f32; for Vec3box; out: Vec3box}
-pv_value_impl! {Mul;mul;*; 2 Vec3arr<N>; for f32; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Mul;mul;*; 2 Vec3box; for f32; out: Vec3box}
-pv_dot_impl! {Dot Vec3arr<N>; for Vec3arr<N>; const N: usize}
-pv_dot_impl! {Dot Vec3box; for Vec3arr<N>; const N: usize}
-pv_dot_impl! {Dot Vec3win<'a>; for Vec3arr<N>; const N: usize; <'a>}
-pv_dot_impl! {Dot Vec3raw; for Vec3arr<N>; const N: usize}
-pv_dot_impl! {Dot Vec3arr<N>; for Vec3box; const N: usize}
-pv_dot_impl! {Dot Vec3box; for Vec3box}
-pv_dot_impl! {Dot Vec3win<'a>; for Vec3box; <'a>}
-pv_dot_impl! {Dot Vec3raw; for Vec3box}
-pv_dot_impl! {Dot Vec3arr<N>; for Vec3win<'a>; const N: usize; <'a>}
-pv_dot_impl! {Dot Vec3box; for Vec3win<'a>; <'a>}
-pv_dot_impl! {Dot Vec3arr<N>; for Vec3raw; const N: usize}
-pv_dot_impl! {Dot Vec3box; for Vec3raw}
+pv_value_impl! {Mul;mul;*; 1 T; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Mul;mul;*; 1 T; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Mul;mul;*; 2 Vector3arr<N, f32>; for f32; out: Vector3arr<N, f32>; const N: usize}
+pv_value_impl! {Mul;mul;*; 2 Vector3arr<N, f64>; for f64; out: Vector3arr<N, f64>; const N: usize}
+pv_value_impl! {Mul;mul;*; 2 Vector3box<f32>; for f32; out: Vector3box<f32>}
+pv_value_impl! {Mul;mul;*; 2 Vector3box<f64>; for f64; out: Vector3box<f64>}
+pv_dot_impl! {Dot Vector3arr<N, T>; for Vector3arr<N, T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector3box<T>; for Vector3arr<N, T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector3win<'a, T>; for Vector3arr<N, T>; out: T; const N: usize; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector3raw<T>; for Vector3arr<N, T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector3arr<N, T>; for Vector3box<T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector3box<T>; for Vector3box<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector3win<'a, T>; for Vector3box<T>; out: T; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector3raw<T>; for Vector3box<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector3arr<N, T>; for Vector3win<'a, T>; out: T; const N: usize; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector3box<T>; for Vector3win<'a, T>; out: T; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector3arr<N, T>; for Vector3raw<T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector3box<T>; for Vector3raw<T>; out: T; T: Scalar}
+vec_value_impl! {Mul;mul;*; 1 T; for Vector3vec<T>}
+vec_value_impl! {Mul;mul;*; 2 Vector3vec<f32>; for f32; out: Vector3vec<f32>}
+vec_value_impl! {Mul;mul;*; 2 Vector3vec<f64>; for f64; out: Vector3vec<f64>}
+pv_dot_impl! {Dot Vector3vec<T>; for Vector3arr<N, T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector3arr<N, T>; for Vector3vec<T>; out: T; const N: usize; T: Scalar}
+pv_dot_impl! {Dot Vector3vec<T>; for Vector3box<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector3box<T>; for Vector3vec<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector3vec<T>; for Vector3vec<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector3win<'a, T>; for Vector3vec<T>; out: T; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector3raw<T>; for Vector3vec<T>; out: T; T: Scalar}
+pv_dot_impl! {Dot Vector3vec<T>; for Vector3win<'a, T>; out: T; <'a>; T: Scalar}
+pv_dot_impl! {Dot Vector3vec<T>; for Vector3raw<T>; out: T; T: Scalar}
 
 //MultAssign
 use std::ops::MulAssign;
 
-pv_inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec3arr<N>; const N: usize}
-pv_inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec3box}
-pv_inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {MulAssign;mul_assign;*=; 0 f32; for Vec3raw}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector3box<T>; T: Scalar}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector3raw<T>; T: Scalar}
+pv_inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector3vec<T>; T: Scalar}
 
 //Neg
 use std::ops::Neg;
 
-impl<const N: usize> Neg for Vec3arr<N> {
-        type Output = Vec3arr<N>;
-        fn neg(self) -> Vec3arr<N> {
-		let mut tmp: Vec3arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-                        tmp[i] = Vec3 { x: -self[i].x, y: -self[i].y, z: -self[i].z };
-                }
-		unsafe { std::mem::transmute::<_, Vec3arr<N>>(tmp) }
+impl<const N: usize, T: Scalar> Neg for Vector3arr<N, T> {
+        type Output = Vector3arr<N, T>;
+        fn neg(self) -> Vector3arr<N, T> {
+		Vector3arr::ftoarr(|i| Vector3 { x: -self[i].x, y: -self[i].y, z: -self[i].z })
         }
 }
 
-impl Neg for Vec3box {
-        type Output = Vec3box;
-        fn neg(self) -> Vec3box {
-                let mut tmp = Box::<[Vec3]>::new_uninit_slice(self.len());
+impl<T: Scalar> Neg for Vector3box<T> {
+        type Output = Vector3box<T>;
+        fn neg(self) -> Vector3box<T> {
+                let mut tmp = Box::<[Vector3<T>]>::new_uninit_slice(self.len());
                 let tmp = unsafe {
                         for i in 0..self.len() {
-                                tmp[i].as_mut_ptr().write( Vec3 { x: -self[i].x, y: -self[i].y, z: -self[i].z } );
+                                tmp[i].as_mut_ptr().write( Vector3 { x: -self[i].x, y: -self[i].y, z: -self[i].z } );
                         }
                         tmp.assume_init()
                 };
-                Vec3box(tmp)
+                Vector3box(tmp)
+        }
+}
+
+impl<T: Scalar> Neg for Vector3vec<T> {
+        type Output = Vector3vec<T>;
+        fn neg(mut self) -> Vector3vec<T> {
+                for v in self.iter_mut() {
+                        *v = -*v;
+                }
+                self
         }
 }
 
 //Sub
 use std::ops::Sub;
 
-pv_value_impl! {Sub;sub;-; 1 f32; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 1 f32; for Vec3box; out: Vec3box}
-pv_value_impl! {Sub;sub;-; 3 Vec3arr<N>; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec3box; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec3win<'a>; for Vec3arr<N>; out: Vec3arr<N>; const N: usize; <'a>}
-pv_value_impl! {Sub;sub;-; 3 Vec3raw; for Vec3arr<N>; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec3arr<N>; for Vec3box; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec3box; for Vec3box; out: Vec3box}
-pv_value_impl! {Sub;sub;-; 3 Vec3win<'a>; for Vec3box; out: Vec3box; <'a>}
-pv_value_impl! {Sub;sub;-; 3 Vec3raw; for Vec3box; out: Vec3box}
-pv_value_impl! {Sub;sub;-; 3 Vec3arr<N>; for Vec3win<'a>; out: Vec3arr<N>; const N: usize; <'a>}
-pv_value_impl! {Sub;sub;-; 3 Vec3box; for Vec3win<'a>; out: Vec3box; <'a>}
-pv_value_impl! {Sub;sub;-; 3 Vec3arr<N>; for Vec3raw; out: Vec3arr<N>; const N: usize}
-pv_value_impl! {Sub;sub;-; 3 Vec3box; for Vec3raw; out: Vec3box}
+pv_value_impl! {Sub;sub;-; 1 T; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 1 T; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3arr<N, T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3box<T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3win<'a, T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3raw<T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3arr<N, T>; for Vector3box<T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3box<T>; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3win<'a, T>; for Vector3box<T>; out: Vector3box<T>; <'a>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3raw<T>; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3arr<N, T>; for Vector3win<'a, T>; out: Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3box<T>; for Vector3win<'a, T>; out: Vector3box<T>; <'a>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3arr<N, T>; for Vector3raw<T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3box<T>; for Vector3raw<T>; out: Vector3box<T>; T: Scalar}
+vec_value_impl! {Sub;sub;-; 1 T; for Vector3vec<T>}
+pv_value_impl! {Sub;sub;-; 3 Vector3vec<T>; for Vector3arr<N, T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3arr<N, T>; for Vector3vec<T>; out: Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3vec<T>; for Vector3box<T>; out: Vector3box<T>; T: Scalar}
+pv_value_impl! {Sub;sub;-; 3 Vector3box<T>; for Vector3vec<T>; out: Vector3box<T>; T: Scalar}
+vec_value_impl! {Sub;sub;-; 3 Vector3vec<T>; for Vector3vec<T>}
+vec_value_impl! {Sub;sub;-; 3 Vector3win<'a, T>; for Vector3vec<T>; <'a>}
+vec_value_impl! {Sub;sub;-; 3 Vector3raw<T>; for Vector3vec<T>}
+vec_value_impl! {Sub;sub;-; 3 Vector3vec<T>; for Vector3win<'a, T>; <'a>}
+vec_value_impl! {Sub;sub;-; 3 Vector3vec<T>; for Vector3raw<T>}
 
 //SubAssign
 use std::ops::SubAssign;
 
-pv_inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec3arr<N>; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec3box}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 0 f32; for Vec3raw}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3arr<N>; for Vec3arr<N>; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3box; for Vec3arr<N>; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3win<'a>; for Vec3arr<N>; const N: usize; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3raw; for Vec3arr<N>; const N: usize}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vec3]; for Vec3arr<N>; const N: usize; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vec3]; for Vec3arr<N>; const N: usize; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3arr<N>; for Vec3box; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3box; for Vec3box}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3win<'a>; for Vec3box; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3raw; for Vec3box}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vec3]; for Vec3box; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vec3]; for Vec3box; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3arr<N>; for Vec3win<'a>; const N: usize; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3box; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3win<'a>; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3raw; for Vec3win<'a>; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vec3]; for Vec3win<'a>; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vec3]; for Vec3win<'a>; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3arr<N>; for Vec3raw; const N: usize}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3box; for Vec3raw}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3win<'a>; for Vec3raw; <'a>}
-pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vec3raw; for Vec3raw}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vec3]; for Vec3raw; <'a>}
-inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vec3]; for Vec3raw; <'a>}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector3box<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector3raw<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3arr<N, T>; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3box<T>; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3win<'a, T>; for Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3raw<T>; for Vector3arr<N, T>; const N: usize; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector3<T>]; for Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector3<T>]; for Vector3arr<N, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3arr<N, T>; for Vector3box<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3box<T>; for Vector3box<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3win<'a, T>; for Vector3box<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3raw<T>; for Vector3box<T>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector3<T>]; for Vector3box<T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector3<T>]; for Vector3box<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3arr<N, T>; for Vector3win<'a, T>; const N: usize; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3box<T>; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3win<'a, T>; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3raw<T>; for Vector3win<'a, T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector3<T>]; for Vector3win<'a, T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector3<T>]; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3arr<N, T>; for Vector3raw<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3box<T>; for Vector3raw<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3win<'a, T>; for Vector3raw<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3raw<T>; for Vector3raw<T>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector3<T>]; for Vector3raw<T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector3<T>]; for Vector3raw<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector3vec<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3vec<T>; for Vector3arr<N, T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3vec<T>; for Vector3box<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3vec<T>; for Vector3win<'a, T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3vec<T>; for Vector3raw<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3arr<N, T>; for Vector3vec<T>; const N: usize; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3box<T>; for Vector3vec<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3win<'a, T>; for Vector3vec<T>; <'a>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3raw<T>; for Vector3vec<T>; T: Scalar}
+pv_inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3vec<T>; for Vector3vec<T>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a mut[Vector3<T>]; for Vector3vec<T>; <'a>; T: Scalar}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &'a [Vector3<T>]; for Vector3vec<T>; <'a>; T: Scalar}
+
+//Try
+
+try_impl! {Try Vector3arr<N, T>; const N: usize}
+try_impl! {Try Vector3box<T>}
+try_impl! {Try Vector3win<'a, T>; <'a>}
+try_impl! {Try Vector3raw<T>}
+try_impl! {Try Vector3vec<T>}
 
 //Vec3arr Methods
 
-impl<const N: usize> Vec3arr<N> {
+impl<const N: usize, T: Scalar> Vector3arr<N, T> {
 
-	pub fn crossarr(&self, rhs : Vec3arr<N>) -> Vec3arr<N> {
-		let mut tmp: Vec3arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-                        tmp[i] = Vec3 {
-                		x: self[i].y * rhs[i].z - self[i].z * rhs[i].y,
-                                y: self[i].z * rhs[i].x - self[i].x * rhs[i].z,
-                                z: self[i].x * rhs[i].y - self[i].y * rhs[i].x
-                        };
-                }
-                unsafe { std::mem::transmute::<_, Vec3arr<N>>(tmp) }
+	pub fn crossarr(&self, rhs : Vector3arr<N, T>) -> Vector3arr<N, T> {
+		Vector3arr::ftoarr(|i| Vector3 {
+			x: self[i].y * rhs[i].z - self[i].z * rhs[i].y,
+			y: self[i].z * rhs[i].x - self[i].x * rhs[i].z,
+			z: self[i].x * rhs[i].y - self[i].y * rhs[i].x
+		})
 	}
 
-	pub fn cross(&self, rhs : Vec3) -> Vec3arr<N> {
-		let mut tmp: Vec3arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-                        tmp[i] = Vec3 {
-                                x: self[i].y * rhs.z - self[i].z * rhs.y,
-                                y: self[i].z * rhs.x - self[i].x * rhs.z,
-                                z: self[i].x * rhs.y - self[i].y * rhs.x
-                        };
-                }
-                unsafe { std::mem::transmute::<_, Vec3arr<N>>(tmp) }
+	pub fn cross(&self, rhs : Vector3<T>) -> Vector3arr<N, T> {
+		Vector3arr::ftoarr(|i| Vector3 {
+			x: self[i].y * rhs.z - self[i].z * rhs.y,
+			y: self[i].z * rhs.x - self[i].x * rhs.z,
+			z: self[i].x * rhs.y - self[i].y * rhs.x
+		})
         }
 
-        pub fn mag(&self) -> [f32; N] {
-		let mut tmp: [f32; N] = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-                        tmp[i] = (self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z).sqrt();
-                }
-                unsafe { std::mem::transmute::<_, [f32; N]>(tmp) }
+        pub fn mag(&self) -> [T; N] {
+		std::array::from_fn(|i| (self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z).sqrt())
         }
 
-        pub fn mag2(&self) -> [f32; N] {
-                let mut tmp: [f32; N] = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-                        tmp[i] = self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z;
-                }
-                unsafe { std::mem::transmute::<_, [f32; N]>(tmp) }
+        pub fn mag2(&self) -> [T; N] {
+		std::array::from_fn(|i| self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z)
         }
 
-        pub fn norm(&self) -> Vec3arr<N> {
-		let mut tmp: Vec3arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-                for i in 0..N {
-			let mag : f32 = (self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z).sqrt();
-                        tmp[i] = Vec3 { x: self[i].x/mag, y: self[i].y/mag, z: self[i].z/mag };
-                }
-                unsafe { std::mem::transmute::<_, Vec3arr<N>>(tmp) }
+        pub fn norm(&self) -> Vector3arr<N, T> {
+		Vector3arr::ftoarr(|i| {
+			let mag : T = (self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z).sqrt();
+			Vector3 { x: self[i].x/mag, y: self[i].y/mag, z: self[i].z/mag }
+		})
         }
 
-	pub fn transform<F : Fn(Vec3) -> Vec3>(&mut self, f: F) {
+	pub fn transform<F : Fn(Vector3<T>) -> Vector3<T>>(&mut self, f: F) {
 		for i in 0..N {
                         self[i] = f(self[i]);
                 }
 	}
 
-	pub fn ftoarr<F : Fn(usize) -> Vec3>(f: F) -> Vec3arr<N> {
-		let mut tmp: Vec3arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-		for i in 0..N {
-                        tmp[i] = f(i);
-                }
-		unsafe { std::mem::transmute::<_, Vec3arr<N>>(tmp) }
+	pub fn ftoarr<F : Fn(usize) -> Vector3<T>>(f: F) -> Vector3arr<N, T> {
+		Vector3arr(std::array::from_fn(f))
 	}
 }
 
 // Vec3box Methods
 
-impl Vec3box {
+impl<T: Scalar> Vector3box<T> {
 
-        pub fn new_zeroed(len: usize) -> Vec3box {
-                unsafe { Vec3box(Box::<[Vec3]>::new_zeroed_slice(len).assume_init()) }
+        pub fn new_zeroed(len: usize) -> Vector3box<T> {
+                unsafe { Vector3box(Box::<[Vector3<T>]>::new_zeroed_slice(len).assume_init()) }
         }
 
-	pub fn from_raw_parts(ptr: *mut Vec3, len: usize) -> Vec3box {
-                unsafe { Vec3box(Box::from_raw(slice_from_raw_parts_mut(ptr, len))) }
+	/// Takes ownership of `len` vectors starting at `ptr`, which are freed when the `Vec3box`
+	/// is dropped.
+	///
+	/// # Safety
+	///
+	/// The pointer must have come from a `Box<[Vec3<T>]>` of exactly `len` vectors, for example
+	/// through `Box::into_raw`, and must not be used again except through the returned `Vec3box`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::Vec3box;
+	/// let a : Vec3box = Vec3box::new_zeroed(4);
+	/// let ptr = Box::into_raw(a.0) as *mut Vec3;
+	/// let b : Vec3box = unsafe { Vec3box::from_raw_parts(ptr, 4) };
+	/// assert_eq!(b.len(), 4);
+	/// assert_eq!(b[3], Vec3::ZERO);
+	/// ```
+	pub unsafe fn from_raw_parts(ptr: *mut Vector3<T>, len: usize) -> Vector3box<T> {
+                Vector3box(Box::from_raw(slice_from_raw_parts_mut(ptr, len)))
         }
 
-        pub fn new_from_arr<const N: usize>(arr: Vec3arr<N>) -> Vec3box {
-                let mut new = Vec3box::new_uninit_box(N);
+        pub fn new_from_arr<const N: usize>(arr: Vector3arr<N, T>) -> Vector3box<T> {
+                let mut new = Vector3box::new_uninit_box(N);
                 let new = unsafe {
 			for i in 0..N {
                         	new[i].as_mut_ptr().write(arr[i]);
                 	}
 			new.assume_init()
 		};
-                Vec3box(new)
+                Vector3box(new)
         }
 
-        pub fn new_from_box(arr: Vec3box) -> Vec3box {
-                let mut new = Vec3box::new_uninit_box(arr.len());
+        pub fn new_from_box(arr: Vector3box<T>) -> Vector3box<T> {
+                let mut new = Vector3box::new_uninit_box(arr.len());
                 let new = unsafe {
                         for i in 0..arr.len() {
                                 new[i].as_mut_ptr().write(arr[i]);
                         }
                         new.assume_init()
                 };
-                Vec3box(new)
+                Vector3box(new)
         }
 
-        pub fn new_from_win<'a>(arr : Vec3win<'a>) -> Vec3box {
-                let mut new = Vec3box::new_uninit_box(arr.len());
+        pub fn new_from_win<'a>(arr : Vector3win<'a, T>) -> Vector3box<T> {
+                let mut new = Vector3box::new_uninit_box(arr.len());
                 let new = unsafe {
                         for i in 0..arr.len() {
                                 new[i].as_mut_ptr().write(arr[i]);
                         }
                         new.assume_init()
                 };
-                Vec3box(new)
+                Vector3box(new)
         }
 
-	pub fn new_from_raw<'a>(arr : Vec3raw) -> Vec3box {
-                let mut new = Vec3box::new_uninit_box(arr.len());
+	pub fn new_from_raw(arr : Vector3raw<T>) -> Vector3box<T> {
+                let mut new = Vector3box::new_uninit_box(arr.len());
                 let new = unsafe {
                         for i in 0..arr.len() {
                                 new[i].as_mut_ptr().write(arr[i]);
                         }
                         new.assume_init()
                 };
-                Vec3box(new)
+                Vector3box(new)
         }
 
-	pub fn crossarr(&self, rhs : Vec3box) -> Vec3box {
-		let mut tmp = Box::<[Vec3]>::new_uninit_slice(self.len());
-                let tmp = unsafe {
-                        for i in 0..self.len() {
-                        	tmp[i].as_mut_ptr().write( Vec3 {
-                                	x: self[i].y * rhs[i].z - self[i].z * rhs[i].y,
-                                	y: self[i].z * rhs[i].x - self[i].x * rhs[i].z,
-                                	z: self[i].x * rhs[i].y - self[i].y * rhs[i].x
-                        	} );
-			}
-			tmp.assume_init()
-                };
-                Vec3box(tmp)
-        }
+	pub fn crossarr(&self, rhs : Vector3box<T>) -> Vector3box<T> {
+		let mut tmp = Vector3box::new_uninit_box(self.len());
+		simd::cross(&mut tmp, self, &rhs);
+		Vector3box(unsafe { tmp.assume_init() })
+	}
 
-        pub fn cross(&self, rhs : Vec3) -> Vec3box {
-		let mut tmp = Box::<[Vec3]>::new_uninit_slice(self.len());
+        pub fn cross(&self, rhs : Vector3<T>) -> Vector3box<T> {
+		let mut tmp = Box::<[Vector3<T>]>::new_uninit_slice(self.len());
                 let tmp = unsafe {
                         for i in 0..self.len() {
-                        	tmp[i].as_mut_ptr().write( Vec3 {
+                        	tmp[i].as_mut_ptr().write( Vector3 {
                                 	x: self[i].y * rhs.z - self[i].z * rhs.y,
                                 	y: self[i].z * rhs.x - self[i].x * rhs.z,
                                 	z: self[i].x * rhs.y - self[i].y * rhs.x
@@ -395,70 +754,80 @@ impl Vec3box {
 			}
 			tmp.assume_init()
                 };
-                Vec3box(tmp)
+                Vector3box(tmp)
         }
 
-	pub fn mag(&self) -> Box<[f32]> {
-		let mut tmp = Box::<[f32]>::new_uninit_slice(self.len());
-                let tmp = unsafe {
-                        for i in 0..self.len() {
-                        	tmp[i].as_mut_ptr().write((self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z).sqrt());
-			}
-			tmp.assume_init()
-                };
-                tmp
-        }
+	pub fn mag(&self) -> Box<[T]> {
+		let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+		simd::mag(&mut tmp, self);
+		unsafe { tmp.assume_init() }
+	}
 
-        pub fn mag2(&self) -> Box<[f32]> {
-		let mut tmp = Box::<[f32]>::new_uninit_slice(self.len());
-                let tmp = unsafe {
-                        for i in 0..self.len() {
-                        	tmp[i].as_mut_ptr().write(self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z);
-			}
-			tmp.assume_init()
-                };
-                tmp
-        }
+	pub fn mag2(&self) -> Box<[T]> {
+		let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+		simd::mag2(&mut tmp, self);
+		unsafe { tmp.assume_init() }
+	}
 
-        pub fn norm(&self) -> Vec3box {
-		let mut tmp = Box::<[Vec3]>::new_uninit_slice(self.len());
-                let tmp = unsafe {
-                        for i in 0..self.len() {
-                        	let mag : f32 = (self[i].x*self[i].x + self[i].y*self[i].y + self[i].z*self[i].z).sqrt();
-                        	tmp[i].as_mut_ptr().write( Vec3 { x: self[i].x/mag, y: self[i].y/mag, z: self[i].z/mag } );
-			}
-			tmp.assume_init()
-                };
-                Vec3box(tmp)
-        }
+	pub fn norm(&self) -> Vector3box<T> {
+		let mut tmp = Vector3box::new_uninit_box(self.len());
+		simd::norm(&mut tmp, self);
+		Vector3box(unsafe { tmp.assume_init() })
+	}
 
-	pub fn transform<F: Fn(Vec3) -> Vec3>(&mut self, f: F) {
+	pub fn transform<F: Fn(Vector3<T>) -> Vector3<T>>(&mut self, f: F) {
                 for i in 0..self.len() {
                         self[i] = f(self[i]);
                 }
         }
 
-	pub fn ftoarr<F: Fn(usize) -> Vec3>(f: F, n: usize) -> Vec3box {
-		let mut tmp = Box::<[Vec3]>::new_uninit_slice(n);
+	pub fn ftoarr<F: Fn(usize) -> Vector3<T>>(f: F, n: usize) -> Vector3box<T> {
+		let mut tmp = Box::<[Vector3<T>]>::new_uninit_slice(n);
                 let tmp = unsafe {
                         for i in 0..n {
                         	tmp[i].as_mut_ptr().write(f(i));
 			}
 			tmp.assume_init()
                 };
-                Vec3box(tmp)
+                Vector3box(tmp)
         }
 
-	pub fn new_uninit_box(len: usize) -> Box<[MaybeUninit<Vec3>]> {
-		Box::<[Vec3]>::new_uninit_slice(len)
+	pub fn new_uninit_box(len: usize) -> Box<[MaybeUninit<Vector3<T>>]> {
+		Box::<[Vector3<T>]>::new_uninit_slice(len)
+	}
+}
+
+use std::iter::FromIterator;
+
+impl<T: Scalar> FromIterator<Vector3<T>> for Vector3box<T> {
+	/// Collects vectors into a box, allocating once if the iterator knows its length.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::{Vec3arr, Vec3box, Vec3raw};
+	/// let mut a : Vec3box = (0..3).map(|i| Vec3 { x: i as f32, y: 0.0, z: 0.0 }).collect();
+	/// for v in &mut a {
+	///     *v *= 2.0;
+	/// }
+	/// let b : Vec3box = a.iter().filter(|v| v.x > 1.0).copied().collect();
+	/// assert_eq!(b.len(), 2);
+	/// assert_eq!(b.into_iter().sum::<Vec3>(), Vec3 { x: 3.0, y: 0.0, z: 0.0 }*2.0);
+	/// let c : Vec3raw = Vec3raw::new_from_arr(Vec3arr([Vec3::ONE; 2]));
+	/// assert_eq!(c.into_iter().collect::<Vec3box>(), Vec3box::ftoarr(|_| Vec3::ONE, 2));
+	/// ```
+	fn from_iter<I: IntoIterator<Item = Vector3<T>>>(iter: I) -> Vector3box<T> {
+		Vector3box(iter.into_iter().collect())
 	}
 }
 
 // Vec3win Methods
 
-impl<'a> Vec3win<'a> {
+impl<'a, T: Scalar> Vector3win<'a, T> {
 
-	pub fn transform<F: Fn(Vec3) -> Vec3>(&mut self, f: F) {
+	pub fn transform<F: Fn(Vector3<T>) -> Vector3<T>>(&mut self, f: F) {
                 for i in 0..self.len() {
                         self[i] = f(self[i]);
                 }
@@ -466,107 +835,665 @@ impl<'a> Vec3win<'a> {
 
 }
 
+// Vec3vec Methods
+
+impl<T: Scalar> Vector3vec<T> {
+
+	pub fn new() -> Vector3vec<T> {
+		Vector3vec(Vec::new())
+	}
+
+	pub fn with_capacity(capacity: usize) -> Vector3vec<T> {
+		Vector3vec(Vec::with_capacity(capacity))
+	}
+
+	pub fn capacity(&self) -> usize {
+		self.0.capacity()
+	}
+
+	/// Adds a vector to the end of the array.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::{Vec3box, Vec3vec};
+	/// let mut a : Vec3vec = Vec3vec::new();
+	/// for i in 0..4 {
+	///     a.push(Vec3 { x: i as f32, y: 0.0, z: 0.0 });
+	/// }
+	/// a.extend(Vec3box::new_zeroed(2).iter());
+	/// a.truncate(5);
+	/// a += 1.0;
+	/// assert_eq!(a.len(), 5);
+	/// assert_eq!(a[4], Vec3::ONE);
+	/// let b : Vec3vec = (0..5).map(|i| Vec3 { x: i as f32, y: 0.0, z: 0.0 }).collect();
+	/// assert_eq!((&a - &b)[..4], [Vec3::ONE; 4]);
+	/// ```
+	pub fn push(&mut self, v: Vector3<T>) {
+		self.0.push(v)
+	}
+
+	pub fn pop(&mut self) -> Option<Vector3<T>> {
+		self.0.pop()
+	}
+
+	/// Shortens the array to `len` vectors, doing nothing if it is already that short.
+	pub fn truncate(&mut self, len: usize) {
+		self.0.truncate(len)
+	}
+
+	/// Makes room for at least `additional` more vectors before the next allocation.
+	pub fn reserve(&mut self, additional: usize) {
+		self.0.reserve(additional)
+	}
+
+	pub fn clear(&mut self) {
+		self.0.clear()
+	}
+
+	/// Turns the array into a [Vec3box] without copying, unless it has spare capacity to give back.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::{Vec3box, Vec3vec, Vec3win};
+	/// let mut a : Vec3vec = Vec3vec::with_capacity(3);
+	/// a.extend([Vec3::ONE; 3]);
+	/// let mut win : Vec3win = a.as_win();
+	/// win *= 2.0;
+	/// let b : Vec3box = a.into_box();
+	/// assert_eq!(b, Vec3box::ftoarr(|_| Vec3::ONE*2.0, 3));
+	/// assert_eq!(Vec3vec::from(b).capacity(), 3);
+	/// ```
+	pub fn into_box(self) -> Vector3box<T> {
+		Vector3box(self.0.into_boxed_slice())
+	}
+
+	/// Borrows the vectors as a [Vec3win].
+	pub fn as_win(&mut self) -> Vector3win<'_, T> {
+		Vector3win(&mut self.0[..])
+	}
+
+	pub fn transform<F: Fn(Vector3<T>) -> Vector3<T>>(&mut self, f: F) {
+		for v in self.iter_mut() {
+			*v = f(*v);
+		}
+	}
+}
+
+impl<T: Scalar> Default for Vector3vec<T> {
+	fn default() -> Vector3vec<T> {
+		Vector3vec::new()
+	}
+}
+
+impl<T: Scalar> From<Vector3box<T>> for Vector3vec<T> {
+	fn from(arr: Vector3box<T>) -> Vector3vec<T> {
+		Vector3vec(arr.0.into_vec())
+	}
+}
+
+impl<T: Scalar> From<Vector3vec<T>> for Vector3box<T> {
+	fn from(arr: Vector3vec<T>) -> Vector3box<T> {
+		arr.into_box()
+	}
+}
+
+impl<T: Scalar> From<Vec<Vector3<T>>> for Vector3vec<T> {
+	fn from(v: Vec<Vector3<T>>) -> Vector3vec<T> {
+		Vector3vec(v)
+	}
+}
+
+impl<T: Scalar> FromIterator<Vector3<T>> for Vector3vec<T> {
+	fn from_iter<I: IntoIterator<Item = Vector3<T>>>(iter: I) -> Vector3vec<T> {
+		Vector3vec(iter.into_iter().collect())
+	}
+}
+
+impl<T: Scalar> Extend<Vector3<T>> for Vector3vec<T> {
+	fn extend<I: IntoIterator<Item = Vector3<T>>>(&mut self, iter: I) {
+		self.0.extend(iter)
+	}
+}
+
+impl<'a, T: Scalar> Extend<&'a Vector3<T>> for Vector3vec<T> {
+	fn extend<I: IntoIterator<Item = &'a Vector3<T>>>(&mut self, iter: I) {
+		self.0.extend(iter)
+	}
+}
+
+// Scalar Views
+
+impl<const N: usize, T: Scalar> Vector3arr<N, T> {
+
+	/// The components of the vectors in order, as x, y, z, x, y, z ...
+	pub fn as_scalar_slice(&self) -> &[T] {
+		simd::flat(&self.0)
+	}
+
+	pub fn as_scalar_slice_mut(&mut self) -> &mut [T] {
+		simd::flat_mut(&mut self.0)
+	}
+
+	/// Copies vectors out of their components, panicking if the slice is not 3N long.
+	pub fn from_scalar_slice(s : &[T]) -> Vector3arr<N, T> {
+		if s.len() != 3*N { panic!("slice and array inequal length"); }
+		let v : &[Vector3<T>] = simd::unflat(s);
+		Vector3arr::ftoarr(|i| v[i])
+	}
+}
+
+impl<T: Scalar> Vector3box<T> {
+
+	/// The components of the vectors in order, as x, y, z, x, y, z ...
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vector3;
+	/// use lineq::vec3arr::{Vector3box, Vector3win};
+	/// let a : Vector3box<f64> = Vector3box::ftoarr(|i| Vector3 { x: i as f64, y: 1.0, z: 2.0 }, 2);
+	/// assert_eq!(a.as_scalar_slice(), &[0.0, 1.0, 2.0, 1.0, 1.0, 2.0]);
+	/// assert_eq!(Vector3box::from_scalar_slice(a.as_scalar_slice()), a);
+	/// let mut floats : [f64; 6] = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+	/// let mut win : Vector3win<f64> = Vector3win::from_scalar_slice(&mut floats);
+	/// win *= 2.0;
+	/// assert_eq!(floats, [2.0, 0.0, 0.0, 0.0, 2.0, 0.0]);
+	/// ```
+	pub fn as_scalar_slice(&self) -> &[T] {
+		simd::flat(&self.0)
+	}
+
+	pub fn as_scalar_slice_mut(&mut self) -> &mut [T] {
+		simd::flat_mut(&mut self.0)
+	}
+
+	/// Copies vectors out of their components, panicking if the length is not a multiple of 3.
+	pub fn from_scalar_slice(s : &[T]) -> Vector3box<T> {
+		Vector3box(simd::unflat(s).into())
+	}
+}
+
+impl<'a, T: Scalar> Vector3win<'a, T> {
+
+	/// The components of the vectors in order, as x, y, z, x, y, z ...
+	pub fn as_scalar_slice(&self) -> &[T] {
+		simd::flat(self.0)
+	}
+
+	pub fn as_scalar_slice_mut(&mut self) -> &mut [T] {
+		simd::flat_mut(self.0)
+	}
+
+	/// Borrows components as vectors without copying, panicking if the length is not a multiple of 3.
+	pub fn from_scalar_slice(s : &'a mut [T]) -> Vector3win<'a, T> {
+		Vector3win(simd::unflat_mut(s))
+	}
+}
+
+// f32 Views
+
+impl<const N: usize> Vector3arr<N, f32> {
+
+	/// The same as [as_scalar_slice](Vec3arr::as_scalar_slice).
+	pub fn as_f32_slice(&self) -> &[f32] {
+		self.as_scalar_slice()
+	}
+
+	/// The same as [as_scalar_slice_mut](Vec3arr::as_scalar_slice_mut).
+	pub fn as_f32_slice_mut(&mut self) -> &mut [f32] {
+		self.as_scalar_slice_mut()
+	}
+
+	/// The same as [from_scalar_slice](Vec3arr::from_scalar_slice).
+	pub fn from_f32_slice(s : &[f32]) -> Vector3arr<N, f32> {
+		Vector3arr::from_scalar_slice(s)
+	}
+}
+
+impl Vector3box<f32> {
+
+	/// The same as [as_scalar_slice](Vec3box::as_scalar_slice).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::Vec3box;
+	/// let a : Vec3box = Vec3box::ftoarr(|i| Vec3 { x: i as f32, y: 1.0, z: 2.0 }, 2);
+	/// assert_eq!(a.as_f32_slice(), &[0.0, 1.0, 2.0, 1.0, 1.0, 2.0]);
+	/// assert_eq!(Vec3box::from_f32_slice(a.as_f32_slice()), a);
+	/// ```
+	pub fn as_f32_slice(&self) -> &[f32] {
+		self.as_scalar_slice()
+	}
+
+	/// The same as [as_scalar_slice_mut](Vec3box::as_scalar_slice_mut).
+	pub fn as_f32_slice_mut(&mut self) -> &mut [f32] {
+		self.as_scalar_slice_mut()
+	}
+
+	/// The same as [from_scalar_slice](Vec3box::from_scalar_slice).
+	pub fn from_f32_slice(s : &[f32]) -> Vector3box<f32> {
+		Vector3box::from_scalar_slice(s)
+	}
+}
+
+impl<'a> Vector3win<'a, f32> {
+
+	/// The same as [as_scalar_slice](Vec3win::as_scalar_slice).
+	pub fn as_f32_slice(&self) -> &[f32] {
+		self.as_scalar_slice()
+	}
+
+	/// The same as [as_scalar_slice_mut](Vec3win::as_scalar_slice_mut).
+	pub fn as_f32_slice_mut(&mut self) -> &mut [f32] {
+		self.as_scalar_slice_mut()
+	}
+
+	/// The same as [from_scalar_slice](Vec3win::from_scalar_slice).
+	pub fn from_f32_slice(s : &'a mut [f32]) -> Vector3win<'a, f32> {
+		Vector3win::from_scalar_slice(s)
+	}
+}
+
 // Vec3raw Methods
 
-impl Vec3raw {
+impl<T: Scalar> Vector3raw<T> {
 
 	fn layout(len: usize) -> Layout {
-		Layout::from_size_align(Layout::new::<Vec3>().size()*len,Layout::new::<Vec3>().align()).unwrap()
+		Layout::array::<Vector3<T>>(len).unwrap()
 	}
 
-	pub unsafe fn new_uninit_ptr(len: usize) -> *mut [MaybeUninit<Vec3>] {
-		let ptr = alloc(Vec3raw::layout(len)) as *mut MaybeUninit<Vec3>;
+	/// Allocates space for `len` vectors with the same layout a `Vec3raw` frees with.
+	/// An empty slice gets a dangling pointer rather than an allocation.
+	///
+	/// # Safety
+	///
+	/// The vectors are uninitialized, and the memory is leaked unless it is passed to
+	/// [from_raw](Vec3raw::from_raw) once initialized.
+	pub unsafe fn new_uninit_ptr(len: usize) -> *mut [MaybeUninit<Vector3<T>>] {
+		if len == 0 { return slice_from_raw_parts_mut(NonNull::dangling().as_ptr(), 0); }
+		let ptr = alloc(Vector3raw::<T>::layout(len)) as *mut MaybeUninit<Vector3<T>>;
+		if ptr.is_null() { handle_alloc_error(Vector3raw::<T>::layout(len)); }
 		slice_from_raw_parts_mut(ptr, len)
 	}
 
-	pub fn from_raw_parts(ptr: *mut Vec3, len: usize) -> Vec3raw {
-		Vec3raw(slice_from_raw_parts_mut(ptr, len))
+	/// Takes ownership of `len` vectors starting at `ptr`, see [from_raw](Vec3raw::from_raw).
+	///
+	/// # Safety
+	///
+	/// The same as [from_raw](Vec3raw::from_raw).
+	pub unsafe fn from_raw_parts(ptr: *mut Vector3<T>, len: usize) -> Vector3raw<T> {
+		Vector3raw(slice_from_raw_parts_mut(ptr, len))
+	}
+
+	/// Takes ownership of a pointer, which is freed when the `Vec3raw` is dropped.
+	///
+	/// # Safety
+	///
+	/// The pointer must have come from [into_raw](Vec3raw::into_raw) or
+	/// [new_uninit_ptr](Vec3raw::new_uninit_ptr) with every vector initialized, and must not
+	/// be used again except through the returned `Vec3raw`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3arr::Vec3raw;
+	/// let a : Vec3raw = Vec3raw::new_zeroed(4);
+	/// let ptr = a.into_raw();
+	/// // the vectors are still allocated and can be handed to other code here
+	/// let b : Vec3raw = unsafe { Vec3raw::from_raw(ptr) };
+	/// assert_eq!(b.len(), 4);
+	/// assert_eq!(b[3], Vec3::ZERO);
+	/// ```
+	pub unsafe fn from_raw(ptr: *mut [Vector3<T>]) -> Vector3raw<T> {
+		Vector3raw(ptr)
+	}
+
+	/// Gives up ownership of the vectors without freeing them, returning the pointer to them.
+	pub fn into_raw(self) -> *mut [Vector3<T>] {
+		let ptr = self.0;
+		std::mem::forget(self);
+		ptr
+	}
+
+	/// Gives up ownership of the vectors without freeing them, returning a reference
+	/// that lives for the rest of the program.
+	pub fn leak(self) -> &'static mut [Vector3<T>] {
+		unsafe { &mut *self.into_raw() }
 	}
 
-	pub fn new_zeroed(len: usize) -> Vec3raw {
-		let ptr = unsafe { Vec3raw::new_uninit_ptr(len) };
+	pub fn new_zeroed(len: usize) -> Vector3raw<T> {
+		let ptr = unsafe { Vector3raw::<T>::new_uninit_ptr(len) };
 		for i in 0..len {
-			unsafe { (*ptr.as_mut_ptr().add(i)).write(Vec3::ZERO) };
+			unsafe { (*ptr.as_mut_ptr().add(i)).write(Vector3::ZERO) };
 		}
-		Vec3raw(ptr as *mut [Vec3])		
+		Vector3raw(ptr as *mut [Vector3<T>])		
 	}
 
-	pub fn new_from_arr<const N: usize>(arr: Vec3arr<N>) -> Vec3raw {
-		let ptr = unsafe { Vec3raw::new_uninit_ptr(N) };
+	pub fn new_from_arr<const N: usize>(arr: Vector3arr<N, T>) -> Vector3raw<T> {
+		let ptr = unsafe { Vector3raw::<T>::new_uninit_ptr(N) };
                 for i in 0..N {
 			unsafe { (*ptr.as_mut_ptr().add(i)).write(arr[i]) };
                 }
-                Vec3raw(ptr as *mut [Vec3])
+                Vector3raw(ptr as *mut [Vector3<T>])
 	}
 
-	pub fn new_from_box(arr: Vec3box) -> Vec3raw {
-		let ptr = unsafe { Vec3raw::new_uninit_ptr(arr.len()) };
+	pub fn new_from_box(arr: Vector3box<T>) -> Vector3raw<T> {
+		let ptr = unsafe { Vector3raw::<T>::new_uninit_ptr(arr.len()) };
                 for i in 0..arr.len() {
                         unsafe { (*ptr.as_mut_ptr().add(i)).write(arr[i]) };
                 }
-                Vec3raw(ptr as *mut [Vec3])
+                Vector3raw(ptr as *mut [Vector3<T>])
 	}
 
-	pub fn new_from_win<'a>(arr : Vec3win<'a>) -> Vec3raw {
-		let ptr = unsafe { Vec3raw::new_uninit_ptr(arr.len()) };
+	pub fn new_from_win<'a>(arr : Vector3win<'a, T>) -> Vector3raw<T> {
+		let ptr = unsafe { Vector3raw::<T>::new_uninit_ptr(arr.len()) };
                 for i in 0..arr.len() {
                         unsafe { (*ptr.as_mut_ptr().add(i)).write(arr[i]) };
                 }
-                Vec3raw(ptr as *mut [Vec3])
+                Vector3raw(ptr as *mut [Vector3<T>])
 	}
 
-	pub fn new_from_raw<'a>(arr : Vec3raw) -> Vec3raw {
-                let ptr = unsafe { Vec3raw::new_uninit_ptr(arr.len()) };
+	pub fn new_from_raw(arr : Vector3raw<T>) -> Vector3raw<T> {
+                let ptr = unsafe { Vector3raw::<T>::new_uninit_ptr(arr.len()) };
                 for i in 0..arr.len() {
                         unsafe { (*ptr.as_mut_ptr().add(i)).write(arr[i]) };
                 }
-                Vec3raw(ptr as *mut [Vec3])
+                Vector3raw(ptr as *mut [Vector3<T>])
         }
 
-	pub fn from_arr<const N: usize>(&mut self, arr: Vec3arr<N>) {
+	pub fn from_arr<const N: usize>(&mut self, arr: Vector3arr<N, T>) {
                 if self.len() != arr.len() { panic!("slice and array inequal length"); }
 		for i in 0..self.len() {
 			self[i] = arr[i];
 		}
         }
 
-        pub fn from_box(&mut self, arr: Vec3box) {
+        pub fn from_box(&mut self, arr: Vector3box<T>) {
                 if self.len() != arr.len() { panic!("slices inequal length"); }
 		for i in 0..self.len() {
                         self[i] = arr[i];
                 }
         }
 
-        pub fn from_win<'a>(&mut self, arr : Vec3win<'a>) {
+        pub fn from_win<'a>(&mut self, arr : Vector3win<'a, T>) {
                 if self.len() != arr.len() { panic!("slices inequal length"); }
 		for i in 0..self.len() {
                         self[i] = arr[i];
                 }
         }
 
-	pub fn to_arr<const N: usize>(&self) -> Vec3arr<N> {
+	pub fn to_arr<const N: usize>(&self) -> Vector3arr<N, T> {
 		if self.len() != N { panic!("slice and array inequal length"); }
-		let mut tmp: Vec3arr<N> = unsafe { MaybeUninit::uninit().assume_init() };
-		for i in 0..N {
-                        tmp[i] = self[i];
-                }
-                unsafe { std::mem::transmute::<_, Vec3arr<N>>(tmp) }
+		Vector3arr::ftoarr(|i| self[i])
         }
 
-        pub fn to_box(&self) -> Vec3box {
-		let mut tmp = Box::<[Vec3]>::new_uninit_slice(self.len());
+        pub fn to_box(&self) -> Vector3box<T> {
+		let mut tmp = Box::<[Vector3<T>]>::new_uninit_slice(self.len());
 		let tmp = unsafe {
                         for i in 0..self.len() {
                                 tmp[i].as_mut_ptr().write(self[i]);
                         }
                         tmp.assume_init()
                 };
-		Vec3box(tmp)
+		Vector3box(tmp)
         }
 
-	pub fn transform<F: Fn(Vec3) -> Vec3>(&mut self, f: F) {
+	/// Copies from a [Vec3arr], failing instead of panicking if the lengths differ.
+	pub fn try_from_arr<const N: usize>(&mut self, arr: Vector3arr<N, T>) -> Result<(), LineqError> {
+		check_len(self.len(), N)?;
+		self.from_arr(arr);
+		Ok(())
+	}
+
+	/// Copies from a [Vec3box], failing instead of panicking if the lengths differ.
+	pub fn try_from_box(&mut self, arr: Vector3box<T>) -> Result<(), LineqError> {
+		check_len(self.len(), arr.len())?;
+		self.from_box(arr);
+		Ok(())
+	}
+
+	/// Copies from a [Vec3win], failing instead of panicking if the lengths differ.
+	pub fn try_from_win<'a>(&mut self, arr : Vector3win<'a, T>) -> Result<(), LineqError> {
+		check_len(self.len(), arr.len())?;
+		self.from_win(arr);
+		Ok(())
+	}
+
+	/// Copies into a [Vec3arr], failing instead of panicking if the lengths differ.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3arr::Vec3raw;
+	/// use lineq::error::LineqError;
+	/// let a : Vec3raw = Vec3raw::new_zeroed(3);
+	/// assert!(a.try_to_arr::<3>().is_ok());
+	/// assert_eq!(a.try_to_arr::<4>(), Err(LineqError::LengthMismatch { expected: 4, found: 3 }));
+	/// ```
+	pub fn try_to_arr<const N: usize>(&self) -> Result<Vector3arr<N, T>, LineqError> {
+		check_len(N, self.len())?;
+		Ok(self.to_arr())
+	}
+
+	pub fn transform<F: Fn(Vector3<T>) -> Vector3<T>>(&mut self, f: F) {
                 for i in 0..self.len() {
                         self[i] = f(self[i]);
                 }
         }
 }
+
+// Vec3Array
+use std::convert::TryFrom;
+
+/// The methods shared by [Vec3arr], [Vec3box], [Vec3vec], [Vec3win] and [Vec3raw], for code that
+/// works with any of them.
+///
+/// Results come back the same way as from the operators, so a [Vec3arr] returns arrays of the
+/// same length and a [Vec3vec] returns another [Vec3vec], while the rest allocate boxes. Arrays
+/// given as `rhs` are taken as slices, and have to be the same length as `self`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lineq;
+/// use lineq::vec3::Vec3;
+/// use lineq::vec3arr::{Vec3arr, Vec3box, Vec3win, Vec3raw, Vec3Array};
+/// fn longest<A: Vec3Array>(a : &A) -> Vec3 {
+///     let mag = a.mag();
+///     let i : usize = (0..a.len()).fold(0, |j, i| if mag.as_ref()[i] > mag.as_ref()[j] { i } else { j });
+///     a.as_slice()[i]
+/// }
+/// let mut a : Vec3arr<3> = Vec3arr([Vec3::UP, Vec3::ONE, Vec3::LEFT]);
+/// let mut b : Vec3box = a.to_box();
+/// let c : Vec3raw = a.to_raw();
+/// assert_eq!(longest(&a), Vec3::ONE);
+/// assert_eq!(longest(&b), Vec3::ONE);
+/// assert_eq!(longest(&Vec3win(&mut b[1..])), Vec3::ONE);
+/// assert_eq!(longest(&c), Vec3::ONE);
+/// Vec3Array::transform(&mut a, |v| v*2.0);
+/// assert_eq!(a.zip_map(&c, |u, v| u - v*2.0), Vec3arr([Vec3::ZERO; 3]));
+/// assert_eq!(a.dot(&c), [2.0, 6.0, 2.0]);
+/// assert_eq!(Vec3Array::crossarr(&b, &*a), Vec3box::new_zeroed(3));
+/// assert_eq!(c.to_arr::<3>(), Vec3arr([Vec3::UP, Vec3::ONE, Vec3::LEFT]));
+/// ```
+pub trait Vec3Array<T: Scalar = f32> {
+	/// The array that vectors are returned in, a [Vec3arr] for [Vec3arr], a [Vec3vec]
+	/// for [Vec3vec] and a [Vec3box] for the rest.
+	type Vecs : Vec3Array<T>;
+	/// The array that scalars are returned in, `[T; N]` for [Vec3arr] and `Box<[T]>` for the rest.
+	type Scalars : AsRef<[T]> + AsMut<[T]>;
+
+	fn as_slice(&self) -> &[Vector3<T>];
+
+	fn as_mut_slice(&mut self) -> &mut [Vector3<T>];
+
+	fn mag(&self) -> Self::Scalars;
+
+	fn mag2(&self) -> Self::Scalars;
+
+	fn norm(&self) -> Self::Vecs;
+
+	fn cross(&self, rhs : Vector3<T>) -> Self::Vecs;
+
+	fn crossarr(&self, rhs : &[Vector3<T>]) -> Self::Vecs;
+
+	fn dot(&self, rhs : &[Vector3<T>]) -> Self::Scalars;
+
+	/// Applies `f` to each vector and the one at the same index in `rhs`.
+	fn zip_map<F: Fn(Vector3<T>, Vector3<T>) -> Vector3<T>>(&self, rhs : &[Vector3<T>], f : F) -> Self::Vecs;
+
+	fn len(&self) -> usize {
+		self.as_slice().len()
+	}
+
+	fn is_empty(&self) -> bool {
+		self.as_slice().is_empty()
+	}
+
+	fn transform<F: Fn(Vector3<T>) -> Vector3<T>>(&mut self, f: F) {
+		for v in self.as_mut_slice().iter_mut() {
+			*v = f(*v);
+		}
+	}
+
+	fn to_arr<const N: usize>(&self) -> Vector3arr<N, T> {
+		match <[Vector3<T>; N]>::try_from(self.as_slice()) {
+			Ok(arr) => Vector3arr(arr),
+			Err(_) => panic!("slice and array inequal length"),
+		}
+	}
+
+	/// Copies into a [Vec3arr], failing instead of panicking if the lengths differ.
+	fn try_to_arr<const N: usize>(&self) -> Result<Vector3arr<N, T>, LineqError> {
+		check_len(N, self.len())?;
+		Ok(self.to_arr())
+	}
+
+	fn to_box(&self) -> Vector3box<T> {
+		Vector3box(self.as_slice().into())
+	}
+
+	fn to_raw(&self) -> Vector3raw<T> {
+		let mut raw : Vector3raw<T> = Vector3raw::new_zeroed(self.len());
+		raw.copy_from_slice(self.as_slice());
+		raw
+	}
+}
+
+impl<const N: usize, T: Scalar> Vec3Array<T> for Vector3arr<N, T> {
+	type Vecs = Vector3arr<N, T>;
+	type Scalars = [T; N];
+
+	fn as_slice(&self) -> &[Vector3<T>] {
+		&self[..]
+	}
+
+	fn as_mut_slice(&mut self) -> &mut [Vector3<T>] {
+		&mut self[..]
+	}
+
+	fn mag(&self) -> [T; N] {
+		Vector3arr::mag(self)
+	}
+
+	fn mag2(&self) -> [T; N] {
+		Vector3arr::mag2(self)
+	}
+
+	fn norm(&self) -> Vector3arr<N, T> {
+		Vector3arr::norm(self)
+	}
+
+	fn cross(&self, rhs : Vector3<T>) -> Vector3arr<N, T> {
+		Vector3arr::cross(self, rhs)
+	}
+
+	fn crossarr(&self, rhs : &[Vector3<T>]) -> Vector3arr<N, T> {
+		if rhs.len() != N { panic!("slice and array inequal length"); }
+		Vector3arr::ftoarr(|i| self[i].cross(rhs[i]))
+	}
+
+	fn dot(&self, rhs : &[Vector3<T>]) -> [T; N] {
+		if rhs.len() != N { panic!("slice and array inequal length"); }
+		std::array::from_fn(|i| self[i]*rhs[i])
+	}
+
+	fn zip_map<F: Fn(Vector3<T>, Vector3<T>) -> Vector3<T>>(&self, rhs : &[Vector3<T>], f : F) -> Vector3arr<N, T> {
+		if rhs.len() != N { panic!("slice and array inequal length"); }
+		Vector3arr::ftoarr(|i| f(self[i], rhs[i]))
+	}
+}
+
+macro_rules! array_impl {
+        (Array $t:ty; out: $out:ident$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?T: Scalar> Vec3Array<T> for $t {
+                        type Vecs = $out<T>;
+                        type Scalars = Box<[T]>;
+
+                        fn as_slice(&self) -> &[Vector3<T>] {
+                                &self[..]
+                        }
+
+                        fn as_mut_slice(&mut self) -> &mut [Vector3<T>] {
+                                &mut self[..]
+                        }
+
+                        fn mag(&self) -> Box<[T]> {
+                                let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+                                simd::mag(&mut tmp, self);
+                                unsafe { tmp.assume_init() }
+                        }
+
+                        fn mag2(&self) -> Box<[T]> {
+                                let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+                                simd::mag2(&mut tmp, self);
+                                unsafe { tmp.assume_init() }
+                        }
+
+                        fn norm(&self) -> $out<T> {
+                                let mut tmp = Vector3box::new_uninit_box(self.len());
+                                simd::norm(&mut tmp, self);
+                                Vector3box(unsafe { tmp.assume_init() }).into()
+                        }
+
+                        fn cross(&self, rhs : Vector3<T>) -> $out<T> {
+                                Vector3box::ftoarr(|i| self[i].cross(rhs), self.len()).into()
+                        }
+
+                        fn crossarr(&self, rhs : &[Vector3<T>]) -> $out<T> {
+                                let mut tmp = Vector3box::new_uninit_box(self.len());
+                                simd::cross(&mut tmp, self, rhs);
+                                Vector3box(unsafe { tmp.assume_init() }).into()
+                        }
+
+                        fn dot(&self, rhs : &[Vector3<T>]) -> Box<[T]> {
+                                let mut tmp = Box::<[T]>::new_uninit_slice(self.len());
+                                simd::dot(&mut tmp, self, rhs);
+                                unsafe { tmp.assume_init() }
+                        }
+
+                        fn zip_map<F: Fn(Vector3<T>, Vector3<T>) -> Vector3<T>>(&self, rhs : &[Vector3<T>], f : F) -> $out<T> {
+                                if self.len() != rhs.len() { panic!("slices inequal length"); }
+                                Vector3box::ftoarr(|i| f(self[i], rhs[i]), self.len()).into()
+                        }
+                }
+        };
+}
+
+array_impl! {Array Vector3box<T>; out: Vector3box}
+array_impl! {Array Vector3vec<T>; out: Vector3vec}
+array_impl! {Array Vector3win<'a, T>; out: Vector3box; <'a>}
+array_impl! {Array Vector3raw<T>; out: Vector3box}
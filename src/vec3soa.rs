@@ -0,0 +1,562 @@
+//! Module containing arrays of [Vec3](crate::vec3::Vec3) stored as a structure of arrays.
+//!
+//! [Vec3soa](crate::vec3soa::Vec3soa) keeps the x, y and z components in three separate buffers,
+//! each aligned to 64 bytes, instead of interleaving them like [Vec3box](crate::vec3arr::Vec3box).
+//! Batch operations then run over contiguous runs of one scalar type, which the compiler can
+//! turn into SIMD instructions without first gathering the components apart. Since the boxed
+//! arrays run on SIMD kernels too, the gain is modest, from about the same speed for `+=` to
+//! between 1.1 and 1.3 times faster for `mag`, `norm`, `crossarr` and the dot product on a
+//! million vectors; the measurements are listed in `benches/vec3soa.rs`.
+//! Conversions to and from the array of structures types copy every vector once, but a
+//! [Vec3arr](crate::vec3arr::Vec3arr), [Vec3box](crate::vec3arr::Vec3box) or
+//! [Vec3win](crate::vec3arr::Vec3win) can be added to or subtracted from a `Vec3soa` directly.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lineq;
+//! use lineq::vec3::Vec3;
+//! use lineq::vec3arr::{Vec3arr, Vec3box, Vec3win};
+//! use lineq::vec3soa::Vec3soa;
+//! let a : Vec3soa = Vec3soa::ftoarr(|i| Vec3 { x: i as f32, y: 0.0, z: 0.0 }, 4);
+//! let b : Vec3soa = Vec3soa::new_from_box(&Vec3box(Box::new([Vec3::UP; 4])));
+//! assert_eq!(&*(&a * &b), &[0.0; 4]);
+//! let c : Vec3soa = a + b;
+//! assert_eq!(c.get(3), Vec3 { x: 3.0, y: 1.0, z: 0.0 });
+//! assert_eq!(c.to_box()[3], Vec3 { x: 3.0, y: 1.0, z: 0.0 });
+//! let mut d : Vec3box = Vec3box(Box::new([Vec3::UP; 4]));
+//! let e : Vec3soa = &c - &d;
+//! assert_eq!(e.get(3), Vec3 { x: 3.0, y: 0.0, z: 0.0 });
+//! assert_eq!(e + Vec3win(&mut d), c);
+//! let mut f : Vec3soa = c.clone();
+//! f -= &Vec3arr([Vec3::UP; 4]);
+//! assert_eq!(f.y(), &[0.0; 4]);
+//! ```
+
+use crate::scalar::Scalar;
+use crate::vec3::Vector3;
+use crate::vec3arr::{Vector3arr, Vector3box, Vector3win};
+use std::alloc::Layout;
+use std::alloc::alloc;
+use std::alloc::dealloc;
+use std::alloc::handle_alloc_error;
+use std::ptr::NonNull;
+use std::ptr::slice_from_raw_parts_mut;
+use std::ops::Range;
+
+// The alignment of each component buffer, a cache line and the width of the widest vector registers.
+const ALIGN : usize = 64;
+
+// A boxed slice of scalars aligned to ALIGN bytes.
+struct AlignedBox<T>(*mut [T]);
+
+/// An array of vectors stored as three aligned buffers, one for each component.
+pub struct Vector3soa<T> {
+        x : AlignedBox<T>,
+        y : AlignedBox<T>,
+        z : AlignedBox<T>,
+}
+
+/// A [Vec3soa] of [f32]s.
+pub type Vec3soa = Vector3soa<f32>;
+
+/// An iterator over copies of the vectors in a [Vec3soa], returned by [iter](Vec3soa::iter).
+pub struct Iter<'a, T = f32> {
+        soa : &'a Vector3soa<T>,
+        range : Range<usize>,
+}
+
+/// An iterator that moves the vectors out of a [Vec3soa].
+pub struct IntoIter<T = f32> {
+        soa : Vector3soa<T>,
+        range : Range<usize>,
+}
+
+// An AlignedBox owns its allocation like a Box<[T]>.
+unsafe impl<T: Send> Send for AlignedBox<T> {}
+unsafe impl<T: Sync> Sync for AlignedBox<T> {}
+
+impl<T: Scalar> AlignedBox<T> {
+	fn layout(len: usize) -> Layout {
+		Layout::array::<T>(len).and_then(|l| l.align_to(ALIGN)).unwrap()
+	}
+
+	fn new_filled(val: T, len: usize) -> AlignedBox<T> {
+		if len == 0 { return AlignedBox(slice_from_raw_parts_mut(NonNull::dangling().as_ptr(), 0)); }
+		let ptr = unsafe { alloc(AlignedBox::<T>::layout(len)) } as *mut T;
+		if ptr.is_null() { handle_alloc_error(AlignedBox::<T>::layout(len)); }
+		for i in 0..len {
+			unsafe { ptr.add(i).write(val) };
+		}
+		AlignedBox(slice_from_raw_parts_mut(ptr, len))
+	}
+}
+
+impl<T> Drop for AlignedBox<T> {
+	fn drop(&mut self) {
+		let len : usize = self.0.len();
+		if len != 0 {
+			let layout : Layout = Layout::array::<T>(len).and_then(|l| l.align_to(ALIGN)).unwrap();
+			unsafe { dealloc(self.0 as *mut u8, layout) };
+		}
+	}
+}
+
+impl<T: Scalar> Clone for AlignedBox<T> {
+	fn clone(&self) -> AlignedBox<T> {
+		let mut tmp : AlignedBox<T> = AlignedBox::new_filled(T::ZERO, self.len());
+		tmp.copy_from_slice(self);
+		tmp
+	}
+}
+
+macro_rules! value_impl {
+        ($imp:ident;$func:ident;$aimp:ident;$afunc:ident; $rhs:ty$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> $imp<$rhs> for Vector3soa<T> {
+                        type Output = Vector3soa<T>;
+                        #[inline]
+                        fn $func(mut self, rhs: $rhs) -> Vector3soa<T> {
+                                $aimp::$afunc(&mut self, rhs);
+                                self
+                        }
+                }
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> $imp<$rhs> for &Vector3soa<T> {
+                        type Output = Vector3soa<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector3soa<T> {
+                                let mut tmp : Vector3soa<T> = self.clone();
+                                $aimp::$afunc(&mut tmp, rhs);
+                                tmp
+                        }
+                }
+        };
+}
+
+macro_rules! inplace_impl {
+        ($imp:ident;$func:ident;$op:tt; 0 T) => {
+                impl<T: Scalar> $imp<T> for Vector3soa<T> {
+                        #[inline]
+                        fn $func(&mut self, rhs: T) {
+                                for c in [&mut self.x, &mut self.y, &mut self.z] {
+                                        for a in c.iter_mut() {
+                                                *a $op rhs;
+                                        }
+                                }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 Vector3soa) => {
+                impl<T: Scalar> $imp<&Vector3soa<T>> for Vector3soa<T> {
+                        #[inline]
+                        fn $func(&mut self, rhs: &Vector3soa<T>) {
+                                if self.len() != rhs.len() { panic!("slices inequal length"); }
+                                for (c, r) in [(&mut self.x, &rhs.x), (&mut self.y, &rhs.y), (&mut self.z, &rhs.z)] {
+                                        for (a, b) in c.iter_mut().zip(r.iter()) {
+                                                *a $op *b;
+                                        }
+                                }
+                        }
+                }
+                impl<T: Scalar> $imp<Vector3soa<T>> for Vector3soa<T> {
+                        #[inline]
+                        fn $func(&mut self, rhs: Vector3soa<T>) {
+                                $imp::$func(self, &rhs);
+                        }
+                }
+        };
+        // an array of structures operand is scattered into the three buffers one vector at a time
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty$(; const $gen:ident: $gent:ty)?$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?$(const $gen: $gent,)?T: Scalar> $imp<$rhs> for Vector3soa<T> {
+                        #[inline]
+                        fn $func(&mut self, rhs: $rhs) {
+                                if self.len() != rhs.len() { panic!("slices inequal length"); }
+                                for (i, v) in rhs.iter().enumerate() {
+                                        self.x[i] $op v.x;
+                                        self.y[i] $op v.y;
+                                        self.z[i] $op v.z;
+                                }
+                        }
+                }
+        };
+}
+
+macro_rules! lhs_impl {
+        ($imp:ident;$func:ident;$aimp:ident;$afunc:ident; for $lhs:ident) => {
+                impl $imp<Vector3soa<$lhs>> for $lhs {
+                        type Output = Vector3soa<$lhs>;
+                        #[inline]
+                        fn $func(self, mut rhs: Vector3soa<$lhs>) -> Vector3soa<$lhs> {
+                                $aimp::$afunc(&mut rhs, self);
+                                rhs
+                        }
+                }
+        };
+}
+
+// Both iterators gather a vector out of the three buffers at each index of their range.
+macro_rules! iter_impl {
+        (Iter $t:ty$(; <$lt:lifetime>)?) => {
+                impl<$($lt,)?T: Scalar> Iterator for $t {
+                        type Item = Vector3<T>;
+                        #[inline]
+                        fn next(&mut self) -> Option<Vector3<T>> {
+                                self.range.next().map(|i| self.soa.get(i))
+                        }
+                        fn size_hint(&self) -> (usize, Option<usize>) {
+                                self.range.size_hint()
+                        }
+                }
+
+                impl<$($lt,)?T: Scalar> DoubleEndedIterator for $t {
+                        #[inline]
+                        fn next_back(&mut self) -> Option<Vector3<T>> {
+                                self.range.next_back().map(|i| self.soa.get(i))
+                        }
+                }
+
+                impl<$($lt,)?T: Scalar> ExactSizeIterator for $t {}
+        };
+}
+
+//Clone
+
+impl<T: Scalar> Clone for Vector3soa<T> {
+	fn clone(&self) -> Vector3soa<T> {
+		Vector3soa { x: self.x.clone(), y: self.y.clone(), z: self.z.clone() }
+	}
+}
+
+//Debug
+
+impl<T: Scalar> fmt::Debug for Vector3soa<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct("Vector3soa").field("x", &&*self.x).field("y", &&*self.y).field("z", &&*self.z).finish()
+        }
+}
+
+//Deref
+use std::ops::Deref;
+
+impl<T> Deref for AlignedBox<T> {
+	type Target = [T];
+	fn deref(&self) -> &[T] {
+		unsafe { &*self.0 }
+	}
+}
+
+//DerefMut
+use std::ops::DerefMut;
+
+impl<T> DerefMut for AlignedBox<T> {
+	fn deref_mut(&mut self) -> &mut [T] {
+		unsafe { &mut *self.0 }
+	}
+}
+
+//Add
+use std::ops::Add;
+
+value_impl! {Add;add;AddAssign;add_assign; T}
+value_impl! {Add;add;AddAssign;add_assign; Vector3soa<T>}
+value_impl! {Add;add;AddAssign;add_assign; &Vector3soa<T>}
+value_impl! {Add;add;AddAssign;add_assign; &Vector3arr<N, T>; const N: usize}
+value_impl! {Add;add;AddAssign;add_assign; &Vector3box<T>}
+value_impl! {Add;add;AddAssign;add_assign; Vector3win<'a, T>; <'a>}
+lhs_impl! {Add;add;AddAssign;add_assign; for f32}
+lhs_impl! {Add;add;AddAssign;add_assign; for f64}
+
+//AddAssign
+use std::ops::AddAssign;
+
+inplace_impl! {AddAssign;add_assign;+=; 0 T}
+inplace_impl! {AddAssign;add_assign;+=; 1 Vector3soa}
+inplace_impl! {AddAssign;add_assign;+=; 2 &Vector3arr<N, T>; const N: usize}
+inplace_impl! {AddAssign;add_assign;+=; 2 &Vector3box<T>}
+inplace_impl! {AddAssign;add_assign;+=; 2 Vector3win<'a, T>; <'a>}
+
+//Display
+use std::fmt;
+
+impl<T: Scalar> fmt::Display for Vector3soa<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "[")?;
+                for i in 0..self.len() {
+                        if i != 0 { write!(f, ", ")?; }
+                        write!(f, "<{}, {}, {}>", self.x[i], self.y[i], self.z[i])?;
+                }
+                write!(f, "]")
+        }
+}
+
+//Div
+use std::ops::Div;
+
+value_impl! {Div;div;DivAssign;div_assign; T}
+
+//DivAssign
+use std::ops::DivAssign;
+
+inplace_impl! {DivAssign;div_assign;/=; 0 T}
+
+//IntoIterator
+
+iter_impl! {Iter Iter<'a, T>; <'a>}
+iter_impl! {Iter IntoIter<T>}
+
+impl<'a, T: Scalar> IntoIterator for &'a Vector3soa<T> {
+        type Item = Vector3<T>;
+        type IntoIter = Iter<'a, T>;
+        fn into_iter(self) -> Iter<'a, T> {
+                self.iter()
+        }
+}
+
+impl<T: Scalar> IntoIterator for Vector3soa<T> {
+        type Item = Vector3<T>;
+        type IntoIter = IntoIter<T>;
+        fn into_iter(self) -> IntoIter<T> {
+                IntoIter { range: 0..self.len(), soa: self }
+        }
+}
+
+use std::iter::FromIterator;
+
+impl<T: Scalar> FromIterator<Vector3<T>> for Vector3soa<T> {
+        fn from_iter<I: IntoIterator<Item = Vector3<T>>>(iter: I) -> Vector3soa<T> {
+                let tmp : Vec<Vector3<T>> = iter.into_iter().collect();
+                Vector3soa::ftoarr(|i| tmp[i], tmp.len())
+        }
+}
+
+//Mult
+use std::ops::Mul;
+
+value_impl! {Mul;mul;MulAssign;mul_assign; T}
+lhs_impl! {Mul;mul;MulAssign;mul_assign; for f32}
+lhs_impl! {Mul;mul;MulAssign;mul_assign; for f64}
+
+impl<T: Scalar> Mul<&Vector3soa<T>> for &Vector3soa<T> {
+        type Output = Box<[T]>;
+        fn mul(self, rhs: &Vector3soa<T>) -> Box<[T]> {
+                self.dot(rhs)
+        }
+}
+
+impl<T: Scalar> Mul<Vector3soa<T>> for Vector3soa<T> {
+        type Output = Box<[T]>;
+        fn mul(self, rhs: Vector3soa<T>) -> Box<[T]> {
+                self.dot(&rhs)
+        }
+}
+
+//MultAssign
+use std::ops::MulAssign;
+
+inplace_impl! {MulAssign;mul_assign;*=; 0 T}
+
+//Neg
+use std::ops::Neg;
+
+impl<T: Scalar> Neg for Vector3soa<T> {
+        type Output = Vector3soa<T>;
+        fn neg(mut self) -> Vector3soa<T> {
+                self *= T::NEG_ONE;
+                self
+        }
+}
+
+//PartialEq
+
+impl<T: Scalar> PartialEq for Vector3soa<T> {
+	fn eq(&self, other: &Vector3soa<T>) -> bool {
+		*self.x == *other.x && *self.y == *other.y && *self.z == *other.z
+	}
+}
+
+//Sub
+use std::ops::Sub;
+
+value_impl! {Sub;sub;SubAssign;sub_assign; T}
+value_impl! {Sub;sub;SubAssign;sub_assign; Vector3soa<T>}
+value_impl! {Sub;sub;SubAssign;sub_assign; &Vector3soa<T>}
+value_impl! {Sub;sub;SubAssign;sub_assign; &Vector3arr<N, T>; const N: usize}
+value_impl! {Sub;sub;SubAssign;sub_assign; &Vector3box<T>}
+value_impl! {Sub;sub;SubAssign;sub_assign; Vector3win<'a, T>; <'a>}
+
+//SubAssign
+use std::ops::SubAssign;
+
+inplace_impl! {SubAssign;sub_assign;-=; 0 T}
+inplace_impl! {SubAssign;sub_assign;-=; 1 Vector3soa}
+inplace_impl! {SubAssign;sub_assign;-=; 2 &Vector3arr<N, T>; const N: usize}
+inplace_impl! {SubAssign;sub_assign;-=; 2 &Vector3box<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 2 Vector3win<'a, T>; <'a>}
+
+//Vec3soa Methods
+
+impl<T: Scalar> Vector3soa<T> {
+
+	pub fn new_zeroed(len: usize) -> Vector3soa<T> {
+		Vector3soa { x: AlignedBox::new_filled(T::ZERO, len), y: AlignedBox::new_filled(T::ZERO, len), z: AlignedBox::new_filled(T::ZERO, len) }
+	}
+
+	pub fn ftoarr<F: Fn(usize) -> Vector3<T>>(f: F, n: usize) -> Vector3soa<T> {
+		let mut tmp : Vector3soa<T> = Vector3soa::new_zeroed(n);
+		for i in 0..n {
+			tmp.set(i, f(i));
+		}
+		tmp
+	}
+
+	pub fn new_from_arr<const N: usize>(arr: &Vector3arr<N, T>) -> Vector3soa<T> {
+		Vector3soa::ftoarr(|i| arr[i], N)
+	}
+
+	pub fn new_from_box(arr: &Vector3box<T>) -> Vector3soa<T> {
+		Vector3soa::ftoarr(|i| arr[i], arr.len())
+	}
+
+	pub fn to_box(&self) -> Vector3box<T> {
+		Vector3box::ftoarr(|i| self.get(i), self.len())
+	}
+
+	pub fn to_arr<const N: usize>(&self) -> Vector3arr<N, T> {
+		if self.len() != N { panic!("slice and array inequal length"); }
+		Vector3arr::ftoarr(|i| self.get(i))
+	}
+
+	pub fn len(&self) -> usize {
+		self.x.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.x.is_empty()
+	}
+
+	pub fn get(&self, i: usize) -> Vector3<T> {
+		Vector3 { x: self.x[i], y: self.y[i], z: self.z[i] }
+	}
+
+	pub fn set(&mut self, i: usize, v: Vector3<T>) {
+		self.x[i] = v.x;
+		self.y[i] = v.y;
+		self.z[i] = v.z;
+	}
+
+	/// The x components of every vector.
+	pub fn x(&self) -> &[T] {
+		&self.x
+	}
+
+	pub fn y(&self) -> &[T] {
+		&self.y
+	}
+
+	pub fn z(&self) -> &[T] {
+		&self.z
+	}
+
+	/// Mutable slices of the x, y and z components, in that order.
+	pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T], &mut [T]) {
+		(&mut self.x, &mut self.y, &mut self.z)
+	}
+
+	pub fn dot(&self, rhs: &Vector3soa<T>) -> Box<[T]> {
+		if self.len() != rhs.len() { panic!("slices inequal length"); }
+		let mut tmp : Box<[T]> = vec![T::ZERO; self.len()].into_boxed_slice();
+		let rhs = rhs.x.iter().zip(rhs.y.iter()).zip(rhs.z.iter());
+		for ((a, ((x, y), z)), ((rx, ry), rz)) in tmp.iter_mut().zip(self.x.iter().zip(self.y.iter()).zip(self.z.iter())).zip(rhs) {
+			*a = *x * *rx + *y * *ry + *z * *rz;
+		}
+		tmp
+	}
+
+	pub fn crossarr(&self, rhs: &Vector3soa<T>) -> Vector3soa<T> {
+		if self.len() != rhs.len() { panic!("slices inequal length"); }
+		let n : usize = self.len();
+		let mut tmp : Vector3soa<T> = Vector3soa::new_zeroed(n);
+		// slicing everything to the same length lets the bounds checks be hoisted out of the loop
+		let (x, y, z) = (&self.x[..n], &self.y[..n], &self.z[..n]);
+		let (rx, ry, rz) = (&rhs.x[..n], &rhs.y[..n], &rhs.z[..n]);
+		let (tx, ty, tz) = tmp.as_mut_slices();
+		let (tx, ty, tz) = (&mut tx[..n], &mut ty[..n], &mut tz[..n]);
+		for i in 0..n {
+			tx[i] = y[i]*rz[i] - z[i]*ry[i];
+			ty[i] = z[i]*rx[i] - x[i]*rz[i];
+			tz[i] = x[i]*ry[i] - y[i]*rx[i];
+		}
+		tmp
+	}
+
+	pub fn cross(&self, rhs: Vector3<T>) -> Vector3soa<T> {
+		let mut tmp : Vector3soa<T> = Vector3soa::new_zeroed(self.len());
+		for i in 0..self.len() {
+			tmp.x[i] = self.y[i]*rhs.z - self.z[i]*rhs.y;
+			tmp.y[i] = self.z[i]*rhs.x - self.x[i]*rhs.z;
+			tmp.z[i] = self.x[i]*rhs.y - self.y[i]*rhs.x;
+		}
+		tmp
+	}
+
+	pub fn mag(&self) -> Box<[T]> {
+		let mut tmp : Box<[T]> = self.mag2();
+		for a in tmp.iter_mut() {
+			*a = a.sqrt();
+		}
+		tmp
+	}
+
+	pub fn mag2(&self) -> Box<[T]> {
+		let mut tmp : Box<[T]> = vec![T::ZERO; self.len()].into_boxed_slice();
+		for (a, ((x, y), z)) in tmp.iter_mut().zip(self.x.iter().zip(self.y.iter()).zip(self.z.iter())) {
+			*a = *x * *x + *y * *y + *z * *z;
+		}
+		tmp
+	}
+
+	pub fn norm(&self) -> Vector3soa<T> {
+		let n : usize = self.len();
+		let mut tmp : Vector3soa<T> = Vector3soa::new_zeroed(n);
+		let (x, y, z) = (&self.x[..n], &self.y[..n], &self.z[..n]);
+		let (tx, ty, tz) = tmp.as_mut_slices();
+		let (tx, ty, tz) = (&mut tx[..n], &mut ty[..n], &mut tz[..n]);
+		for i in 0..n {
+			let mag : T = (x[i]*x[i] + y[i]*y[i] + z[i]*z[i]).sqrt();
+			tx[i] = x[i]/mag;
+			ty[i] = y[i]/mag;
+			tz[i] = z[i]/mag;
+		}
+		tmp
+	}
+
+	pub fn transform<F: Fn(Vector3<T>) -> Vector3<T>>(&mut self, f: F) {
+		for i in 0..self.len() {
+			let v : Vector3<T> = f(self.get(i));
+			self.set(i, v);
+		}
+	}
+
+	/// Iterates over copies of the vectors, since they are not stored anywhere to be borrowed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec3soa::Vec3soa;
+	/// let a : Vec3soa = (0..4).map(|i| Vec3 { x: i as f32, y: 1.0, z: 0.0 }).collect();
+	/// assert_eq!(a.iter().rev().next(), Some(Vec3 { x: 3.0, y: 1.0, z: 0.0 }));
+	/// assert_eq!(a.iter().sum::<Vec3>(), Vec3 { x: 6.0, y: 4.0, z: 0.0 });
+	/// let b : Vec3soa = a.zip_map(&a, |u, v| u.cross(v));
+	/// assert!(b.into_iter().all(|v| v == Vec3::ZERO));
+	/// ```
+	pub fn iter(&self) -> Iter<'_, T> {
+		Iter { soa: self, range: 0..self.len() }
+	}
+
+	/// Applies `f` to each vector and the one at the same index in `rhs`.
+	pub fn zip_map<F: Fn(Vector3<T>, Vector3<T>) -> Vector3<T>>(&self, rhs: &Vector3soa<T>, f: F) -> Vector3soa<T> {
+		if self.len() != rhs.len() { panic!("slices inequal length"); }
+		Vector3soa::ftoarr(|i| f(self.get(i), rhs.get(i)), self.len())
+	}
+}
@@ -0,0 +1,308 @@
+use crate::scalar::Scalar;
+use crate::error::LineqError;
+use crate::vec3::Vector3;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Vector4<T> {
+        pub x : T,
+        pub y : T,
+        pub z : T,
+        pub w : T,
+}
+
+/// A [Vec4] of [f32]s.
+pub type Vec4 = Vector4<f32>;
+
+macro_rules! value_impl {
+        ($imp:ident;$func:ident;$op:tt; 3 $rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector4<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector4<T> {
+				Vector4 { x: self.x $op rhs.x, y: self.y $op rhs.y, z: self.z $op rhs.z, w: self.w $op rhs.w }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for &$lhs:ident) => {
+                impl $imp<$rhs> for &$lhs {
+                        type Output = Vector4<$lhs>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector4<$lhs> {
+                                Vector4 { x: *self $op rhs.x, y: *self $op rhs.y, z: *self $op rhs.z, w: *self $op rhs.w }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 2 $rhs:ty; for $lhs:ident) => {
+                impl $imp<$rhs> for $lhs {
+                        type Output = Vector4<$lhs>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector4<$lhs> {
+                                Vector4 { x: self $op rhs.x, y: self $op rhs.y, z: self $op rhs.z, w: self $op rhs.w }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 &$rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<&$rhs> for $lhs {
+                        type Output = Vector4<T>;
+                        #[inline]
+                        fn $func(self, rhs: &$rhs) -> Vector4<T> {
+                                Vector4 { x: self.x $op *rhs, y: self.y $op *rhs, z: self.z $op *rhs, w: self.w $op *rhs }
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        type Output = Vector4<T>;
+                        #[inline]
+                        fn $func(self, rhs: $rhs) -> Vector4<T> {
+                                Vector4 { x: self.x $op rhs, y: self.y $op rhs, z: self.z $op rhs, w: self.w $op rhs }
+                        }
+                }
+        };
+}
+
+macro_rules! inplace_impl {
+        ($imp:ident;$func:ident;$op:tt; 0 &$rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<&$rhs> for $lhs {
+                        #[inline]
+                        fn $func(&mut self, rhs: &$rhs) {
+                                self.x $op *rhs;
+                                self.y $op *rhs;
+				self.z $op *rhs;
+				self.w $op *rhs;
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 0 $rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        #[inline]
+                        fn $func(&mut self, rhs: $rhs) {
+                                self.x $op rhs;
+                                self.y $op rhs;
+				self.z $op rhs;
+				self.w $op rhs;
+                        }
+                }
+        };
+        ($imp:ident;$func:ident;$op:tt; 1 $rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> $imp<$rhs> for $lhs {
+                        #[inline]
+                        fn $func(&mut self, rhs: $rhs) {
+                                self.x $op rhs.x;
+                                self.y $op rhs.y;
+                        	self.z $op rhs.z;
+                        	self.w $op rhs.w;
+                        }
+                }
+        };
+}
+
+macro_rules! dot_impl {
+        (Dot $rhs:ty; for $lhs:ty) => {
+                impl<T: Scalar> Mul<$rhs> for $lhs {
+                        type Output = T;
+                        #[inline]
+                        fn mul(self, rhs: $rhs) -> T {
+				self.x*rhs.x+self.y*rhs.y+self.z*rhs.z+self.w*rhs.w
+                        }
+                }
+        };
+}
+
+//Add
+use std::ops::Add;
+
+value_impl! {Add;add;+; 1 T; for Vector4<T>}
+value_impl! {Add;add;+; 2 Vector4<f32>; for f32}
+value_impl! {Add;add;+; 2 Vector4<f64>; for f64}
+value_impl! {Add;add;+; 3 Vector4<T>; for Vector4<T>}
+value_impl! {Add;add;+; 1 &T; for Vector4<T>}
+value_impl! {Add;add;+; 2 &Vector4<f32>; for f32}
+value_impl! {Add;add;+; 2 &Vector4<f64>; for f64}
+value_impl! {Add;add;+; 3 &Vector4<T>; for Vector4<T>}
+value_impl! {Add;add;+; 1 T; for &Vector4<T>}
+value_impl! {Add;add;+; 2 Vector4<f32>; for &f32}
+value_impl! {Add;add;+; 2 Vector4<f64>; for &f64}
+value_impl! {Add;add;+; 3 Vector4<T>; for &Vector4<T>}
+value_impl! {Add;add;+; 1 &T; for &Vector4<T>}
+value_impl! {Add;add;+; 2 &Vector4<f32>; for &f32}
+value_impl! {Add;add;+; 2 &Vector4<f64>; for &f64}
+value_impl! {Add;add;+; 3 &Vector4<T>; for &Vector4<T>}
+
+//AddAssign
+use std::ops::AddAssign;
+
+inplace_impl! {AddAssign;add_assign;+=; 0 T; for Vector4<T>}
+inplace_impl! {AddAssign;add_assign;+=; 1 Vector4<T>; for Vector4<T>}
+inplace_impl! {AddAssign;add_assign;+=; 0 &T; for Vector4<T>}
+inplace_impl! {AddAssign;add_assign;+=; 1 &Vector4<T>; for Vector4<T>}
+
+//Display
+use std::fmt;
+
+impl<T: Scalar> fmt::Display for Vector4<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "<{}, {}, {}, {}>", self.x, self.y, self.z, self.w)
+        }
+}
+
+//Div
+use std::ops::Div;
+
+value_impl! {Div;div;/; 1 T; for Vector4<T>}
+value_impl! {Div;div;/; 1 &T; for Vector4<T>}
+value_impl! {Div;div;/; 1 T; for &Vector4<T>}
+value_impl! {Div;div;/; 1 &T; for &Vector4<T>}
+
+//DivAssign
+use std::ops::DivAssign;
+
+inplace_impl! {DivAssign;div_assign;/=; 0 T; for Vector4<T>}
+inplace_impl! {DivAssign;div_assign;/=; 0 &T; for Vector4<T>}
+
+//Mult
+use std::ops::Mul;
+
+value_impl! {Mul;mul;*; 1 T; for Vector4<T>}
+value_impl! {Mul;mul;*; 2 Vector4<f32>; for f32}
+value_impl! {Mul;mul;*; 2 Vector4<f64>; for f64}
+dot_impl! {Dot Vector4<T>; for Vector4<T>}
+value_impl! {Mul;mul;*; 1 &T; for Vector4<T>}
+value_impl! {Mul;mul;*; 2 &Vector4<f32>; for f32}
+value_impl! {Mul;mul;*; 2 &Vector4<f64>; for f64}
+dot_impl! {Dot &Vector4<T>; for Vector4<T>}
+value_impl! {Mul;mul;*; 1 T; for &Vector4<T>}
+value_impl! {Mul;mul;*; 2 Vector4<f32>; for &f32}
+value_impl! {Mul;mul;*; 2 Vector4<f64>; for &f64}
+dot_impl! {Dot Vector4<T>; for &Vector4<T>}
+value_impl! {Mul;mul;*; 1 &T; for &Vector4<T>}
+value_impl! {Mul;mul;*; 2 &Vector4<f32>; for &f32}
+value_impl! {Mul;mul;*; 2 &Vector4<f64>; for &f64}
+dot_impl! {Dot &Vector4<T>; for &Vector4<T>}
+
+//MultAssign
+use std::ops::MulAssign;
+
+inplace_impl! {MulAssign;mul_assign;*=; 0 T; for Vector4<T>}
+inplace_impl! {MulAssign;mul_assign;*=; 0 &T; for Vector4<T>}
+
+//Neg
+use std::ops::Neg;
+
+impl<T: Scalar> Neg for Vector4<T> {
+        type Output = Vector4<T>;
+        fn neg(self) -> Vector4<T> {
+                Vector4 { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+        }
+}
+
+impl<T: Scalar> Neg for &Vector4<T> {
+        type Output = Vector4<T>;
+        fn neg(self) -> Vector4<T> {
+                Vector4 { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+        }
+}
+
+//Sub
+use std::ops::Sub;
+
+value_impl! {Sub;sub;-; 1 T; for Vector4<T>}
+value_impl! {Sub;sub;-; 3 Vector4<T>; for Vector4<T>}
+value_impl! {Sub;sub;-; 1 &T; for Vector4<T>}
+value_impl! {Sub;sub;-; 3 &Vector4<T>; for Vector4<T>}
+value_impl! {Sub;sub;-; 1 T; for &Vector4<T>}
+value_impl! {Sub;sub;-; 3 Vector4<T>; for &Vector4<T>}
+value_impl! {Sub;sub;-; 1 &T; for &Vector4<T>}
+value_impl! {Sub;sub;-; 3 &Vector4<T>; for &Vector4<T>}
+
+//SubAssign
+use std::ops::SubAssign;
+
+inplace_impl! {SubAssign;sub_assign;-=; 0 T; for Vector4<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 1 Vector4<T>; for Vector4<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 0 &T; for Vector4<T>}
+inplace_impl! {SubAssign;sub_assign;-=; 1 &Vector4<T>; for Vector4<T>}
+
+//Sum
+use std::iter::Sum;
+
+impl<T: Scalar> Sum for Vector4<T> {
+        fn sum<I: Iterator<Item = Vector4<T>>>(iter: I) -> Vector4<T> {
+                iter.fold(Vector4::ZERO, |a, b| a + b)
+        }
+}
+
+impl<'a, T: Scalar> Sum<&'a Vector4<T>> for Vector4<T> {
+        fn sum<I: Iterator<Item = &'a Vector4<T>>>(iter: I) -> Vector4<T> {
+                iter.fold(Vector4::ZERO, |a, b| a + *b)
+        }
+}
+
+//Vec4 Methods
+
+impl<T: Scalar> Vector4<T> {
+	pub const ZERO : Vector4<T> = Vector4 { x: T::ZERO, y: T::ZERO, z: T::ZERO, w: T::ZERO };
+        pub const ONE : Vector4<T> = Vector4 { x: T::ONE, y: T::ONE, z: T::ONE, w: T::ONE };
+
+	/// Creates a homogeneous point from a [Vec3](crate::vec3::Vec3), setting w to one so that it is moved by translations.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec4::Vec4;
+	/// let p : Vec4 = Vec4::from_point(Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+	/// assert_eq!(p, Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 });
+	/// ```
+	pub fn from_point(v : Vector3<T>) -> Vector4<T> {
+		Vector4 { x: v.x, y: v.y, z: v.z, w: T::ONE }
+	}
+
+	/// Creates a homogeneous direction from a [Vec3](crate::vec3::Vec3), setting w to zero so that it is not moved by translations.
+	pub fn from_dir(v : Vector3<T>) -> Vector4<T> {
+		Vector4 { x: v.x, y: v.y, z: v.z, w: T::ZERO }
+	}
+
+	/// Converts a homogeneous point back to a [Vec3](crate::vec3::Vec3) by dividing by w.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # extern crate lineq;
+	/// use lineq::vec3::Vec3;
+	/// use lineq::vec4::Vec4;
+	/// let p : Vec4 = Vec4 { x: 2.0, y: 4.0, z: 6.0, w: 2.0 };
+	/// assert_eq!(p.to_point(), Vec3 { x: 1.0, y: 2.0, z: 3.0 });
+	/// ```
+	pub fn to_point(&self) -> Vector3<T> {
+		Vector3 { x: self.x/self.w, y: self.y/self.w, z: self.z/self.w }
+	}
+
+	/// Converts a homogeneous direction back to a [Vec3](crate::vec3::Vec3) by dropping w.
+	pub fn to_dir(&self) -> Vector3<T> {
+		Vector3 { x: self.x, y: self.y, z: self.z }
+	}
+
+        pub fn mag(&self) -> T {
+                (self.x*self.x + self.y*self.y + self.z*self.z + self.w*self.w).sqrt()
+        }
+
+        pub fn mag2(&self) -> T {
+                self.x*self.x + self.y*self.y + self.z*self.z + self.w*self.w
+        }
+
+        pub fn norm(&self) -> Vector4<T> {
+                let mag : T = self.mag();
+                Vector4 { x: self.x/mag, y: self.y/mag, z: self.z/mag, w: self.w/mag }
+        }
+
+	/// Normalizes the vector, failing instead of dividing by zero when its magnitude is zero.
+	pub fn try_norm(&self) -> Result<Vector4<T>, LineqError> {
+		let mag : T = self.mag();
+		if mag == T::ZERO { return Err(LineqError::ZeroVector); }
+		Ok(Vector4 { x: self.x/mag, y: self.y/mag, z: self.z/mag, w: self.w/mag })
+	}
+}